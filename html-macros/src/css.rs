@@ -0,0 +1,515 @@
+use proc_macro2::{Literal, Span, TokenStream, TokenTree};
+use quote::{quote, quote_spanned};
+use syn::{
+    braced,
+    ext::IdentExt,
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Expr, Ident, Lit, LitFloat, LitInt, LitStr, Token,
+};
+
+pub struct Stylesheet(Vec<Block>);
+
+struct Block {
+    selector: Selector,
+    decls: Vec<Declaration>,
+}
+
+/// A chain of compound selectors joined by `>`, or a list of simple selectors
+enum Selector {
+    Chain(Compound),
+    List(Vec<TokenStream>),
+}
+
+struct Compound {
+    top: TokenStream,
+    sub: Option<Sub>,
+}
+
+enum Sub {
+    Visited,
+    NthChild(TokenStream),
+    Child(Box<Compound>),
+}
+
+struct Declaration {
+    property: TokenStream,
+    value: Vec<Part>,
+    important: bool,
+}
+
+enum Part {
+    Value(TokenStream),
+    Comma,
+}
+
+/// Parses names such as `card` or `flex-start`.
+/// Selectors also allow numbers after a dash, as in `col-2`.
+fn parse_name(input: ParseStream, allow_numbers: bool) -> syn::Result<(String, Span)> {
+    let first = Ident::parse_any(input)?;
+    let span = first.span();
+    let mut name = first.to_string();
+    while input.peek(Token![-]) && (input.peek2(Ident::peek_any) || allow_numbers) {
+        input.parse::<Token![-]>()?;
+        name.push('-');
+        if allow_numbers && input.peek(LitInt) {
+            name.push_str(&input.parse::<LitInt>()?.to_string());
+        } else {
+            name.push_str(&Ident::parse_any(input)?.to_string());
+        }
+    }
+    Ok((name, span))
+}
+
+/// Turns a css name into the function of `macro_support` it refers to
+fn function_name(name: &str, span: Span) -> Ident {
+    let name = name.replace('-', "_");
+    if syn::parse_str::<Ident>(&name).is_ok() {
+        Ident::new(&name, span)
+    } else {
+        Ident::new_raw(&name, span)
+    }
+}
+
+fn parse_variable(input: ParseStream) -> syn::Result<String> {
+    input.parse::<Token![-]>()?;
+    input.parse::<Token![-]>()?;
+    Ok(parse_name(input, true)?.0)
+}
+
+fn parse_child_selector(input: ParseStream) -> syn::Result<TokenStream> {
+    let content;
+    parenthesized!(content in input);
+    let stream: TokenStream = content.parse()?;
+    let span = stream.span();
+    let s: String = stream
+        .to_string()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    let invalid = || syn::Error::new(span, format!("invalid `:nth-child` argument `{s}`"));
+    match s.as_str() {
+        "odd" => return Ok(quote!(::html::css::selector::ChildSelector::Odd)),
+        "even" => return Ok(quote!(::html::css::selector::ChildSelector::Even)),
+        _ => (),
+    }
+    let (a, b) = match s.split_once('n') {
+        Some((a, b)) => {
+            let a: i32 = match a {
+                "" | "+" => 1,
+                "-" => -1,
+                a => a.parse().map_err(|_| invalid())?,
+            };
+            (a, b)
+        }
+        None => (0, s.as_str()),
+    };
+    let b = b.strip_prefix('+').unwrap_or(b);
+    let b: i32 = if b.is_empty() {
+        0
+    } else {
+        b.parse().map_err(|_| invalid())?
+    };
+    Ok(quote!(::html::css::selector::ChildSelector::AnPlusB(#a, #b)))
+}
+
+fn parse_simple(input: ParseStream) -> syn::Result<TokenStream> {
+    let top = quote!(::html::css::selector::TopSelector);
+    if input.peek(Token![*]) {
+        input.parse::<Token![*]>()?;
+        return Ok(quote!(#top::All));
+    }
+    let variant = if input.peek(Token![.]) {
+        input.parse::<Token![.]>()?;
+        quote!(Class)
+    } else if input.peek(Token![#]) {
+        input.parse::<Token![#]>()?;
+        quote!(Id)
+    } else if input.peek(Token![:]) {
+        input.parse::<Token![:]>()?;
+        quote!(Pseudo)
+    } else {
+        quote!(Tag)
+    };
+    let (name, _) = parse_name(input, true)?;
+    Ok(quote!(#top::#variant(::std::borrow::ToOwned::to_owned(#name))))
+}
+
+impl Parse for Compound {
+    fn parse(input: ParseStream) -> syn::Result<Compound> {
+        let top = parse_simple(input)?;
+        let sub = if input.peek(Token![>]) {
+            input.parse::<Token![>]>()?;
+            Some(Sub::Child(Box::new(input.parse()?)))
+        } else if input.peek(Token![:]) {
+            input.parse::<Token![:]>()?;
+            let (name, span) = parse_name(input, false)?;
+            match name.as_str() {
+                "visited" => Some(Sub::Visited),
+                "nth-child" => Some(Sub::NthChild(parse_child_selector(input)?)),
+                _ => {
+                    return Err(syn::Error::new(
+                        span,
+                        format!("`:{name}` can only be used on its own"),
+                    ))
+                }
+            }
+        } else {
+            None
+        };
+        if !(input.peek(syn::token::Brace) || input.peek(Token![,])) {
+            return Err(
+                input.error("expected `>`, `,` or `{`, descendant selectors are not supported")
+            );
+        }
+        Ok(Compound { top, sub })
+    }
+}
+
+impl Parse for Selector {
+    fn parse(input: ParseStream) -> syn::Result<Selector> {
+        let first: Compound = input.parse()?;
+        if !input.peek(Token![,]) {
+            return Ok(Selector::Chain(first));
+        }
+        let mut tops = vec![first];
+        while input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            tops.push(input.parse()?);
+        }
+        tops.into_iter()
+            .map(|compound| match compound.sub {
+                None => Ok(compound.top),
+                Some(_) => Err(syn::Error::new(
+                    compound.top.span(),
+                    "selector lists can only contain simple selectors",
+                )),
+            })
+            .collect::<syn::Result<_>>()
+            .map(Selector::List)
+    }
+}
+
+/// Parses a hex color written after `#`, either as a token or a string literal for digits the
+/// Rust lexer rejects
+fn parse_hex(input: ParseStream) -> syn::Result<TokenStream> {
+    let token: TokenTree = input.parse()?;
+    let span = token.span();
+    let hex = match &token {
+        TokenTree::Ident(ident) => ident.to_string(),
+        TokenTree::Literal(lit) => match Lit::new(lit.clone()) {
+            Lit::Str(s) => s.value(),
+            _ => lit.to_string(),
+        },
+        _ => return Err(syn::Error::new(span, "expected hex digits")),
+    };
+    let invalid = || syn::Error::new(span, format!("invalid color `#{hex}`"));
+    if !hex.is_ascii() {
+        return Err(invalid());
+    }
+    let digits: Vec<u8> = match hex.len() {
+        3 | 4 => hex
+            .chars()
+            .map(|c| u8::from_str_radix(&c.to_string().repeat(2), 16).ok())
+            .collect::<Option<_>>(),
+        6 | 8 => (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+            .collect::<Option<_>>(),
+        _ => None,
+    }
+    .ok_or_else(invalid)?;
+    let color = quote!(::html::css::value::Color);
+    Ok(match digits[..] {
+        [r, g, b] => quote!(#color::Rgb(#r, #g, #b)),
+        [r, g, b, a] => {
+            let a = Literal::f32_suffixed(a as f32 / 255.0);
+            quote!(#color::Rgba(#r, #g, #b, #a))
+        }
+        _ => unreachable!(),
+    })
+}
+
+/// Parses the arguments of `rgb(..)` and `rgba(..)`
+fn parse_rgb(input: ParseStream, alpha: bool, span: Span) -> syn::Result<TokenStream> {
+    let content;
+    parenthesized!(content in input);
+    let args = Punctuated::<Lit, Token![,]>::parse_terminated(&content)?;
+    let args: Vec<Lit> = args.into_iter().collect();
+    let channel = |lit: &Lit| match lit {
+        Lit::Int(int) => int.base10_parse::<u8>(),
+        lit => Err(syn::Error::new(
+            lit.span(),
+            "expected a number from 0 to 255",
+        )),
+    };
+    let color = quote!(::html::css::value::Color);
+    match (&args[..], alpha) {
+        ([r, g, b], false) => {
+            let (r, g, b) = (channel(r)?, channel(g)?, channel(b)?);
+            Ok(quote!(#color::Rgb(#r, #g, #b)))
+        }
+        ([r, g, b, a], true) => {
+            let (r, g, b) = (channel(r)?, channel(g)?, channel(b)?);
+            let a: f32 = match a {
+                Lit::Float(float) => float.base10_parse()?,
+                Lit::Int(int) => int.base10_parse()?,
+                lit => return Err(syn::Error::new(lit.span(), "expected a number")),
+            };
+            let a = Literal::f32_suffixed(a);
+            Ok(quote!(#color::Rgba(#r, #g, #b, #a)))
+        }
+        _ => Err(syn::Error::new(
+            span,
+            if alpha {
+                "`rgba` takes four arguments"
+            } else {
+                "`rgb` takes three arguments"
+            },
+        )),
+    }
+}
+
+/// Parses a number with its unit, a unit starting with `e` has to follow after a space as in
+/// `1.5 em` since Rust reads `1.5em` as an exponent
+fn parse_number(input: ParseStream, negative: bool) -> syn::Result<TokenStream> {
+    let (digits, suffix, span) = if input.peek(LitFloat) {
+        let lit: LitFloat = input.parse()?;
+        let digits = lit.base10_digits().to_owned();
+        (digits, lit.suffix().to_owned(), lit.span())
+    } else {
+        let lit: LitInt = input.parse()?;
+        let digits = lit.base10_digits().to_owned();
+        (digits, lit.suffix().to_owned(), lit.span())
+    };
+    let digits = if negative {
+        format!("-{digits}")
+    } else {
+        digits
+    };
+    let unit = if !suffix.is_empty() {
+        Some(Ident::new(&suffix, span))
+    } else if input.peek(Token![%]) {
+        input.parse::<Token![%]>()?;
+        Some(Ident::new("percent", span))
+    } else if input.peek(Ident) && input.fork().parse::<Ident>()? == "em" {
+        Some(input.parse()?)
+    } else {
+        None
+    };
+    let value = quote!(::html::css::value::Value);
+    Ok(match unit {
+        Some(unit) => {
+            let num: f32 = digits
+                .parse()
+                .map_err(|_| syn::Error::new(span, "invalid number"))?;
+            let num = Literal::f32_suffixed(num);
+            quote_spanned!(unit.span()=> #value::Measurement(#num, ::html::macro_support::units::#unit()))
+        }
+        None => quote!(#value::Other(::std::borrow::ToOwned::to_owned(#digits))),
+    })
+}
+
+impl Parse for Part {
+    fn parse(input: ParseStream) -> syn::Result<Part> {
+        let value = quote!(::html::css::value::Value);
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            return Ok(Part::Comma);
+        }
+        if input.peek(syn::token::Brace) {
+            let content;
+            braced!(content in input);
+            let expr: Expr = content.parse()?;
+            return Ok(Part::Value(
+                quote_spanned!(expr.span()=> #value::from(#expr)),
+            ));
+        }
+        if input.peek(LitStr) {
+            let s: LitStr = input.parse()?;
+            return Ok(Part::Value(
+                quote!(#value::Str(::std::borrow::ToOwned::to_owned(#s))),
+            ));
+        }
+        if input.peek(Token![#]) {
+            input.parse::<Token![#]>()?;
+            let color = parse_hex(input)?;
+            return Ok(Part::Value(quote!(#value::Color(#color))));
+        }
+        if input.peek(Token![-]) && (input.peek2(LitInt) || input.peek2(LitFloat)) {
+            input.parse::<Token![-]>()?;
+            return parse_number(input, true).map(Part::Value);
+        }
+        if input.peek(LitInt) || input.peek(LitFloat) {
+            return parse_number(input, false).map(Part::Value);
+        }
+        if input.peek(Ident::peek_any) {
+            let (name, span) = parse_name(input, false)?;
+            if !input.peek(syn::token::Paren) {
+                let keyword = function_name(&name, span);
+                return Ok(Part::Value(quote_spanned!(span=>
+                    #value::Keyword(::html::macro_support::keywords::#keyword())
+                )));
+            }
+            return match name.as_str() {
+                "rgb" | "rgba" => {
+                    let color = parse_rgb(input, name == "rgba", span)?;
+                    Ok(Part::Value(quote!(#value::Color(#color))))
+                }
+                "var" => {
+                    let content;
+                    parenthesized!(content in input);
+                    let var = parse_variable(&content)?;
+                    Ok(Part::Value(
+                        quote!(#value::Var(::std::borrow::ToOwned::to_owned(#var))),
+                    ))
+                }
+                _ => Err(syn::Error::new(span, format!("unknown function `{name}`"))),
+            };
+        }
+        Err(input.error("expected a value"))
+    }
+}
+
+impl Parse for Declaration {
+    fn parse(input: ParseStream) -> syn::Result<Declaration> {
+        let property = if input.peek(Token![-]) && input.peek2(Token![-]) {
+            let var = parse_variable(input)?;
+            quote!(::html::css::property::Property::Var(::std::borrow::ToOwned::to_owned(#var)))
+        } else {
+            let (name, span) = parse_name(input, false)?;
+            let function = function_name(&name, span);
+            quote_spanned!(span=> ::html::macro_support::properties::#function())
+        };
+        input.parse::<Token![:]>()?;
+        let mut value = vec![];
+        while !(input.is_empty() || input.peek(Token![;]) || input.peek(Token![!])) {
+            value.push(input.parse()?);
+        }
+        if value.is_empty() {
+            return Err(input.error("expected a value"));
+        }
+        let important = input.peek(Token![!]);
+        if important {
+            input.parse::<Token![!]>()?;
+            let (name, span) = parse_name(input, false)?;
+            if name != "important" {
+                return Err(syn::Error::new(span, "expected `important`"));
+            }
+        }
+        if !input.is_empty() {
+            input.parse::<Token![;]>()?;
+        }
+        Ok(Declaration {
+            property,
+            value,
+            important,
+        })
+    }
+}
+
+impl Parse for Block {
+    fn parse(input: ParseStream) -> syn::Result<Block> {
+        let selector = input.parse()?;
+        let content;
+        braced!(content in input);
+        let mut decls = vec![];
+        while !content.is_empty() {
+            decls.push(content.parse()?);
+        }
+        Ok(Block { selector, decls })
+    }
+}
+
+impl Parse for Stylesheet {
+    fn parse(input: ParseStream) -> syn::Result<Stylesheet> {
+        let mut blocks = vec![];
+        while !input.is_empty() {
+            blocks.push(input.parse()?);
+        }
+        Ok(Stylesheet(blocks))
+    }
+}
+
+impl Stylesheet {
+    /// Expands to a `CssDocument`
+    pub fn expand(&self) -> TokenStream {
+        let blocks = self.0.iter().map(Block::expand);
+        quote!(::html::css::CssDocument {
+            decls: ::std::vec![#(#blocks),*],
+        })
+    }
+}
+
+impl Block {
+    fn expand(&self) -> TokenStream {
+        let selector = match &self.selector {
+            Selector::Chain(compound) => compound.expand(),
+            Selector::List(tops) => quote!(::html::css::selector::Selector {
+                top: ::html::css::selector::TopSelector::Multiple(::std::vec![#(#tops),*]),
+                sub: ::std::option::Option::None,
+            }),
+        };
+        let decls = self.decls.iter().map(Declaration::expand);
+        quote!(::html::css::block::CssBlock {
+            selector: #selector,
+            decls: ::std::vec![#(#decls),*],
+        })
+    }
+}
+
+impl Compound {
+    fn expand(&self) -> TokenStream {
+        let top = &self.top;
+        let sub_selector = quote!(::html::css::selector::SubSelector);
+        let sub = match &self.sub {
+            None => quote!(::std::option::Option::None),
+            Some(Sub::Visited) => quote!(::std::option::Option::Some(#sub_selector::Visited)),
+            Some(Sub::NthChild(child)) => {
+                quote!(::std::option::Option::Some(#sub_selector::NthChild(#child)))
+            }
+            Some(Sub::Child(compound)) => {
+                let child = compound.expand();
+                quote!(::std::option::Option::Some(#sub_selector::ChildCombinator(
+                    ::std::rc::Rc::new(#child)
+                )))
+            }
+        };
+        quote!(::html::css::selector::Selector { top: #top, sub: #sub })
+    }
+}
+
+impl Declaration {
+    fn expand(&self) -> TokenStream {
+        let property = &self.property;
+        let important = self.important;
+        let value = match &self.value[..] {
+            [Part::Value(value)] => value.clone(),
+            // values of several parts are rendered together, as in `1px solid #000`
+            parts => {
+                let mut pieces = vec![];
+                for (i, part) in parts.iter().enumerate() {
+                    match part {
+                        Part::Value(value) => {
+                            if i > 0 {
+                                pieces.push(quote!(" "));
+                            }
+                            pieces.push(quote!(::html::render::Render::render(&#value).as_str()));
+                        }
+                        Part::Comma => pieces.push(quote!(",")),
+                    }
+                }
+                quote!(::html::css::value::Value::Other(
+                    [#(#pieces),*].concat()
+                ))
+            }
+        };
+        quote!(::html::css::declaration::Declaration {
+            property: #property,
+            value: #value,
+            important: #important,
+        })
+    }
+}
@@ -0,0 +1,397 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{
+    braced,
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    Expr, Ident, Lit, LitStr, Pat, Token,
+};
+
+pub struct Nodes(Vec<Node>);
+
+enum Node {
+    Element(Element),
+    Text(LitStr),
+    Expr(Expr),
+    For(Expr),
+    If(If),
+    Match(Match),
+}
+
+struct Element {
+    /// Custom elements such as `my-widget` contain a dash
+    name: String,
+    span: Span,
+    attributes: Vec<Attribute>,
+    children: Vec<Node>,
+}
+
+struct Attribute {
+    name: String,
+    span: Span,
+    value: Option<AttributeValue>,
+}
+
+enum AttributeValue {
+    Lit(Lit),
+    Expr(Expr),
+}
+
+struct If {
+    cond: Expr,
+    then: Nodes,
+    otherwise: Option<Box<Else>>,
+}
+
+enum Else {
+    If(If),
+    Nodes(Nodes),
+}
+
+struct Match {
+    expr: Expr,
+    arms: Vec<Arm>,
+}
+
+struct Arm {
+    pat: Pat,
+    guard: Option<Expr>,
+    body: Nodes,
+}
+
+/// Parses names such as `div` or `data-id`, keywords included
+fn parse_name(input: ParseStream) -> syn::Result<(String, Span)> {
+    let first = Ident::parse_any(input)?;
+    let span = first.span();
+    let mut name = first.to_string();
+    while input.peek(Token![-]) {
+        input.parse::<Token![-]>()?;
+        name.push('-');
+        name.push_str(&Ident::parse_any(input)?.to_string());
+    }
+    Ok((name, span))
+}
+
+fn parse_braced_nodes(input: ParseStream) -> syn::Result<Nodes> {
+    let content;
+    braced!(content in input);
+    content.parse()
+}
+
+impl Parse for Nodes {
+    fn parse(input: ParseStream) -> syn::Result<Nodes> {
+        let mut nodes = vec![];
+        while !(input.is_empty() || input.peek(Token![<]) && input.peek2(Token![/])) {
+            nodes.push(input.parse()?);
+        }
+        Ok(Nodes(nodes))
+    }
+}
+
+impl Parse for Node {
+    fn parse(input: ParseStream) -> syn::Result<Node> {
+        if input.peek(Token![<]) {
+            return input.parse().map(Node::Element);
+        }
+        if input.peek(LitStr) {
+            return input.parse().map(Node::Text);
+        }
+        if input.peek(Token![if]) {
+            return input.parse().map(Node::If);
+        }
+        if input.peek(Token![match]) {
+            return input.parse().map(Node::Match);
+        }
+        if input.peek(syn::token::Brace) {
+            let content;
+            braced!(content in input);
+            if content.peek(Token![for]) {
+                content.parse::<Token![for]>()?;
+                return content.parse().map(Node::For);
+            }
+            return content.parse().map(Node::Expr);
+        }
+        Err(input.error("expected an element, a string literal, `{expression}`, `if` or `match`"))
+    }
+}
+
+impl Parse for Element {
+    fn parse(input: ParseStream) -> syn::Result<Element> {
+        input.parse::<Token![<]>()?;
+        let (name, span) = parse_name(input)?;
+
+        let mut attributes = vec![];
+        while !input.peek(Token![>]) && !input.peek(Token![/]) {
+            let (name, span) = parse_name(input)?;
+            let value = if input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                if input.peek(syn::token::Brace) {
+                    let content;
+                    braced!(content in input);
+                    Some(AttributeValue::Expr(content.parse()?))
+                } else {
+                    Some(AttributeValue::Lit(input.parse()?))
+                }
+            } else {
+                None
+            };
+            attributes.push(Attribute { name, span, value });
+        }
+
+        if input.peek(Token![/]) {
+            input.parse::<Token![/]>()?;
+            input.parse::<Token![>]>()?;
+            return Ok(Element {
+                name,
+                span,
+                attributes,
+                children: vec![],
+            });
+        }
+        input.parse::<Token![>]>()?;
+        let Nodes(children) = input.parse()?;
+        if input.is_empty() {
+            return Err(syn::Error::new(span, format!("unclosed element `{name}`")));
+        }
+        input.parse::<Token![<]>()?;
+        input.parse::<Token![/]>()?;
+        let (closing, closing_span) = parse_name(input)?;
+        if name != closing {
+            return Err(syn::Error::new(
+                closing_span,
+                format!("expected `</{name}>`, found `</{closing}>`"),
+            ));
+        }
+        input.parse::<Token![>]>()?;
+        Ok(Element {
+            name,
+            span,
+            attributes,
+            children,
+        })
+    }
+}
+
+impl Parse for If {
+    fn parse(input: ParseStream) -> syn::Result<If> {
+        input.parse::<Token![if]>()?;
+        let cond = Expr::parse_without_eager_brace(input)?;
+        let then = parse_braced_nodes(input)?;
+        let otherwise = if input.peek(Token![else]) {
+            input.parse::<Token![else]>()?;
+            if input.peek(Token![if]) {
+                Some(Box::new(Else::If(input.parse()?)))
+            } else {
+                Some(Box::new(Else::Nodes(parse_braced_nodes(input)?)))
+            }
+        } else {
+            None
+        };
+        Ok(If {
+            cond,
+            then,
+            otherwise,
+        })
+    }
+}
+
+impl Parse for Match {
+    fn parse(input: ParseStream) -> syn::Result<Match> {
+        input.parse::<Token![match]>()?;
+        let expr = Expr::parse_without_eager_brace(input)?;
+        let content;
+        braced!(content in input);
+        let mut arms = vec![];
+        while !content.is_empty() {
+            let pat = Pat::parse_multi_with_leading_vert(&content)?;
+            let guard = if content.peek(Token![if]) {
+                content.parse::<Token![if]>()?;
+                Some(content.parse()?)
+            } else {
+                None
+            };
+            content.parse::<Token![=>]>()?;
+            let body = if content.peek(syn::token::Brace) {
+                parse_braced_nodes(&content)?
+            } else {
+                Nodes(vec![content.parse()?])
+            };
+            if content.peek(Token![,]) {
+                content.parse::<Token![,]>()?;
+            }
+            arms.push(Arm { pat, guard, body });
+        }
+        Ok(Match { expr, arms })
+    }
+}
+
+impl Nodes {
+    /// Expands to a single `HtmlElement`
+    pub fn expand(&self) -> TokenStream {
+        match self.0.as_slice() {
+            [] => quote!(<::html::elements::HtmlElement as ::std::default::Default>::default()),
+            [node] => node.expand(),
+            nodes => {
+                let nodes = nodes.iter().map(Node::expand);
+                quote!(::html::elements::HtmlElement::ComponentList(
+                    ::std::vec![#(#nodes),*]
+                ))
+            }
+        }
+    }
+}
+
+impl Node {
+    fn expand(&self) -> TokenStream {
+        match self {
+            Node::Element(elem) if elem.name == "option" => {
+                syn::Error::new(elem.span, "`<option>` has to be inside a `<select>`")
+                    .to_compile_error()
+            }
+            Node::Element(elem) => {
+                let builder = elem.expand_builder();
+                quote!(::html::elements::Build::build(#builder))
+            }
+            Node::Text(text) => quote!(::html::elements::HtmlElement::from(#text)),
+            Node::Expr(expr) => {
+                quote_spanned!(expr.span()=> ::html::elements::HtmlElement::from(#expr))
+            }
+            Node::For(iter) => quote_spanned! {iter.span()=>
+                ::html::elements::HtmlElement::ComponentList(
+                    ::std::iter::IntoIterator::into_iter(#iter)
+                        .map(::std::convert::Into::into)
+                        .collect(),
+                )
+            },
+            Node::If(if_node) => if_node.expand(),
+            Node::Match(match_node) => {
+                let expr = &match_node.expr;
+                let arms = match_node.arms.iter().map(|arm| {
+                    let pat = &arm.pat;
+                    let body = arm.body.expand();
+                    match &arm.guard {
+                        Some(guard) => quote!(#pat if #guard => #body,),
+                        None => quote!(#pat => #body,),
+                    }
+                });
+                quote!(match #expr { #(#arms)* })
+            }
+        }
+    }
+}
+
+impl If {
+    fn expand(&self) -> TokenStream {
+        let cond = &self.cond;
+        let then = self.then.expand();
+        let otherwise = match self.otherwise.as_deref() {
+            Some(Else::If(if_node)) => if_node.expand(),
+            Some(Else::Nodes(nodes)) => nodes.expand(),
+            None => Nodes(vec![]).expand(),
+        };
+        quote!(if #cond { #then } else { #otherwise })
+    }
+}
+
+impl Element {
+    /// Expands to the builder of the element, which is not finished yet
+    fn expand_builder(&self) -> TokenStream {
+        let builder = if self.name.contains('-') {
+            let name = &self.name;
+            quote_spanned!(self.span=> ::html::elements::Element::new(#name))
+        } else {
+            let name = Ident::new(&self.name, self.span);
+            quote!(::html::macro_support::tags::#name())
+        };
+        let attributes = self.attributes.iter().map(Attribute::expand);
+        let children = self.children.iter().map(|child| match child {
+            // list items are not elements on their own
+            Node::Element(elem) if elem.name == "li" => {
+                let item = elem.expand_builder();
+                quote_spanned!(elem.span=> .item(#item))
+            }
+            // neither are options
+            Node::Element(elem) if elem.name == "option" => {
+                let option = elem.expand_option();
+                quote_spanned!(elem.span=> .option(#option))
+            }
+            Node::For(iter) => quote_spanned!(iter.span()=> .children(#iter)),
+            child => {
+                let child = child.expand();
+                quote!(.child(#child))
+            }
+        });
+        quote! {{
+            #[allow(unused_imports)]
+            use ::html::elements::{WithAttributes as _, WithChildren as _};
+            #builder
+                #(#attributes)*
+                #(#children)*
+        }}
+    }
+
+    /// Expands an `<option>`, whose value defaults to its text as in html
+    fn expand_option(&self) -> TokenStream {
+        if let Some(attr) = self.attributes.iter().find(|attr| attr.name != "value") {
+            return syn::Error::new(attr.span, "`<option>` only takes a `value`")
+                .to_compile_error();
+        }
+        let value = match (
+            self.attributes.first().and_then(Attribute::value),
+            &self.children[..],
+        ) {
+            (Some(value), _) => value,
+            (None, [Node::Text(text)]) => quote!(#text),
+            (None, _) => {
+                let message = "`<option>` needs a `value` unless it only contains text";
+                return syn::Error::new(self.span, message).to_compile_error();
+            }
+        };
+        let children = self.children.iter().map(Node::expand);
+        quote! {{
+            #[allow(unused_imports)]
+            use ::html::elements::WithChildren as _;
+            ::html::macro_support::tags::option(#value)
+                #(.child(#children))*
+        }}
+    }
+}
+
+impl Attribute {
+    fn value(&self) -> Option<TokenStream> {
+        match &self.value {
+            Some(AttributeValue::Lit(lit)) => Some(quote!(#lit)),
+            Some(AttributeValue::Expr(expr)) => Some(quote!(#expr)),
+            None => None,
+        }
+    }
+
+    fn expand(&self) -> TokenStream {
+        if let Some(key) = self.name.strip_prefix("data-") {
+            if key.contains(|c: char| c.is_ascii_uppercase()) {
+                let message = format!("data key `{key}` may not contain upper case letters");
+                return syn::Error::new(self.span, message).to_compile_error();
+            }
+            let value = self.value().unwrap_or_else(|| quote!(""));
+            return quote_spanned!(self.span=> .attr(::html::macro_support::attributes::data(#key, #value)));
+        }
+        let value = self.value().unwrap_or_default();
+        // handlers such as `onkeydown` are looked up by their event
+        if let Some(event) = self
+            .name
+            .strip_prefix("on")
+            .and_then(|event| syn::parse_str::<Ident>(event).ok())
+        {
+            let event = Ident::new(&event.to_string(), self.span);
+            return quote_spanned!(self.span=> .attr(::html::macro_support::attributes::on(::html::macro_support::events::#event, #value)));
+        }
+        let name = self.name.replace('-', "_");
+        let function = if syn::parse_str::<Ident>(&name).is_ok() {
+            Ident::new(&name, self.span)
+        } else {
+            Ident::new_raw(&name, self.span)
+        };
+        quote_spanned!(self.span=> .attr(::html::macro_support::attributes::#function(#value)))
+    }
+}
@@ -0,0 +1,24 @@
+//! Procedural macros of the `html` crate, use them through its re-exports
+mod css;
+mod html;
+mod tabular;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+#[proc_macro]
+pub fn html(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as html::Nodes).expand().into()
+}
+
+#[proc_macro]
+pub fn css(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as css::Stylesheet).expand().into()
+}
+
+#[proc_macro_derive(Tabular, attributes(tabular))]
+pub fn derive_tabular(input: TokenStream) -> TokenStream {
+    tabular::derive(parse_macro_input!(input as DeriveInput))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
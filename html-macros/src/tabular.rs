@@ -0,0 +1,79 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Data, DeriveInput, Fields, LitStr};
+
+/// A field that becomes a column
+struct Column {
+    ident: syn::Ident,
+    header: String,
+}
+
+fn column(field: &syn::Field) -> syn::Result<Option<Column>> {
+    let ident = field.ident.clone().expect("Only called for named fields");
+    let mut header = ident.to_string();
+    let mut skip = false;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("tabular"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skip = true;
+                Ok(())
+            } else if meta.path.is_ident("rename") {
+                header = meta.value()?.parse::<LitStr>()?.value();
+                Ok(())
+            } else {
+                Err(meta.error("expected `skip` or `rename = \"..\"`"))
+            }
+        })?;
+    }
+    Ok((!skip).then_some(Column { ident, header }))
+}
+
+/// Implements `Tabular` with a column for every field
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            fields => {
+                return Err(syn::Error::new(
+                    fields.span(),
+                    "Tabular can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "Tabular can only be derived for structs",
+            ))
+        }
+    };
+    let columns = fields
+        .iter()
+        .map(column)
+        .collect::<syn::Result<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+    let headers = columns.iter().map(|column| &column.header);
+    let cells = columns.iter().map(|column| {
+        let ident = &column.ident;
+        quote!(::html::tabular::ToCell::to_cell(&self.#ident))
+    });
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::html::tabular::Tabular for #name #ty_generics #where_clause {
+            fn headers() -> ::std::vec::Vec<&'static str> {
+                ::std::vec![#(#headers),*]
+            }
+            fn cells(&self) -> ::std::vec::Vec<::html::tabular::CellValue> {
+                ::std::vec![#(#cells),*]
+            }
+        }
+    })
+}
@@ -0,0 +1,160 @@
+use crate::{
+    parse::ParseError,
+    render::{Render, Renderer},
+};
+use std::{fmt, str::FromStr};
+
+/// Accessibility attributes, rendered with the `aria-` prefix
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AriaAttr {
+    Label(String),
+    /// Ids of the elements labelling this one
+    LabelledBy(String),
+    /// Ids of the elements describing this one
+    DescribedBy(String),
+    /// Ids of the elements whose content or presence this one controls
+    Controls(String),
+    /// The current item within a set, such as `page` or `step`
+    Current(String),
+    Hidden(bool),
+    Expanded(bool),
+    Pressed(bool),
+    Selected(bool),
+    Checked(bool),
+    Disabled(bool),
+    Live(Live),
+}
+
+/// How updates of a live region are announced
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Live {
+    Off,
+    Polite,
+    /// Interrupts the current announcement
+    Assertive,
+}
+
+impl Render for Live {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(match self {
+            Live::Off => "off",
+            Live::Polite => "polite",
+            Live::Assertive => "assertive",
+        })
+    }
+}
+
+impl FromStr for Live {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Live, ParseError> {
+        match s.to_ascii_lowercase().as_str() {
+            "off" => Ok(Live::Off),
+            "polite" => Ok(Live::Polite),
+            "assertive" => Ok(Live::Assertive),
+            _ => Err(ParseError::at(s, 0, &format!("unknown aria-live `{s}`"))),
+        }
+    }
+}
+
+impl AriaAttr {
+    /// The full name, `aria-` included
+    pub fn name(&self) -> &'static str {
+        match self {
+            AriaAttr::Label(_) => "aria-label",
+            AriaAttr::LabelledBy(_) => "aria-labelledby",
+            AriaAttr::DescribedBy(_) => "aria-describedby",
+            AriaAttr::Controls(_) => "aria-controls",
+            AriaAttr::Current(_) => "aria-current",
+            AriaAttr::Hidden(_) => "aria-hidden",
+            AriaAttr::Expanded(_) => "aria-expanded",
+            AriaAttr::Pressed(_) => "aria-pressed",
+            AriaAttr::Selected(_) => "aria-selected",
+            AriaAttr::Checked(_) => "aria-checked",
+            AriaAttr::Disabled(_) => "aria-disabled",
+            AriaAttr::Live(_) => "aria-live",
+        }
+    }
+
+    pub fn value(&self) -> String {
+        match self {
+            AriaAttr::Label(val)
+            | AriaAttr::LabelledBy(val)
+            | AriaAttr::DescribedBy(val)
+            | AriaAttr::Controls(val)
+            | AriaAttr::Current(val) => val.clone(),
+            AriaAttr::Hidden(flag)
+            | AriaAttr::Expanded(flag)
+            | AriaAttr::Pressed(flag)
+            | AriaAttr::Selected(flag)
+            | AriaAttr::Checked(flag)
+            | AriaAttr::Disabled(flag) => flag.to_string(),
+            AriaAttr::Live(live) => live.render(),
+        }
+    }
+
+    /// The attribute for `name` without its `aria-` prefix, `None` for unknown names and
+    /// values that do not fit, such as `aria-checked="mixed"`
+    pub fn parse(name: &str, value: &str) -> Option<AriaAttr> {
+        let flag = || match value.to_ascii_lowercase().as_str() {
+            "true" => Some(true),
+            "false" => Some(false),
+            _ => None,
+        };
+        match name.to_ascii_lowercase().as_str() {
+            "label" => Some(AriaAttr::Label(value.to_owned())),
+            "labelledby" => Some(AriaAttr::LabelledBy(value.to_owned())),
+            "describedby" => Some(AriaAttr::DescribedBy(value.to_owned())),
+            "controls" => Some(AriaAttr::Controls(value.to_owned())),
+            "current" => Some(AriaAttr::Current(value.to_owned())),
+            "hidden" => flag().map(AriaAttr::Hidden),
+            "expanded" => flag().map(AriaAttr::Expanded),
+            "pressed" => flag().map(AriaAttr::Pressed),
+            "selected" => flag().map(AriaAttr::Selected),
+            "checked" => flag().map(AriaAttr::Checked),
+            "disabled" => flag().map(AriaAttr::Disabled),
+            "live" => value.parse().ok().map(AriaAttr::Live),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod aria_tests {
+    use super::{AriaAttr, Live};
+
+    #[test]
+    fn aria_name_value() {
+        let result = [
+            AriaAttr::LabelledBy("title".to_owned()),
+            AriaAttr::Hidden(true),
+            AriaAttr::Live(Live::Polite),
+        ]
+        .map(|aria| (aria.name(), aria.value()));
+        let expected = [
+            ("aria-labelledby", "title".to_owned()),
+            ("aria-hidden", "true".to_owned()),
+            ("aria-live", "polite".to_owned()),
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_aria() {
+        let result = [
+            ("label", "Close"),
+            ("Expanded", "FALSE"),
+            ("live", "assertive"),
+            ("checked", "mixed"),
+            ("rowindex", "1"),
+        ]
+        .map(|(name, value)| AriaAttr::parse(name, value));
+        let expected = [
+            Some(AriaAttr::Label("Close".to_owned())),
+            Some(AriaAttr::Expanded(false)),
+            Some(AriaAttr::Live(Live::Assertive)),
+            None,
+            None,
+        ];
+        assert_eq!(result, expected)
+    }
+}
@@ -0,0 +1,265 @@
+use crate::{
+    parse::ParseError,
+    render::{Render, Renderer},
+};
+use std::{fmt, str::FromStr};
+
+/// Events with handler attributes, rendered with their lowercase names as in `onkeydown`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Event {
+    Abort,
+    /// Click with any but the primary button
+    AuxClick,
+    /// Before the value of an editable element changes
+    BeforeInput,
+    Blur,
+    Cancel,
+    CanPlay,
+    /// A changed value is committed, unlike [`Event::Input`] which fires on every edit
+    Change,
+    Click,
+    Close,
+    ContextMenu,
+    Copy,
+    Cut,
+    DblClick,
+    Drag,
+    DragEnd,
+    DragEnter,
+    DragLeave,
+    DragOver,
+    DragStart,
+    Drop,
+    Ended,
+    Error,
+    Focus,
+    Input,
+    Invalid,
+    KeyDown,
+    /// Deprecated in favour of [`Event::KeyDown`]
+    KeyPress,
+    KeyUp,
+    Load,
+    LoadedData,
+    LoadedMetadata,
+    MouseDown,
+    MouseEnter,
+    MouseLeave,
+    MouseMove,
+    MouseOut,
+    MouseOver,
+    MouseUp,
+    Paste,
+    Pause,
+    Play,
+    Playing,
+    PointerCancel,
+    PointerDown,
+    PointerEnter,
+    PointerLeave,
+    PointerMove,
+    PointerOut,
+    PointerOver,
+    PointerUp,
+    Reset,
+    Resize,
+    Scroll,
+    /// Scrolling has completed
+    ScrollEnd,
+    Select,
+    Submit,
+    TimeUpdate,
+    /// A `<details>` or popover is opened or closed
+    Toggle,
+    TouchCancel,
+    TouchEnd,
+    TouchMove,
+    TouchStart,
+    VolumeChange,
+    Wheel,
+}
+
+impl Event {
+    pub const ALL: [Event; 64] = [
+        Event::Abort,
+        Event::AuxClick,
+        Event::BeforeInput,
+        Event::Blur,
+        Event::Cancel,
+        Event::CanPlay,
+        Event::Change,
+        Event::Click,
+        Event::Close,
+        Event::ContextMenu,
+        Event::Copy,
+        Event::Cut,
+        Event::DblClick,
+        Event::Drag,
+        Event::DragEnd,
+        Event::DragEnter,
+        Event::DragLeave,
+        Event::DragOver,
+        Event::DragStart,
+        Event::Drop,
+        Event::Ended,
+        Event::Error,
+        Event::Focus,
+        Event::Input,
+        Event::Invalid,
+        Event::KeyDown,
+        Event::KeyPress,
+        Event::KeyUp,
+        Event::Load,
+        Event::LoadedData,
+        Event::LoadedMetadata,
+        Event::MouseDown,
+        Event::MouseEnter,
+        Event::MouseLeave,
+        Event::MouseMove,
+        Event::MouseOut,
+        Event::MouseOver,
+        Event::MouseUp,
+        Event::Paste,
+        Event::Pause,
+        Event::Play,
+        Event::Playing,
+        Event::PointerCancel,
+        Event::PointerDown,
+        Event::PointerEnter,
+        Event::PointerLeave,
+        Event::PointerMove,
+        Event::PointerOut,
+        Event::PointerOver,
+        Event::PointerUp,
+        Event::Reset,
+        Event::Resize,
+        Event::Scroll,
+        Event::ScrollEnd,
+        Event::Select,
+        Event::Submit,
+        Event::TimeUpdate,
+        Event::Toggle,
+        Event::TouchCancel,
+        Event::TouchEnd,
+        Event::TouchMove,
+        Event::TouchStart,
+        Event::VolumeChange,
+        Event::Wheel,
+    ];
+
+    /// The name without the `on` prefix
+    pub fn name(&self) -> &'static str {
+        match self {
+            Event::Abort => "abort",
+            Event::AuxClick => "auxclick",
+            Event::BeforeInput => "beforeinput",
+            Event::Blur => "blur",
+            Event::Cancel => "cancel",
+            Event::CanPlay => "canplay",
+            Event::Change => "change",
+            Event::Click => "click",
+            Event::Close => "close",
+            Event::ContextMenu => "contextmenu",
+            Event::Copy => "copy",
+            Event::Cut => "cut",
+            Event::DblClick => "dblclick",
+            Event::Drag => "drag",
+            Event::DragEnd => "dragend",
+            Event::DragEnter => "dragenter",
+            Event::DragLeave => "dragleave",
+            Event::DragOver => "dragover",
+            Event::DragStart => "dragstart",
+            Event::Drop => "drop",
+            Event::Ended => "ended",
+            Event::Error => "error",
+            Event::Focus => "focus",
+            Event::Input => "input",
+            Event::Invalid => "invalid",
+            Event::KeyDown => "keydown",
+            Event::KeyPress => "keypress",
+            Event::KeyUp => "keyup",
+            Event::Load => "load",
+            Event::LoadedData => "loadeddata",
+            Event::LoadedMetadata => "loadedmetadata",
+            Event::MouseDown => "mousedown",
+            Event::MouseEnter => "mouseenter",
+            Event::MouseLeave => "mouseleave",
+            Event::MouseMove => "mousemove",
+            Event::MouseOut => "mouseout",
+            Event::MouseOver => "mouseover",
+            Event::MouseUp => "mouseup",
+            Event::Paste => "paste",
+            Event::Pause => "pause",
+            Event::Play => "play",
+            Event::Playing => "playing",
+            Event::PointerCancel => "pointercancel",
+            Event::PointerDown => "pointerdown",
+            Event::PointerEnter => "pointerenter",
+            Event::PointerLeave => "pointerleave",
+            Event::PointerMove => "pointermove",
+            Event::PointerOut => "pointerout",
+            Event::PointerOver => "pointerover",
+            Event::PointerUp => "pointerup",
+            Event::Reset => "reset",
+            Event::Resize => "resize",
+            Event::Scroll => "scroll",
+            Event::ScrollEnd => "scrollend",
+            Event::Select => "select",
+            Event::Submit => "submit",
+            Event::TimeUpdate => "timeupdate",
+            Event::Toggle => "toggle",
+            Event::TouchCancel => "touchcancel",
+            Event::TouchEnd => "touchend",
+            Event::TouchMove => "touchmove",
+            Event::TouchStart => "touchstart",
+            Event::VolumeChange => "volumechange",
+            Event::Wheel => "wheel",
+        }
+    }
+}
+
+impl Render for Event {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(self.name())
+    }
+}
+
+impl FromStr for Event {
+    type Err = ParseError;
+    /// Parses the name without the `on` prefix, ignoring case
+    fn from_str(s: &str) -> Result<Event, ParseError> {
+        Event::ALL
+            .into_iter()
+            .find(|event| event.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| ParseError::at(s, 0, &format!("unknown event `{s}`")))
+    }
+}
+
+#[cfg(test)]
+mod event_tests {
+    use super::{Event, Render};
+    use std::str::FromStr;
+
+    #[test]
+    fn render_event() {
+        let result =
+            [Event::KeyDown, Event::DblClick, Event::PointerUp].map(|event| event.render());
+        let expected = ["keydown", "dblclick", "pointerup"];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_event() {
+        let result = ["KeyUp", "touchstart", "dragover"].map(|s| Event::from_str(s).unwrap());
+        let expected = [Event::KeyUp, Event::TouchStart, Event::DragOver];
+        assert_eq!(result, expected);
+        assert!(Event::from_str("onclick").is_err())
+    }
+
+    #[test]
+    fn event_names_roundtrip() {
+        for event in Event::ALL {
+            assert_eq!(Event::from_str(event.name()).unwrap(), event)
+        }
+    }
+}
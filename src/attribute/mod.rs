@@ -0,0 +1,774 @@
+use super::{
+    escape::escape_attribute,
+    parse::ParseError,
+    render::{Render, Renderer},
+};
+use std::{borrow::Cow, error::Error, fmt, str::FromStr};
+
+mod aria;
+mod event;
+pub use aria::{AriaAttr, Live};
+pub use event::Event;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Attribute {
+    Id(String),
+    Class(Vec<String>),
+    Src(String),
+    Style(String),
+    Href(String),
+    Rel(String),
+    Type(String),
+    Value(String),
+    Title(String),
+    Name(String),
+    Action(String),
+    Method(Method),
+    For(String),
+    Placeholder(String),
+    /// Id of the `<datalist>` suggesting values for an input
+    List(String),
+    Disabled,
+    Required,
+    Min(String),
+    Max(String),
+    Step(String),
+    /// Comma separated file extensions or mime types
+    Accept(String),
+    Multiple,
+    Checked,
+    Colspan(u32),
+    Rowspan(u32),
+    /// Cells a header cell applies to
+    Scope(Scope),
+    /// Number of columns of a `<col>` or `<colgroup>`
+    Span(u32),
+    Alt(String),
+    Width(u32),
+    Height(u32),
+    /// Candidate images with their widths or pixel densities
+    Srcset(String),
+    Sizes(String),
+    Loading(Loading),
+    Controls,
+    Autoplay,
+    Muted,
+    Loop,
+    Poster(String),
+    Preload(Preload),
+    /// Media query a `<source>` applies to
+    Media(String),
+    Kind(TrackKind),
+    Srclang(String),
+    Label(String),
+    /// Marks the `<track>` enabled by default
+    Default,
+    Charset(String),
+    /// Value of a named `<meta>`
+    Content(String),
+    /// Language of the content, such as `en` or `he`
+    Lang(String),
+    Dir(Dir),
+    Role(String),
+    /// Browsing context a link or form opens in, such as `_blank`
+    Target(String),
+    /// `data-{key}`, built with [`Attribute::data`] to check the key
+    Data(String, String),
+    Aria(AriaAttr),
+    /// Any other attribute, without a value it is rendered as a boolean attribute.
+    /// Built with [`Attribute::custom`] to check the name
+    Custom(String, Option<String>),
+    /// Handler for an event, the script is escaped like any other value
+    On(Event, String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AttributeError {
+    /// A name that is empty or contains whitespace, quotes, `/`, `=`, `>` or control characters
+    InvalidName(String),
+    /// A key of a data attribute that is no valid name or contains upper case letters
+    InvalidDataKey(String),
+}
+
+impl fmt::Display for AttributeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AttributeError::InvalidName(name) => write!(f, "`{name}` is no valid attribute name"),
+            AttributeError::InvalidDataKey(key) => write!(f, "`{key}` is no valid data key"),
+        }
+    }
+}
+
+impl Error for AttributeError {}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.chars().any(|c| {
+            c.is_whitespace() || c.is_control() || matches!(c, '"' | '\'' | '/' | '=' | '>' | '<')
+        })
+}
+
+/// How a form is submitted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+    /// Closes the dialog the form is in
+    Dialog,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scope {
+    Row,
+    Col,
+    RowGroup,
+    ColGroup,
+}
+
+/// When an image is fetched
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Loading {
+    Eager,
+    /// Once the image is about to be scrolled into view
+    Lazy,
+}
+
+/// How much of a video or audio is loaded before playback
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Preload {
+    None,
+    Metadata,
+    Auto,
+}
+
+/// What a `<track>` is used for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrackKind {
+    Subtitles,
+    Captions,
+    Descriptions,
+    Chapters,
+    Metadata,
+}
+
+/// Direction of text
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dir {
+    Ltr,
+    Rtl,
+    /// Decided by the first strongly directional character
+    Auto,
+}
+
+impl Render for Dir {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(match self {
+            Dir::Ltr => "ltr",
+            Dir::Rtl => "rtl",
+            Dir::Auto => "auto",
+        })
+    }
+}
+
+impl FromStr for Dir {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Dir, ParseError> {
+        match s.to_ascii_lowercase().as_str() {
+            "ltr" => Ok(Dir::Ltr),
+            "rtl" => Ok(Dir::Rtl),
+            "auto" => Ok(Dir::Auto),
+            _ => Err(ParseError::at(s, 0, &format!("unknown dir `{s}`"))),
+        }
+    }
+}
+
+impl Render for Loading {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(match self {
+            Loading::Eager => "eager",
+            Loading::Lazy => "lazy",
+        })
+    }
+}
+
+impl FromStr for Loading {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Loading, ParseError> {
+        match s.to_ascii_lowercase().as_str() {
+            "eager" => Ok(Loading::Eager),
+            "lazy" => Ok(Loading::Lazy),
+            _ => Err(ParseError::at(s, 0, &format!("unknown loading `{s}`"))),
+        }
+    }
+}
+
+impl Render for Preload {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(match self {
+            Preload::None => "none",
+            Preload::Metadata => "metadata",
+            Preload::Auto => "auto",
+        })
+    }
+}
+
+impl FromStr for Preload {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Preload, ParseError> {
+        match s.to_ascii_lowercase().as_str() {
+            "none" => Ok(Preload::None),
+            "metadata" => Ok(Preload::Metadata),
+            // an empty value means auto
+            "auto" | "" => Ok(Preload::Auto),
+            _ => Err(ParseError::at(s, 0, &format!("unknown preload `{s}`"))),
+        }
+    }
+}
+
+impl Render for TrackKind {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(match self {
+            TrackKind::Subtitles => "subtitles",
+            TrackKind::Captions => "captions",
+            TrackKind::Descriptions => "descriptions",
+            TrackKind::Chapters => "chapters",
+            TrackKind::Metadata => "metadata",
+        })
+    }
+}
+
+impl FromStr for TrackKind {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<TrackKind, ParseError> {
+        match s.to_ascii_lowercase().as_str() {
+            "subtitles" => Ok(TrackKind::Subtitles),
+            "captions" => Ok(TrackKind::Captions),
+            "descriptions" => Ok(TrackKind::Descriptions),
+            "chapters" => Ok(TrackKind::Chapters),
+            "metadata" => Ok(TrackKind::Metadata),
+            _ => Err(ParseError::at(s, 0, &format!("unknown track kind `{s}`"))),
+        }
+    }
+}
+
+impl Render for Scope {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(match self {
+            Scope::Row => "row",
+            Scope::Col => "col",
+            Scope::RowGroup => "rowgroup",
+            Scope::ColGroup => "colgroup",
+        })
+    }
+}
+
+impl FromStr for Scope {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Scope, ParseError> {
+        match s.to_ascii_lowercase().as_str() {
+            "row" => Ok(Scope::Row),
+            "col" => Ok(Scope::Col),
+            "rowgroup" => Ok(Scope::RowGroup),
+            "colgroup" => Ok(Scope::ColGroup),
+            _ => Err(ParseError::at(s, 0, &format!("unknown scope `{s}`"))),
+        }
+    }
+}
+
+impl Render for Method {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(match self {
+            Method::Get => "get",
+            Method::Post => "post",
+            Method::Dialog => "dialog",
+        })
+    }
+}
+
+impl FromStr for Method {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Method, ParseError> {
+        match s.to_ascii_lowercase().as_str() {
+            "get" => Ok(Method::Get),
+            "post" => Ok(Method::Post),
+            "dialog" => Ok(Method::Dialog),
+            _ => Err(ParseError::at(s, 0, &format!("unknown method `{s}`"))),
+        }
+    }
+}
+
+impl Attribute {
+    /// `data-{key}`, the key may not contain upper case letters
+    pub fn data(key: &str, value: &str) -> Result<Attribute, AttributeError> {
+        if is_valid_name(key) && !key.contains(|c: char| c.is_ascii_uppercase()) {
+            Ok(Attribute::Data(key.to_owned(), value.to_owned()))
+        } else {
+            Err(AttributeError::InvalidDataKey(key.to_owned()))
+        }
+    }
+
+    /// An attribute the crate has no variant for, `None` renders it without a value
+    pub fn custom(name: &str, value: Option<&str>) -> Result<Attribute, AttributeError> {
+        if is_valid_name(name) {
+            Ok(Attribute::Custom(name.to_owned(), value.map(str::to_owned)))
+        } else {
+            Err(AttributeError::InvalidName(name.to_owned()))
+        }
+    }
+
+    pub(crate) fn name(&self) -> Cow<'_, str> {
+        let name = match self {
+            Attribute::Id(_) => "id",
+            Attribute::Class(_) => "class",
+            Attribute::Src(_) => "src",
+            Attribute::Style(_) => "style",
+            Attribute::Href(_) => "href",
+            Attribute::Rel(_) => "rel",
+            Attribute::Type(_) => "type",
+            Attribute::Value(_) => "value",
+            Attribute::Title(_) => "title",
+            Attribute::Name(_) => "name",
+            Attribute::Action(_) => "action",
+            Attribute::Method(_) => "method",
+            Attribute::For(_) => "for",
+            Attribute::Placeholder(_) => "placeholder",
+            Attribute::List(_) => "list",
+            Attribute::Disabled => "disabled",
+            Attribute::Required => "required",
+            Attribute::Min(_) => "min",
+            Attribute::Max(_) => "max",
+            Attribute::Step(_) => "step",
+            Attribute::Accept(_) => "accept",
+            Attribute::Multiple => "multiple",
+            Attribute::Checked => "checked",
+            Attribute::Colspan(_) => "colspan",
+            Attribute::Rowspan(_) => "rowspan",
+            Attribute::Scope(_) => "scope",
+            Attribute::Span(_) => "span",
+            Attribute::Alt(_) => "alt",
+            Attribute::Width(_) => "width",
+            Attribute::Height(_) => "height",
+            Attribute::Srcset(_) => "srcset",
+            Attribute::Sizes(_) => "sizes",
+            Attribute::Loading(_) => "loading",
+            Attribute::Controls => "controls",
+            Attribute::Autoplay => "autoplay",
+            Attribute::Muted => "muted",
+            Attribute::Loop => "loop",
+            Attribute::Poster(_) => "poster",
+            Attribute::Preload(_) => "preload",
+            Attribute::Media(_) => "media",
+            Attribute::Kind(_) => "kind",
+            Attribute::Srclang(_) => "srclang",
+            Attribute::Label(_) => "label",
+            Attribute::Default => "default",
+            Attribute::Charset(_) => "charset",
+            Attribute::Content(_) => "content",
+            Attribute::Lang(_) => "lang",
+            Attribute::Dir(_) => "dir",
+            Attribute::Role(_) => "role",
+            Attribute::Target(_) => "target",
+            Attribute::Data(key, _) => return Cow::Owned(format!("data-{key}")),
+            Attribute::Aria(aria) => aria.name(),
+            Attribute::Custom(name, _) => return Cow::Borrowed(name),
+            Attribute::On(event, _) => return Cow::Owned(format!("on{}", event.name())),
+        };
+        Cow::Borrowed(name)
+    }
+
+    /// Whether the name can be written into a tag, see [`Attribute::custom`]
+    pub fn has_valid_name(&self) -> bool {
+        is_valid_name(&self.name())
+    }
+
+    /// `None` for boolean attributes, which are set by being present
+    fn value(&self) -> Option<String> {
+        let value = match self {
+            Attribute::Class(classes) => classes.join(" "),
+            Attribute::Id(val)
+            | Attribute::Src(val)
+            | Attribute::Style(val)
+            | Attribute::Href(val)
+            | Attribute::Rel(val)
+            | Attribute::Type(val)
+            | Attribute::Value(val)
+            | Attribute::Title(val)
+            | Attribute::Name(val)
+            | Attribute::Action(val)
+            | Attribute::For(val)
+            | Attribute::Placeholder(val)
+            | Attribute::List(val)
+            | Attribute::Min(val)
+            | Attribute::Max(val)
+            | Attribute::Step(val)
+            | Attribute::Accept(val)
+            | Attribute::Alt(val)
+            | Attribute::Srcset(val)
+            | Attribute::Sizes(val)
+            | Attribute::Poster(val)
+            | Attribute::Media(val)
+            | Attribute::Srclang(val)
+            | Attribute::Label(val)
+            | Attribute::Charset(val)
+            | Attribute::Content(val)
+            | Attribute::Lang(val)
+            | Attribute::Role(val)
+            | Attribute::Target(val)
+            | Attribute::Data(_, val)
+            | Attribute::On(_, val) => val.clone(),
+            Attribute::Aria(aria) => aria.value(),
+            Attribute::Custom(_, val) => return val.clone(),
+            Attribute::Method(method) => method.render(),
+            Attribute::Colspan(n)
+            | Attribute::Rowspan(n)
+            | Attribute::Span(n)
+            | Attribute::Width(n)
+            | Attribute::Height(n) => n.to_string(),
+            Attribute::Scope(scope) => scope.render(),
+            Attribute::Loading(loading) => loading.render(),
+            Attribute::Preload(preload) => preload.render(),
+            Attribute::Kind(kind) => kind.render(),
+            Attribute::Dir(dir) => dir.render(),
+            Attribute::Disabled
+            | Attribute::Required
+            | Attribute::Multiple
+            | Attribute::Checked
+            | Attribute::Controls
+            | Attribute::Autoplay
+            | Attribute::Muted
+            | Attribute::Loop
+            | Attribute::Default => return None,
+        };
+        Some(value)
+    }
+}
+
+/// An attribute whose name is not valid, only possible for [`Attribute::Data`] and
+/// [`Attribute::Custom`] built directly, renders as nothing and is left out of tags
+impl Render for Attribute {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        let name = self.name();
+        if !is_valid_name(&name) {
+            return Ok(());
+        }
+        out.write_str(&name)?;
+        match self.value() {
+            None => Ok(()),
+            Some(value) => {
+                out.write_str("=\"")?;
+                out.write_str(&escape_attribute(&value))?;
+                out.write_str("\"")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod attribute_tests {
+    use super::{
+        AriaAttr, Attribute, AttributeError, Dir, Event, Loading, Method, Preload, Render, Scope,
+        TrackKind,
+    };
+    use std::str::FromStr;
+
+    #[test]
+    fn render_id() {
+        let result = Attribute::Id("test_id".to_owned()).render();
+        let expected = "id=\"test_id\"";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_class() {
+        let result = Attribute::Class(vec!["class1".to_owned(), "class2".to_owned()]).render();
+        let expected = "class=\"class1 class2\"";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_src() {
+        let result = Attribute::Src("file.jpg".to_owned()).render();
+        let expected = "src=\"file.jpg\"";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_style() {
+        let result = Attribute::Style("height:100%;".to_owned()).render();
+        let expected = "style=\"height:100%;\"";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_href() {
+        let result = Attribute::Href("index.html".to_owned()).render();
+        let expected = "href=\"index.html\"";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_rel() {
+        let result = Attribute::Rel("stylesheet".to_owned()).render();
+        let expected = "rel=\"stylesheet\"";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_type() {
+        let result = Attribute::Type("javascript".to_owned()).render();
+        let expected = "type=\"javascript\"";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_onchange() {
+        let result = Attribute::On(Event::Change, "alert()".to_owned()).render();
+        let expected = "onchange=\"alert()\"";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_onload() {
+        let result = Attribute::On(Event::Load, "alert()".to_owned()).render();
+        let expected = "onload=\"alert()\"";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_onkeyup() {
+        let result = Attribute::On(Event::KeyUp, "alert()".to_owned()).render();
+        let expected = "onkeyup=\"alert()\"";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_onclick() {
+        let result = Attribute::On(Event::Click, "alert()".to_owned()).render();
+        let expected = "onclick=\"alert()\"";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_event_handlers() {
+        let result = [
+            Attribute::On(Event::KeyDown, "next(event)".to_owned()),
+            Attribute::On(Event::Submit, "return check('a & b')".to_owned()),
+            Attribute::On(Event::PointerEnter, "show(\"tip\")".to_owned()),
+        ]
+        .map(|attr| attr.render());
+        let expected = [
+            "onkeydown=\"next(event)\"",
+            "onsubmit=\"return check(&#39;a &amp; b&#39;)\"",
+            "onpointerenter=\"show(&quot;tip&quot;)\"",
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_value() {
+        let result = Attribute::Value("a \"b\"".to_owned()).render();
+        let expected = "value=\"a &quot;b&quot;\"";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_form_attributes() {
+        let result: Vec<String> = [
+            Attribute::Name("email".to_owned()),
+            Attribute::Action("/login".to_owned()),
+            Attribute::Method(Method::Post),
+            Attribute::For("email".to_owned()),
+            Attribute::Placeholder("you@example.com".to_owned()),
+            Attribute::List("domains".to_owned()),
+        ]
+        .iter()
+        .map(Attribute::render)
+        .collect();
+        let expected = vec![
+            "name=\"email\"",
+            "action=\"/login\"",
+            "method=\"post\"",
+            "for=\"email\"",
+            "placeholder=\"you@example.com\"",
+            "list=\"domains\"",
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_boolean() {
+        let result = [
+            Attribute::Disabled,
+            Attribute::Required,
+            Attribute::Multiple,
+            Attribute::Checked,
+        ]
+        .map(|attr| attr.render());
+        let expected = ["disabled", "required", "multiple", "checked"];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_table_attributes() {
+        let result = [
+            Attribute::Colspan(2),
+            Attribute::Rowspan(3),
+            Attribute::Scope(Scope::ColGroup),
+            Attribute::Span(4),
+        ]
+        .map(|attr| attr.render());
+        let expected = [
+            "colspan=\"2\"",
+            "rowspan=\"3\"",
+            "scope=\"colgroup\"",
+            "span=\"4\"",
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_media_attributes() {
+        let result = [
+            Attribute::Width(640),
+            Attribute::Loading(Loading::Lazy),
+            Attribute::Preload(Preload::Metadata),
+            Attribute::Kind(TrackKind::Captions),
+            Attribute::Muted,
+            Attribute::Default,
+        ]
+        .map(|attr| attr.render());
+        let expected = [
+            "width=\"640\"",
+            "loading=\"lazy\"",
+            "preload=\"metadata\"",
+            "kind=\"captions\"",
+            "muted",
+            "default",
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_media_enums() {
+        let result = (
+            Loading::from_str("Eager").unwrap(),
+            Preload::from_str("").unwrap(),
+            TrackKind::from_str("chapters").unwrap(),
+        );
+        let expected = (Loading::Eager, Preload::Auto, TrackKind::Chapters);
+        assert_eq!(result, expected);
+        assert!(TrackKind::from_str("audio").is_err())
+    }
+
+    #[test]
+    fn render_lang_dir() {
+        let result =
+            [Attribute::Lang("he".to_owned()), Attribute::Dir(Dir::Rtl)].map(|attr| attr.render());
+        let expected = ["lang=\"he\"", "dir=\"rtl\""];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_dir() {
+        let result = ["LTR", "rtl", "auto"].map(|s| Dir::from_str(s).unwrap());
+        let expected = [Dir::Ltr, Dir::Rtl, Dir::Auto];
+        assert_eq!(result, expected);
+        assert!(Dir::from_str("up").is_err())
+    }
+
+    #[test]
+    fn render_open_attributes() {
+        let result = [
+            Attribute::data("user-id", "4\"2").unwrap(),
+            Attribute::Aria(AriaAttr::Expanded(false)),
+            Attribute::Role("tab".to_owned()),
+            Attribute::Target("_blank".to_owned()),
+            Attribute::custom("tabindex", Some("-1")).unwrap(),
+            Attribute::custom("hidden", None).unwrap(),
+        ]
+        .map(|attr| attr.render());
+        let expected = [
+            "data-user-id=\"4&quot;2\"",
+            "aria-expanded=\"false\"",
+            "role=\"tab\"",
+            "target=\"_blank\"",
+            "tabindex=\"-1\"",
+            "hidden",
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn invalid_names() {
+        let result = [
+            Attribute::data("userId", ""),
+            Attribute::data("", ""),
+            Attribute::custom("on click", None),
+            Attribute::custom("a\"=b", Some("c")),
+            Attribute::custom("", None),
+        ];
+        let expected = [
+            Err(AttributeError::InvalidDataKey("userId".to_owned())),
+            Err(AttributeError::InvalidDataKey("".to_owned())),
+            Err(AttributeError::InvalidName("on click".to_owned())),
+            Err(AttributeError::InvalidName("a\"=b".to_owned())),
+            Err(AttributeError::InvalidName("".to_owned())),
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_unchecked_names() {
+        let result = [
+            Attribute::Custom("x onmouseover=alert(1) y".to_owned(), None),
+            Attribute::Data("x\"><script>".to_owned(), "a".to_owned()),
+            Attribute::Custom("ok".to_owned(), None),
+        ]
+        .map(|attr| attr.render());
+        let expected = ["", "", "ok"];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn invalid_name_display() {
+        let result = AttributeError::InvalidName("a b".to_owned()).to_string();
+        let expected = "`a b` is no valid attribute name";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_method() {
+        let result = ["GET", "post", "dialog"].map(|s| Method::from_str(s).unwrap());
+        let expected = [Method::Get, Method::Post, Method::Dialog];
+        assert_eq!(result, expected);
+        assert!(Method::from_str("put").is_err())
+    }
+
+    #[test]
+    fn render_title() {
+        let result = Attribute::Title("tooltip".to_owned()).render();
+        let expected = "title=\"tooltip\"";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_escaped_quotes() {
+        let result = Attribute::Href("\" onclick=\"alert()".to_owned()).render();
+        let expected = "href=\"&quot; onclick=&quot;alert()\"";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_escaped_class() {
+        let result = Attribute::Class(vec!["a<b".to_owned(), "c&d".to_owned()]).render();
+        let expected = "class=\"a&lt;b c&amp;d\"";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_escaped_handler() {
+        let result = Attribute::On(Event::Click, "alert(\"hi\")".to_owned()).render();
+        let expected = "onclick=\"alert(&quot;hi&quot;)\"";
+        assert_eq!(result, expected)
+    }
+}
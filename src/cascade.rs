@@ -0,0 +1,271 @@
+//! Resolving which css declarations apply to the elements of a document.
+//!
+//! Declarations come from every [`Style`] element in the document and from
+//! inline [`Attribute::Style`] attributes. For each property the declaration with the highest
+//! priority wins, compared by
+//! 1. `!important`
+//! 2. inline style over stylesheets
+//! 3. [`Selector::specificity`]
+//! 4. source order
+//!
+//! Shorthands such as `margin` and their longhands are treated as unrelated properties.
+use crate::{
+    attribute::Attribute,
+    css::{
+        declaration::Declaration,
+        selector::{Selector, Specificity, TopSelector},
+    },
+    elements::Style,
+    html_document::HtmlDocument,
+    parse::parse_declarations,
+    query::{matches, walk_document_paths, Frame},
+    visit::{walk_document, Node, Visitor},
+};
+
+/// The declarations that apply to an element after resolving the cascade and inheritance,
+/// with one declaration per property.
+/// Inherited declarations come first, followed by the element's own ones from lowest to
+/// highest priority.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComputedStyle<'a> {
+    pub node: Node<'a>,
+    pub declarations: Vec<Declaration>,
+}
+
+pub(crate) struct Rule<'a> {
+    pub selector: Selector,
+    specificity: Specificity,
+    declarations: &'a [Declaration],
+}
+
+impl<'a> Rule<'a> {
+    fn new(selector: Selector, declarations: &'a [Declaration]) -> Rule<'a> {
+        Rule {
+            specificity: selector.specificity(),
+            selector,
+            declarations,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Rules<'a>(Vec<Rule<'a>>);
+
+impl<'a> Visitor<'a> for Rules<'a> {
+    fn enter_style(&mut self, style: &'a Style) {
+        for block in style.style.decls.iter() {
+            match &block.selector {
+                // every selector of a list is weighted on its own
+                Selector {
+                    top: TopSelector::Multiple(tops),
+                    sub: None,
+                } => self.0.extend(
+                    tops.iter()
+                        .map(|top| Rule::new(top.clone().into(), &block.decls)),
+                ),
+                selector => self.0.push(Rule::new(selector.clone(), &block.decls)),
+            }
+        }
+    }
+}
+
+/// The rules of all style elements in `document` in source order
+pub(crate) fn stylesheet_rules(document: &HtmlDocument) -> Vec<Rule<'_>> {
+    let mut rules = Rules::default();
+    walk_document(&mut rules, document);
+    rules.0
+}
+
+/// Declarations of inline style attributes, invalid ones are ignored like browsers do
+fn inline_declarations(attributes: &[Attribute]) -> Vec<Declaration> {
+    attributes
+        .iter()
+        .filter_map(|attr| match attr {
+            Attribute::Style(style) => parse_declarations(style).ok(),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+/// The winning declarations of the last element of `path` ordered by priority,
+/// without inherited ones
+pub(crate) fn cascade(path: &[Frame], rules: &[Rule]) -> Vec<Declaration> {
+    let mut candidates = vec![];
+    for (order, rule) in rules.iter().enumerate() {
+        if matches(&rule.selector, path) {
+            for decl in rule.declarations {
+                candidates.push(((decl.important, false, rule.specificity, order), decl));
+            }
+        }
+    }
+    let inline = path
+        .last()
+        .map(|elem| inline_declarations(elem.attributes))
+        .unwrap_or_default();
+    for decl in inline.iter() {
+        candidates.push(((decl.important, true, Specificity::default(), 0), decl));
+    }
+    // stable, so later declarations of the same rule still win
+    candidates.sort_by_key(|(priority, _)| *priority);
+
+    let mut own: Vec<Declaration> = vec![];
+    for (_, decl) in candidates.into_iter().rev() {
+        if !own.iter().any(|winner| winner.property == decl.property) {
+            own.push(decl.clone());
+        }
+    }
+    own.reverse();
+    own
+}
+
+/// Puts the inherited declarations of `parent` that are not overridden before `own`
+fn inherit(parent: &[Declaration], own: Vec<Declaration>) -> Vec<Declaration> {
+    let mut computed: Vec<Declaration> = parent
+        .iter()
+        .filter(|decl| {
+            decl.property.is_inherited() && !own.iter().any(|o| o.property == decl.property)
+        })
+        .map(|decl| Declaration {
+            important: false,
+            ..decl.clone()
+        })
+        .collect();
+    computed.extend(own);
+    computed
+}
+
+/// Computes the declarations of every element of `document` in document order.
+/// Inherited properties without a declaration of their own are taken from the parent,
+/// starting at `<html>`, which is matched by selectors but not part of the result.
+pub fn compute_styles(document: &HtmlDocument) -> Vec<ComputedStyle<'_>> {
+    let rules = stylesheet_rules(document);
+    let mut stack: Vec<Vec<Declaration>> = vec![];
+    let mut styles = vec![];
+    walk_document_paths(document, |node, path| {
+        if stack.is_empty() {
+            stack.push(inherit(&[], cascade(&path[..1], &rules)));
+        }
+        stack.truncate(path.len() - 1);
+        let parent = stack.last().map(Vec::as_slice).unwrap_or_default();
+        let declarations = inherit(parent, cascade(path, &rules));
+        stack.push(declarations.clone());
+        styles.push(ComputedStyle { node, declarations });
+    });
+    styles
+}
+
+#[cfg(test)]
+mod cascade_tests {
+    use super::compute_styles;
+    use crate::{
+        css::{declaration::Declaration, selector::Selector},
+        html_document::HtmlDocument,
+        parse::{parse_declarations, parse_document},
+        render::Render,
+    };
+
+    fn document(css: &str, body: &str) -> HtmlDocument {
+        parse_document(&format!(
+            "<html><head><title>t</title><style>{css}</style></head><body>{body}</body></html>"
+        ))
+        .unwrap()
+    }
+
+    fn computed(doc: &HtmlDocument, selector: &str) -> String {
+        let node = doc
+            .query_selector(&selector.parse::<Selector>().unwrap())
+            .unwrap();
+        let style = compute_styles(doc)
+            .into_iter()
+            .find(|style| style.node == node)
+            .unwrap();
+        style
+            .declarations
+            .iter()
+            .map(Declaration::render)
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn cascade_specificity() {
+        let doc = document(
+            "#x { color: blue; } div { color: red; }",
+            "<div id=\"x\"></div>",
+        );
+        let result = computed(&doc, "div");
+        let expected = "color:blue;";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn cascade_source_order() {
+        let doc = document(
+            ".a { color: red; } .b { color: blue; }",
+            "<div class=\"a b\"></div>",
+        );
+        let result = computed(&doc, "div");
+        let expected = "color:blue;";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn cascade_important() {
+        let doc = document(
+            "div { color: red !important; } #x { color: blue; }",
+            "<div id=\"x\"></div>",
+        );
+        let result = computed(&doc, "div");
+        let expected = "color:red!important;";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn cascade_inline() {
+        let doc = document(
+            "#x { color: blue; width: 1px !important; }",
+            "<div id=\"x\" style=\"color: red; width: 2px\"></div>",
+        );
+        let result = computed(&doc, "div");
+        let expected = "color:red; width:1px!important;";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn cascade_inherit() {
+        let doc = document(
+            "body { color: red; background: blue; font-size: 2px; } a { font-size: 3px; }",
+            "<div><a href=\"/\">link</a></div>",
+        );
+        let result = computed(&doc, "a");
+        let expected = "color:red; font-size:3px;";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn cascade_root() {
+        let doc = document("html { font-family: serif; }", "<div></div>");
+        let result = computed(&doc, "div");
+        let expected = "font-family:serif;";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn cascade_selector_list() {
+        let doc = document(
+            "#x, div { color: blue; } .a { color: red; }",
+            "<div id=\"x\" class=\"a\"></div><div class=\"a\"></div>",
+        );
+        let result = compute_styles(&doc)
+            .into_iter()
+            .filter(|style| style.node.tag_name() == Some("div"))
+            .map(|style| style.declarations)
+            .collect::<Vec<_>>();
+        let expected = vec![
+            parse_declarations("color: blue").unwrap(),
+            parse_declarations("color: red").unwrap(),
+        ];
+        assert_eq!(result, expected)
+    }
+}
@@ -1,6 +1,7 @@
 use super::CssDocument;
 use super::{declaration::Declaration, selector::Selector};
-use crate::render::Render;
+use crate::render::{Render, Renderer};
+use std::fmt;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct CssBlock {
@@ -9,11 +10,25 @@ pub struct CssBlock {
 }
 
 impl Render for CssBlock {
-    fn render(&self) -> String {
-        let selector_str = self.selector.render();
-        let decls_str = self.decls.render().replace('\n', "\n\t");
-
-        format!("{selector_str} {{\n\t{decls_str}\n}}")
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        self.selector.render_into(out)?;
+        if out.options().space_after_selector {
+            out.write_str(" ")?;
+        }
+        out.write_str("{")?;
+        out.block(|out| {
+            for (i, decl) in self.decls.iter().enumerate() {
+                if i > 0 {
+                    out.newline()?;
+                }
+                decl.render_unterminated(out)?;
+                if i + 1 < self.decls.len() || out.options().trailing_semicolons {
+                    out.write_str(";")?;
+                }
+            }
+            Ok(())
+        })?;
+        out.write_str("}")
     }
 }
 
@@ -26,8 +41,14 @@ impl From<Vec<CssBlock>> for CssDocument {
 #[cfg(test)]
 mod css_block_tests {
     use super::{CssBlock, CssDocument, Render};
-    use crate::css::{
-        declaration::Declaration, property::Property, selector::TopSelector, value::Keyword,
+    use crate::{
+        css::{
+            declaration::Declaration,
+            property::{Color, Property},
+            selector::TopSelector,
+            value::{Color as ValueColor, Keyword},
+        },
+        render::RenderOptions,
     };
 
     fn example_block() -> CssBlock {
@@ -54,4 +75,37 @@ mod css_block_tests {
         let expected = example_block().render();
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn render_minified() {
+        let block = CssBlock {
+            selector: TopSelector::Tag("a".to_owned()).into(),
+            decls: vec![
+                (Color::Color.into(), ValueColor::Rgb(255, 255, 255).into()).into(),
+                (Property::Display, Keyword::Non.into()).into(),
+            ],
+        };
+        let result = block.render_with(&RenderOptions::minified());
+        let expected = "a{color:#ffffff;display:none}";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_compact() {
+        let result = example_block().render_with(&RenderOptions::compact());
+        let expected = "#container {\ndisplay:none;\n}";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_no_trailing_semicolon() {
+        let options = RenderOptions {
+            trailing_semicolons: false,
+            space_after_selector: false,
+            ..RenderOptions::pretty()
+        };
+        let result = example_block().render_with(&options);
+        let expected = "#container{\n\tdisplay:none\n}";
+        assert_eq!(result, expected)
+    }
 }
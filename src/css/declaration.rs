@@ -1,5 +1,6 @@
 use super::{property::Property, value::Value};
-use crate::render::Render;
+use crate::render::{Render, Renderer};
+use std::fmt;
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct Declaration {
@@ -8,16 +9,23 @@ pub struct Declaration {
     pub important: bool,
 }
 
+impl Declaration {
+    /// Writes the declaration without its terminating `;`
+    pub fn render_unterminated<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        self.property.render_into(out)?;
+        out.write_str(":")?;
+        self.value.render_into(out)?;
+        if self.important {
+            out.write_str("!important")?;
+        }
+        Ok(())
+    }
+}
+
 impl Render for Declaration {
-    fn render(&self) -> String {
-        let property_str = self.property.render();
-        let value_str = self.value.render();
-        let imp_str = if self.important {
-            "!important".to_owned()
-        } else {
-            "".to_owned()
-        };
-        format!("{property_str}:{value_str}{imp_str};")
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        self.render_unterminated(out)?;
+        out.write_str(";")
     }
 }
 
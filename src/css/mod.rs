@@ -6,9 +6,10 @@ pub mod value;
 
 use crate::{
     elements::{HtmlElement, Style},
-    render::Render,
+    render::{Render, Renderer},
 };
 use block::CssBlock;
+use std::fmt;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct CssDocument {
@@ -16,8 +17,8 @@ pub struct CssDocument {
 }
 
 impl Render for CssDocument {
-    fn render(&self) -> String {
-        self.decls.render()
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        self.decls.render_into(out)
     }
 }
 
@@ -69,4 +70,12 @@ mod css_tests {
             + "\n</style>";
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn render_to_nested() {
+        let mut result = String::new();
+        example_document().render_to(&mut result, 1).unwrap();
+        let expected = example_document().render().replace('\n', "\n\t");
+        assert_eq!(result, expected)
+    }
 }
@@ -1,5 +1,6 @@
 use super::{Direction, Property};
-use crate::render::Render;
+use crate::render::{Render, Renderer};
+use std::fmt;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum Border {
@@ -11,18 +12,18 @@ pub enum Border {
 }
 
 impl Render for Border {
-    fn render(&self) -> String {
-        match self {
-            Border::Side(Direction::Top) => "border-top".to_owned(),
-            Border::Side(Direction::Bottom) => "border-bottom".to_owned(),
-            Border::Side(Direction::Left) => "border-left".to_owned(),
-            Border::Side(Direction::Right) => "border-right".to_owned(),
-            Border::Side(Direction::All) => "border".to_owned(),
-            Border::Color => "border-color".to_owned(),
-            Border::Style => "border-style".to_owned(),
-            Border::Radius => "border-radius".to_owned(),
-            Border::Collapse => "border-collapse".to_owned(),
-        }
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(match self {
+            Border::Side(Direction::Top) => "border-top",
+            Border::Side(Direction::Bottom) => "border-bottom",
+            Border::Side(Direction::Left) => "border-left",
+            Border::Side(Direction::Right) => "border-right",
+            Border::Side(Direction::All) => "border",
+            Border::Color => "border-color",
+            Border::Style => "border-style",
+            Border::Radius => "border-radius",
+            Border::Collapse => "border-collapse",
+        })
     }
 }
 
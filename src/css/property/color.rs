@@ -1,5 +1,6 @@
 use super::Property;
-use crate::render::Render;
+use crate::render::{Render, Renderer};
+use std::fmt;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum Color {
@@ -8,11 +9,11 @@ pub enum Color {
 }
 
 impl Render for Color {
-    fn render(&self) -> String {
-        match self {
-            Color::Background => "background".to_owned(),
-            Color::Color => "color".to_owned(),
-        }
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(match self {
+            Color::Background => "background",
+            Color::Color => "color",
+        })
     }
 }
 
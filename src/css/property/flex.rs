@@ -1,5 +1,6 @@
 use super::Property;
-use crate::render::Render;
+use crate::render::{Render, Renderer};
+use std::fmt;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum Flex {
@@ -13,16 +14,16 @@ pub enum Flex {
 }
 
 impl Render for Flex {
-    fn render(&self) -> String {
-        match self {
-            Flex::AlignContent => "align-content".to_owned(),
-            Flex::AlignItems => "align-items".to_owned(),
-            Flex::AlignSelf => "align-self".to_owned(),
-            Flex::FlexWrap => "flex-wrap".to_owned(),
-            Flex::FlexDirection => "flex-direction".to_owned(),
-            Flex::Gap => "gap".to_owned(),
-            Flex::JustifyContent => "justify-content".to_owned(),
-        }
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(match self {
+            Flex::AlignContent => "align-content",
+            Flex::AlignItems => "align-items",
+            Flex::AlignSelf => "align-self",
+            Flex::FlexWrap => "flex-wrap",
+            Flex::FlexDirection => "flex-direction",
+            Flex::Gap => "gap",
+            Flex::JustifyContent => "justify-content",
+        })
     }
 }
 
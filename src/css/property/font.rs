@@ -1,5 +1,6 @@
 use super::Property;
-use crate::render::Render;
+use crate::render::{Render, Renderer};
+use std::fmt;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Font {
@@ -9,12 +10,12 @@ pub enum Font {
 }
 
 impl Render for Font {
-    fn render(&self) -> String {
-        match self {
-            Font::Family => "font-family".to_owned(),
-            Font::Weight => "font-weight".to_owned(),
-            Font::Size => "font-size".to_owned(),
-        }
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(match self {
+            Font::Family => "font-family",
+            Font::Weight => "font-weight",
+            Font::Size => "font-size",
+        })
     }
 }
 
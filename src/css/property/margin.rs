@@ -1,5 +1,6 @@
 use super::{Direction, Property};
-use crate::render::Render;
+use crate::render::{Render, Renderer};
+use std::fmt;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Margin {
@@ -7,14 +8,14 @@ pub struct Margin {
 }
 
 impl Render for Margin {
-    fn render(&self) -> String {
-        match self.dir {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(&match self.dir {
             Direction::Top => "margin-top".to_owned(),
             Direction::Bottom => "margin-bottom".to_owned(),
             Direction::Left => "margin-left".to_owned(),
             Direction::Right => "margin-right".to_owned(),
             Direction::All => "margin".to_owned(),
-        }
+        })
     }
 }
 
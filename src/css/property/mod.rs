@@ -16,7 +16,11 @@ pub use padding::Padding;
 pub use position::Position;
 pub use size::Size;
 
-use crate::render::Render;
+use crate::{
+    parse::ParseError,
+    render::{Render, Renderer},
+};
+use std::{fmt, str::FromStr};
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum Property {
@@ -35,6 +39,8 @@ pub enum Property {
     Padding(Padding),
     Var(String),
     Border(Border),
+    /// Any property that is not modelled, rendered as is
+    Other(String),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -46,9 +52,24 @@ pub enum Direction {
     All,
 }
 
+impl Property {
+    /// Whether elements take the computed value of their parent when they do not set this property
+    pub fn is_inherited(&self) -> bool {
+        matches!(
+            self,
+            Property::TextAlign
+                | Property::Cursor
+                | Property::LineHeight
+                | Property::Color(Color::Color)
+                | Property::Font(_)
+                | Property::Var(_)
+        )
+    }
+}
+
 impl Render for Property {
-    fn render(&self) -> String {
-        match self {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(&match self {
             Property::TextAlign => "text-align".to_owned(),
             Property::Cursor => "cursor".to_owned(),
             Property::Display => "display".to_owned(),
@@ -64,7 +85,97 @@ impl Render for Property {
             Property::Padding(padding) => padding.render(),
             Property::Border(border) => border.render(),
             Property::Var(v) => format!("--{v}"),
+            Property::Other(other) => other.clone(),
+        })
+    }
+}
+
+impl FromStr for Property {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Property, ParseError> {
+        if let Some(var) = s.strip_prefix("--") {
+            return Ok(Property::Var(var.to_owned()));
         }
+        let property = match s.to_ascii_lowercase().as_str() {
+            "text-align" => Property::TextAlign,
+            "cursor" => Property::Cursor,
+            "display" => Property::Display,
+            "overflow" => Property::Overflow,
+            "float" => Property::Float,
+            "line-height" => Property::LineHeight,
+            "position" => Position::Position.into(),
+            "top" => Position::Top.into(),
+            "bottom" => Position::Bottom.into(),
+            "left" => Position::Left.into(),
+            "right" => Position::Right.into(),
+            "background" => Color::Background.into(),
+            "color" => Color::Color.into(),
+            "width" => Size::Width.into(),
+            "max-width" => Size::MaxWidth.into(),
+            "height" => Size::Height.into(),
+            "max-height" => Size::MaxHeight.into(),
+            "align-content" => Flex::AlignContent.into(),
+            "align-items" => Flex::AlignItems.into(),
+            "align-self" => Flex::AlignSelf.into(),
+            "flex-wrap" => Flex::FlexWrap.into(),
+            "flex-direction" => Flex::FlexDirection.into(),
+            "gap" => Flex::Gap.into(),
+            "justify-content" => Flex::JustifyContent.into(),
+            "font-family" => Font::Family.into(),
+            "font-weight" => Font::Weight.into(),
+            "font-size" => Font::Size.into(),
+            "border-top" => Border::Side(Direction::Top).into(),
+            "border-bottom" => Border::Side(Direction::Bottom).into(),
+            "border-left" => Border::Side(Direction::Left).into(),
+            "border-right" => Border::Side(Direction::Right).into(),
+            "border" => Border::Side(Direction::All).into(),
+            "border-color" => Border::Color.into(),
+            "border-style" => Border::Style.into(),
+            "border-radius" => Border::Radius.into(),
+            "border-collapse" => Border::Collapse.into(),
+            "margin-top" => Margin {
+                dir: Direction::Top,
+            }
+            .into(),
+            "margin-bottom" => Margin {
+                dir: Direction::Bottom,
+            }
+            .into(),
+            "margin-left" => Margin {
+                dir: Direction::Left,
+            }
+            .into(),
+            "margin-right" => Margin {
+                dir: Direction::Right,
+            }
+            .into(),
+            "margin" => Margin {
+                dir: Direction::All,
+            }
+            .into(),
+            "padding-top" => Padding {
+                dir: Direction::Top,
+            }
+            .into(),
+            "padding-bottom" => Padding {
+                dir: Direction::Bottom,
+            }
+            .into(),
+            "padding-left" => Padding {
+                dir: Direction::Left,
+            }
+            .into(),
+            "padding-right" => Padding {
+                dir: Direction::Right,
+            }
+            .into(),
+            "padding" => Padding {
+                dir: Direction::All,
+            }
+            .into(),
+            _ => return Err(ParseError::at(s, 0, &format!("unknown property `{s}`"))),
+        };
+        Ok(property)
     }
 }
 
@@ -73,6 +184,7 @@ mod property_tests {
     use super::{
         Border, Color, Direction, Flex, Font, Margin, Padding, Position, Property, Render, Size,
     };
+    use std::str::FromStr;
 
     #[test]
     fn render_align() {
@@ -177,4 +289,51 @@ mod property_tests {
         let expected = "--bg-color";
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn render_other() {
+        let result = Property::Other("grid-template-areas".to_owned()).render();
+        let expected = "grid-template-areas";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_rendered() {
+        let properties = vec![
+            Property::TextAlign,
+            Property::Position(Position::Top),
+            Property::Color(Color::Background),
+            Property::Size(Size::MaxWidth),
+            Property::Flex(Flex::JustifyContent),
+            Property::Font(Font::Family),
+            Property::Margin(Margin {
+                dir: Direction::All,
+            }),
+            Property::Padding(Padding {
+                dir: Direction::Left,
+            }),
+            Property::Border(Border::Side(Direction::Bottom)),
+            Property::Var("bg-color".to_owned()),
+        ];
+        for property in properties {
+            assert_eq!(Property::from_str(&property.render()).unwrap(), property)
+        }
+    }
+
+    #[test]
+    fn parse_unknown() {
+        let result = Property::from_str("grid-area").unwrap_err();
+        assert_eq!(result.message, "unknown property `grid-area`")
+    }
+
+    #[test]
+    fn inherited_properties() {
+        assert!(Property::Color(Color::Color).is_inherited());
+        assert!(Property::Font(Font::Family).is_inherited());
+        assert!(!Property::Color(Color::Background).is_inherited());
+        assert!(!Property::Margin(Margin {
+            dir: Direction::All
+        })
+        .is_inherited())
+    }
 }
@@ -1,5 +1,6 @@
 use super::{Direction, Property};
-use crate::render::Render;
+use crate::render::{Render, Renderer};
+use std::fmt;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Padding {
@@ -7,14 +8,14 @@ pub struct Padding {
 }
 
 impl Render for Padding {
-    fn render(&self) -> String {
-        match self.dir {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(&match self.dir {
             Direction::Top => "padding-top".to_owned(),
             Direction::Bottom => "padding-bottom".to_owned(),
             Direction::Left => "padding-left".to_owned(),
             Direction::Right => "padding-right".to_owned(),
             Direction::All => "padding".to_owned(),
-        }
+        })
     }
 }
 
@@ -1,5 +1,6 @@
 use super::Property;
-use crate::render::Render;
+use crate::render::{Render, Renderer};
+use std::fmt;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum Position {
@@ -11,14 +12,14 @@ pub enum Position {
 }
 
 impl Render for Position {
-    fn render(&self) -> String {
-        match self {
-            Position::Position => "position".to_owned(),
-            Position::Top => "top".to_owned(),
-            Position::Bottom => "bottom".to_owned(),
-            Position::Left => "left".to_owned(),
-            Position::Right => "right".to_owned(),
-        }
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(match self {
+            Position::Position => "position",
+            Position::Top => "top",
+            Position::Bottom => "bottom",
+            Position::Left => "left",
+            Position::Right => "right",
+        })
     }
 }
 
@@ -1,5 +1,6 @@
 use super::Property;
-use crate::render::Render;
+use crate::render::{Render, Renderer};
+use std::fmt;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum Size {
@@ -10,13 +11,13 @@ pub enum Size {
 }
 
 impl Render for Size {
-    fn render(&self) -> String {
-        match self {
-            Size::Width => "width".to_owned(),
-            Size::MaxWidth => "max-width".to_owned(),
-            Size::Height => "height".to_owned(),
-            Size::MaxHeight => "max-height".to_owned(),
-        }
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(match self {
+            Size::Width => "width",
+            Size::MaxWidth => "max-width",
+            Size::Height => "height",
+            Size::MaxHeight => "max-height",
+        })
     }
 }
 
@@ -4,7 +4,28 @@ mod top_selector;
 pub use sub_selector::{ChildSelector, SubSelector};
 pub use top_selector::TopSelector;
 
-use crate::render::Render;
+use crate::render::{Render, Renderer};
+use std::{fmt, ops::Add};
+
+/// Weight of a selector in the cascade, compared by ids first, then classes, then tags
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Specificity {
+    pub ids: u32,
+    /// Classes and pseudo classes
+    pub classes: u32,
+    pub tags: u32,
+}
+
+impl Add for Specificity {
+    type Output = Specificity;
+    fn add(self, other: Specificity) -> Specificity {
+        Specificity {
+            ids: self.ids + other.ids,
+            classes: self.classes + other.classes,
+            tags: self.tags + other.tags,
+        }
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Selector {
@@ -12,22 +33,39 @@ pub struct Selector {
     pub sub: Option<SubSelector>,
 }
 
+impl Selector {
+    pub fn specificity(&self) -> Specificity {
+        let sub = match &self.sub {
+            None => Specificity::default(),
+            Some(SubSelector::Visited) | Some(SubSelector::NthChild(_)) => Specificity {
+                classes: 1,
+                ..Specificity::default()
+            },
+            Some(SubSelector::ChildCombinator(child)) => child.specificity(),
+        };
+        self.top.specificity() + sub
+    }
+}
+
 impl Render for Selector {
-    fn render(&self) -> String {
-        let top_str = self.top.render();
-        let sub_str = self
-            .sub
-            .as_ref()
-            .map(|sb| sb.render())
-            .unwrap_or("".to_owned());
-        format!("{top_str}{sub_str}")
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        self.top.render_into(out)?;
+        match &self.sub {
+            Some(sub) => sub.render_into(out),
+            None => Ok(()),
+        }
     }
 }
 
 #[cfg(test)]
 mod selector_tests {
 
-    use super::{Render, Selector, SubSelector, TopSelector};
+    use super::{Render, Selector, Specificity, SubSelector, TopSelector};
+    use std::str::FromStr;
+
+    fn specificity(selector: &str) -> Specificity {
+        Selector::from_str(selector).unwrap().specificity()
+    }
 
     #[test]
     fn render_top_only() {
@@ -50,4 +88,51 @@ mod selector_tests {
         let expected = "#ident:visited";
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn specificity_simple() {
+        assert_eq!(
+            specificity("#id"),
+            Specificity {
+                ids: 1,
+                classes: 0,
+                tags: 0
+            }
+        );
+        assert_eq!(
+            specificity(".class"),
+            Specificity {
+                ids: 0,
+                classes: 1,
+                tags: 0
+            }
+        );
+        assert_eq!(
+            specificity("div"),
+            Specificity {
+                ids: 0,
+                classes: 0,
+                tags: 1
+            }
+        );
+        assert_eq!(specificity("*"), Specificity::default())
+    }
+
+    #[test]
+    fn specificity_compound() {
+        let result = specificity("ul > li:nth-child(odd)");
+        let expected = Specificity {
+            ids: 0,
+            classes: 1,
+            tags: 2,
+        };
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn specificity_order() {
+        assert!(specificity("#a") > specificity(".a > .b > .c"));
+        assert!(specificity(".a") > specificity("ul > li > a"));
+        assert!(specificity("a:visited") > specificity("a"))
+    }
 }
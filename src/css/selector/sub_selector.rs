@@ -1,6 +1,6 @@
 use super::Selector;
-use crate::render::Render;
-use std::rc::Rc;
+use crate::render::{Render, Renderer};
+use std::{fmt, rc::Rc};
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum SubSelector {
@@ -17,27 +17,46 @@ pub enum ChildSelector {
 }
 
 impl Render for SubSelector {
-    fn render(&self) -> String {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
         match self {
-            SubSelector::Visited => ":visited".to_owned(),
+            SubSelector::Visited => out.write_str(":visited"),
             SubSelector::NthChild(child_select) => {
                 let child_select_str = child_select.render();
-                format!(":nth-child({child_select_str})")
+                out.write_str(&format!(":nth-child({child_select_str})"))
             }
             SubSelector::ChildCombinator(top_select) => {
-                let top_str = top_select.render();
-                format!(">{top_str}")
+                out.write_str(">")?;
+                top_select.render_into(out)
             }
         }
     }
 }
 
 impl Render for ChildSelector {
-    fn render(&self) -> String {
-        match self {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(&match self {
             ChildSelector::Odd => "odd".to_string(),
             ChildSelector::Even => "even".to_string(),
             ChildSelector::AnPlusB(a, b) => format!("{a}n+{b}"),
+        })
+    }
+}
+
+impl ChildSelector {
+    /// Whether the child at the 1-based `position` is selected
+    pub fn matches(&self, position: usize) -> bool {
+        let position = position as i64;
+        match self {
+            ChildSelector::Odd => position % 2 == 1,
+            ChildSelector::Even => position % 2 == 0,
+            ChildSelector::AnPlusB(a, b) => {
+                let (a, b) = (*a as i64, *b as i64);
+                if a == 0 {
+                    position == b
+                } else {
+                    (position - b) % a == 0 && (position - b) / a >= 0
+                }
+            }
         }
     }
 }
@@ -112,6 +131,32 @@ mod subselector_tests {
         assert_eq!(result, expected)
     }
 
+    #[test]
+    fn matches_odd_even() {
+        let result: Vec<usize> = (1..=6).filter(|i| ChildSelector::Odd.matches(*i)).collect();
+        assert_eq!(result, vec![1, 3, 5]);
+        let result: Vec<usize> = (1..=6)
+            .filter(|i| ChildSelector::Even.matches(*i))
+            .collect();
+        assert_eq!(result, vec![2, 4, 6])
+    }
+
+    #[test]
+    fn matches_linear() {
+        let result: Vec<usize> = (1..=10)
+            .filter(|i| ChildSelector::AnPlusB(3, 1).matches(*i))
+            .collect();
+        assert_eq!(result, vec![1, 4, 7, 10]);
+        let result: Vec<usize> = (1..=10)
+            .filter(|i| ChildSelector::AnPlusB(-1, 3).matches(*i))
+            .collect();
+        assert_eq!(result, vec![1, 2, 3]);
+        let result: Vec<usize> = (1..=10)
+            .filter(|i| ChildSelector::AnPlusB(0, 4).matches(*i))
+            .collect();
+        assert_eq!(result, vec![4])
+    }
+
     #[test]
     fn render_into() {
         let result = <ChildSelector as Into<SubSelector>>::into(ChildSelector::Even).render();
@@ -1,5 +1,6 @@
-use super::Selector;
-use crate::render::Render;
+use super::{Selector, Specificity};
+use crate::render::{Render, Renderer};
+use std::fmt;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum TopSelector {
@@ -11,17 +12,49 @@ pub enum TopSelector {
     Multiple(Vec<TopSelector>),
 }
 
+impl TopSelector {
+    /// For a list of selectors this is the highest specificity in the list
+    pub fn specificity(&self) -> Specificity {
+        match self {
+            TopSelector::Id(_) => Specificity {
+                ids: 1,
+                ..Specificity::default()
+            },
+            TopSelector::Class(_) | TopSelector::Pseudo(_) => Specificity {
+                classes: 1,
+                ..Specificity::default()
+            },
+            TopSelector::Tag(_) => Specificity {
+                tags: 1,
+                ..Specificity::default()
+            },
+            TopSelector::All => Specificity::default(),
+            TopSelector::Multiple(tops) => tops
+                .iter()
+                .map(TopSelector::specificity)
+                .max()
+                .unwrap_or_default(),
+        }
+    }
+}
+
 impl Render for TopSelector {
-    fn render(&self) -> String {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
         match self {
-            TopSelector::Class(class) => format!(".{class}"),
-            TopSelector::Id(id) => format!("#{id}"),
-            TopSelector::Tag(tag) => tag.to_string(),
-            TopSelector::Pseudo(pseudo) => format!(":{pseudo}"),
-            TopSelector::All => "*".to_owned(),
+            TopSelector::Class(class) => out.write_str(&format!(".{class}")),
+            TopSelector::Id(id) => out.write_str(&format!("#{id}")),
+            TopSelector::Tag(tag) => out.write_str(tag),
+            TopSelector::Pseudo(pseudo) => out.write_str(&format!(":{pseudo}")),
+            TopSelector::All => out.write_str("*"),
             TopSelector::Multiple(selectors) => {
-                let sel_strs: Vec<String> = selectors.iter().map(|s| s.render()).collect();
-                sel_strs.join(", ")
+                for (i, selector) in selectors.iter().enumerate() {
+                    if i > 0 {
+                        out.write_str(",")?;
+                        out.space()?;
+                    }
+                    selector.render_into(out)?;
+                }
+                Ok(())
             }
         }
     }
@@ -37,6 +70,7 @@ impl From<TopSelector> for Selector {
 mod top_selector_tests {
 
     use super::{Render, Selector, TopSelector};
+    use crate::render::RenderOptions;
 
     #[test]
     fn render_class() {
@@ -90,4 +124,15 @@ mod top_selector_tests {
         let expected = TopSelector::All.render();
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn render_multiple_minified() {
+        let result = TopSelector::Multiple(vec![
+            TopSelector::Id("id".to_owned()),
+            TopSelector::Class("class".to_owned()),
+        ])
+        .render_with(&RenderOptions::minified());
+        let expected = "#id,.class";
+        assert_eq!(result, expected)
+    }
 }
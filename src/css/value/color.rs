@@ -1,5 +1,9 @@
 use super::Value;
-use crate::render::Render;
+use crate::{
+    parse::ParseError,
+    render::{Render, Renderer},
+};
+use std::{fmt, str::FromStr};
 
 #[derive(Clone, PartialEq, Debug)]
 pub enum Color {
@@ -8,13 +12,13 @@ pub enum Color {
 }
 
 impl Render for Color {
-    fn render(&self) -> String {
-        match self {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(&match self {
             Color::Rgb(r, g, b) => {
                 format!("#{r:02x}{g:02x}{b:02x}")
             }
             Color::Rgba(r, g, b, a) => format!("rgba({r},{g},{b},{a:.2})"),
-        }
+        })
     }
 }
 
@@ -24,10 +28,64 @@ impl From<Color> for Value {
     }
 }
 
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.is_ascii() {
+        return None;
+    }
+    let digits: Vec<u8> = match hex.len() {
+        3 | 4 => hex
+            .chars()
+            .map(|c| u8::from_str_radix(&c.to_string().repeat(2), 16).ok())
+            .collect::<Option<_>>()?,
+        6 | 8 => (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+            .collect::<Option<_>>()?,
+        _ => return None,
+    };
+    match digits[..] {
+        [r, g, b] => Some(Color::Rgb(r, g, b)),
+        [r, g, b, a] => Some(Color::Rgba(r, g, b, a as f32 / 255.0)),
+        _ => None,
+    }
+}
+
+fn parse_function(s: &str) -> Option<Color> {
+    let (name, args) = s.strip_suffix(')')?.split_once('(')?;
+    let args: Vec<&str> = args.split(',').map(|arg| arg.trim()).collect();
+    match (name.trim(), &args[..]) {
+        ("rgb", [r, g, b]) => Some(Color::Rgb(
+            r.parse().ok()?,
+            g.parse().ok()?,
+            b.parse().ok()?,
+        )),
+        ("rgba", [r, g, b, a]) => Some(Color::Rgba(
+            r.parse().ok()?,
+            g.parse().ok()?,
+            b.parse().ok()?,
+            a.parse().ok()?,
+        )),
+        _ => None,
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Color, ParseError> {
+        let s = s.trim();
+        let color = match s.strip_prefix('#') {
+            Some(hex) => parse_hex(hex),
+            None => parse_function(&s.to_ascii_lowercase()),
+        };
+        color.ok_or_else(|| ParseError::at(s, 0, &format!("invalid color `{s}`")))
+    }
+}
+
 #[cfg(test)]
 mod color_tests {
 
     use super::{Color, Render, Value};
+    use std::str::FromStr;
 
     #[test]
     fn render_rgb() {
@@ -49,4 +107,35 @@ mod color_tests {
         let expected = Color::Rgb(10, 10, 10).render();
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn parse_hex() {
+        let result = Color::from_str("#050a10").unwrap();
+        assert_eq!(result, Color::Rgb(5, 10, 16))
+    }
+
+    #[test]
+    fn parse_short_hex() {
+        let result = Color::from_str("#fA0").unwrap();
+        assert_eq!(result, Color::Rgb(255, 170, 0))
+    }
+
+    #[test]
+    fn parse_rgb() {
+        let result = Color::from_str("rgb(1, 2, 3)").unwrap();
+        assert_eq!(result, Color::Rgb(1, 2, 3))
+    }
+
+    #[test]
+    fn parse_rgba() {
+        let result = Color::from_str("rgba(10,15,20,0.5)").unwrap();
+        assert_eq!(result, Color::Rgba(10, 15, 20, 0.5))
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(Color::from_str("#12345").is_err());
+        assert!(Color::from_str("rgb(1,2)").is_err());
+        assert!(Color::from_str("red").is_err())
+    }
 }
@@ -1,5 +1,9 @@
 use super::Value;
-use crate::render::Render;
+use crate::{
+    parse::ParseError,
+    render::{Render, Renderer},
+};
+use std::{fmt, str::FromStr};
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum Keyword {
@@ -29,32 +33,32 @@ pub enum Keyword {
 }
 
 impl Render for Keyword {
-    fn render(&self) -> String {
-        match self {
-            Keyword::Center => "center".to_owned(),
-            Keyword::FlexStart => "flex-start".to_owned(),
-            Keyword::Pointer => "pointer".to_owned(),
-            Keyword::Collapse => "collapse".to_owned(),
-            Keyword::Auto => "auto".to_owned(),
-            Keyword::Right => "right".to_owned(),
-            Keyword::Left => "left".to_owned(),
-            Keyword::Flex => "flex".to_owned(),
-            Keyword::SpaceAround => "space-around".to_owned(),
-            Keyword::Wrap => "wrap".to_owned(),
-            Keyword::Stretch => "stretch".to_owned(),
-            Keyword::Non => "none".to_owned(),
-            Keyword::Fixed => "fixed".to_owned(),
-            Keyword::Relative => "relative".to_owned(),
-            Keyword::Block => "block".to_owned(),
-            Keyword::Solid => "solid".to_owned(),
-            Keyword::Column => "column".to_owned(),
-            Keyword::FlexEnd => "flex-end".to_owned(),
-            Keyword::Bold => "bold".to_owned(),
-            Keyword::Transparent => "transparent".to_owned(),
-            Keyword::Hidden => "hidden".to_owned(),
-            Keyword::FlowRoot => "flot-root".to_owned(),
-            Keyword::NoWrap => "nowrap".to_owned(),
-        }
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(match self {
+            Keyword::Center => "center",
+            Keyword::FlexStart => "flex-start",
+            Keyword::Pointer => "pointer",
+            Keyword::Collapse => "collapse",
+            Keyword::Auto => "auto",
+            Keyword::Right => "right",
+            Keyword::Left => "left",
+            Keyword::Flex => "flex",
+            Keyword::SpaceAround => "space-around",
+            Keyword::Wrap => "wrap",
+            Keyword::Stretch => "stretch",
+            Keyword::Non => "none",
+            Keyword::Fixed => "fixed",
+            Keyword::Relative => "relative",
+            Keyword::Block => "block",
+            Keyword::Solid => "solid",
+            Keyword::Column => "column",
+            Keyword::FlexEnd => "flex-end",
+            Keyword::Bold => "bold",
+            Keyword::Transparent => "transparent",
+            Keyword::Hidden => "hidden",
+            Keyword::FlowRoot => "flot-root",
+            Keyword::NoWrap => "nowrap",
+        })
     }
 }
 
@@ -64,10 +68,44 @@ impl From<Keyword> for Value {
     }
 }
 
+impl FromStr for Keyword {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Keyword, ParseError> {
+        match s.to_ascii_lowercase().as_str() {
+            "center" => Ok(Keyword::Center),
+            "flex-start" => Ok(Keyword::FlexStart),
+            "pointer" => Ok(Keyword::Pointer),
+            "collapse" => Ok(Keyword::Collapse),
+            "auto" => Ok(Keyword::Auto),
+            "right" => Ok(Keyword::Right),
+            "left" => Ok(Keyword::Left),
+            "flex" => Ok(Keyword::Flex),
+            "space-around" => Ok(Keyword::SpaceAround),
+            "wrap" => Ok(Keyword::Wrap),
+            "stretch" => Ok(Keyword::Stretch),
+            "none" => Ok(Keyword::Non),
+            "fixed" => Ok(Keyword::Fixed),
+            "relative" => Ok(Keyword::Relative),
+            "block" => Ok(Keyword::Block),
+            "solid" => Ok(Keyword::Solid),
+            "column" => Ok(Keyword::Column),
+            "flex-end" => Ok(Keyword::FlexEnd),
+            "bold" => Ok(Keyword::Bold),
+            "transparent" => Ok(Keyword::Transparent),
+            "hidden" => Ok(Keyword::Hidden),
+            "flot-root" => Ok(Keyword::FlowRoot),
+            "nowrap" => Ok(Keyword::NoWrap),
+            "flow-root" => Ok(Keyword::FlowRoot),
+            _ => Err(ParseError::at(s, 0, &format!("unknown keyword `{s}`"))),
+        }
+    }
+}
+
 #[cfg(test)]
 mod keyword_tests {
 
     use super::{Keyword, Render, Value};
+    use std::str::FromStr;
 
     #[test]
     fn render_center() {
@@ -236,4 +274,28 @@ mod keyword_tests {
         let expected = Keyword::Center.render();
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn parse_rendered() {
+        let keywords = vec![
+            Keyword::Center,
+            Keyword::SpaceAround,
+            Keyword::Non,
+            Keyword::FlowRoot,
+        ];
+        for keyword in keywords {
+            assert_eq!(Keyword::from_str(&keyword.render()).unwrap(), keyword)
+        }
+    }
+
+    #[test]
+    fn parse_flow_root() {
+        let result = Keyword::from_str("flow-root").unwrap();
+        assert_eq!(result, Keyword::FlowRoot)
+    }
+
+    #[test]
+    fn parse_unknown() {
+        assert!(Keyword::from_str("inline-grid").is_err())
+    }
 }
@@ -2,9 +2,10 @@ mod color;
 mod keyword;
 mod unit;
 
-use crate::render::Render;
+use crate::render::{Render, Renderer};
 pub use color::Color;
 pub use keyword::Keyword;
+use std::{convert::Infallible, fmt, str::FromStr};
 pub use unit::Unit;
 
 #[derive(Clone, PartialEq, Debug)]
@@ -14,11 +15,13 @@ pub enum Value {
     Var(String),
     Measurement(f32, Unit),
     Keyword(Keyword),
+    /// Any value that is not modelled, rendered as is
+    Other(String),
 }
 
 impl Render for Value {
-    fn render(&self) -> String {
-        match self {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(&match self {
             Value::Color(color) => color.render(),
             Value::Str(st) => format!("\"{st}\""),
             Value::Var(v) => format!("var(--{v})"),
@@ -28,7 +31,8 @@ impl Render for Value {
                 format!("{num_str}{unit_str}")
             }
             Value::Keyword(kw) => kw.render(),
-        }
+            Value::Other(other) => other.clone(),
+        })
     }
 }
 
@@ -38,9 +42,52 @@ impl From<(f32, Unit)> for Value {
     }
 }
 
+fn parse_measurement(s: &str) -> Option<Value> {
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .unwrap_or(s.len());
+    let (num, unit) = s.split_at(split);
+    Some(Value::Measurement(num.parse().ok()?, unit.parse().ok()?))
+}
+
+fn parse_quoted(s: &str) -> Option<&str> {
+    ['"', '\''].iter().find_map(|quote| {
+        let inner = s.strip_prefix(*quote)?.strip_suffix(*quote)?;
+        (!inner.contains(*quote)).then_some(inner)
+    })
+}
+
+/// Values are parsed leniently, anything that is not modelled becomes [`Value::Other`]
+impl FromStr for Value {
+    type Err = Infallible;
+    fn from_str(s: &str) -> Result<Value, Infallible> {
+        let s = s.trim();
+        if let Some(var) = s
+            .strip_prefix("var(--")
+            .and_then(|var| var.strip_suffix(')'))
+        {
+            return Ok(Value::Var(var.to_owned()));
+        }
+        if let Some(st) = parse_quoted(s) {
+            return Ok(Value::Str(st.to_owned()));
+        }
+        if let Ok(color) = s.parse() {
+            return Ok(Value::Color(color));
+        }
+        if let Some(measurement) = parse_measurement(s) {
+            return Ok(measurement);
+        }
+        if let Ok(keyword) = s.parse() {
+            return Ok(Value::Keyword(keyword));
+        }
+        Ok(Value::Other(s.to_owned()))
+    }
+}
+
 #[cfg(test)]
 mod value_tests {
     use super::{Color, Keyword, Render, Unit, Value};
+    use std::str::FromStr;
 
     #[test]
     fn render_color() {
@@ -83,4 +130,42 @@ mod value_tests {
         let expected = Value::Measurement(1.0, Unit::Em).render();
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn render_other() {
+        let result = Value::Other("1px solid red".to_owned()).render();
+        let expected = "1px solid red";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_values() {
+        let result: Vec<Value> = [
+            "#010203",
+            "\"value\"",
+            "var(--bg-color)",
+            "1.5em",
+            "-2px",
+            "center",
+        ]
+        .iter()
+        .map(|s| Value::from_str(s).unwrap())
+        .collect();
+        let expected = vec![
+            Value::Color(Color::Rgb(1, 2, 3)),
+            Value::Str("value".to_owned()),
+            Value::Var("bg-color".to_owned()),
+            Value::Measurement(1.5, Unit::Em),
+            Value::Measurement(-2.0, Unit::Px),
+            Value::Keyword(Keyword::Center),
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_fallback() {
+        let result = Value::from_str(" 1px solid red ").unwrap();
+        let expected = Value::Other("1px solid red".to_owned());
+        assert_eq!(result, expected)
+    }
 }
@@ -1,4 +1,8 @@
-use crate::render::Render;
+use crate::{
+    parse::ParseError,
+    render::{Render, Renderer},
+};
+use std::{fmt, str::FromStr};
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum Unit {
@@ -10,13 +14,27 @@ pub enum Unit {
 }
 
 impl Render for Unit {
-    fn render(&self) -> String {
-        match self {
-            Unit::Pt => "pt".to_owned(),
-            Unit::Percent => "%".to_owned(),
-            Unit::Em => "em".to_owned(),
-            Unit::Vh => "vh".to_owned(),
-            Unit::Px => "px".to_owned(),
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(match self {
+            Unit::Pt => "pt",
+            Unit::Percent => "%",
+            Unit::Em => "em",
+            Unit::Vh => "vh",
+            Unit::Px => "px",
+        })
+    }
+}
+
+impl FromStr for Unit {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Unit, ParseError> {
+        match s.to_ascii_lowercase().as_str() {
+            "pt" => Ok(Unit::Pt),
+            "%" => Ok(Unit::Percent),
+            "em" => Ok(Unit::Em),
+            "vh" => Ok(Unit::Vh),
+            "px" => Ok(Unit::Px),
+            _ => Err(ParseError::at(s, 0, &format!("unknown unit `{s}`"))),
         }
     }
 }
@@ -25,6 +43,7 @@ impl Render for Unit {
 mod unit_tests {
 
     use super::{Render, Unit};
+    use std::str::FromStr;
 
     #[test]
     fn render_pt() {
@@ -60,4 +79,19 @@ mod unit_tests {
         let expected = "px";
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn parse_units() {
+        let result: Vec<Unit> = ["pt", "%", "EM", "vh", "px"]
+            .iter()
+            .map(|s| Unit::from_str(s).unwrap())
+            .collect();
+        let expected = vec![Unit::Pt, Unit::Percent, Unit::Em, Unit::Vh, Unit::Px];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_unknown() {
+        assert!(Unit::from_str("rem").is_err())
+    }
 }
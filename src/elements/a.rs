@@ -1,19 +1,26 @@
-use super::HtmlElement;
-use crate::{attribute::Attribute, render::Render};
-use std::rc::Rc;
+use super::{
+    builder::{push_content, WithAttributes, WithChildren},
+    HtmlElement,
+};
+use crate::{
+    attribute::Attribute,
+    render::{Render, Renderer},
+};
+use std::{fmt, rc::Rc};
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct A {
     pub attributes: Vec<Attribute>,
     pub content: Rc<HtmlElement>,
 }
 
 impl Render for A {
-    fn render(&self) -> String {
-        let attr_str = self.attributes.render().replace('\n', " ");
-        let cont_str = self.content.render();
-
-        format!("<a {attr_str}>{cont_str}</a>")
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<a")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        self.content.render_into(out)?;
+        out.write_str("</a>")
     }
 }
 
@@ -23,10 +30,33 @@ impl From<A> for HtmlElement {
     }
 }
 
+impl A {
+    pub fn new() -> A {
+        A::default()
+    }
+
+    pub fn href(self, href: &str) -> A {
+        self.attr(Attribute::Href(href.to_owned()))
+    }
+}
+
+impl WithAttributes for A {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+impl WithChildren for A {
+    fn push_child(&mut self, child: HtmlElement) {
+        push_content(&mut self.content, child)
+    }
+}
+
 #[cfg(test)]
 mod a_tests {
 
-    use super::{Attribute, HtmlElement, Render, A};
+    use super::{Attribute, HtmlElement, Render, WithAttributes, WithChildren, A};
+    use crate::elements::Build;
     use std::rc::Rc;
 
     fn example_a() -> A {
@@ -48,4 +78,18 @@ mod a_tests {
         let expected = example_a().render();
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn build_a() {
+        let result = A::new().href("/x").class("link").text("x").build();
+        let expected = A {
+            attributes: vec![
+                Attribute::Href("/x".to_owned()),
+                Attribute::Class(vec!["link".to_owned()]),
+            ],
+            content: Rc::new("x".to_owned().into()),
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
 }
@@ -1,8 +1,14 @@
-use super::HtmlElement;
-use crate::{attribute::Attribute, render::Render};
-use std::rc::Rc;
+use super::{
+    builder::{push_content, WithAttributes, WithChildren},
+    HtmlElement,
+};
+use crate::{
+    attribute::Attribute,
+    render::{Render, Renderer},
+};
+use std::{fmt, rc::Rc};
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Body {
     pub attributes: Vec<Attribute>,
     pub content: Rc<HtmlElement>,
@@ -24,17 +30,38 @@ impl From<HtmlElement> for Body {
 }
 
 impl Render for Body {
-    fn render(&self) -> String {
-        let content_str = self.content.render().replace('\n', "\n\t");
-        let attr_str = self.attributes.render().replace('\n', " ");
-        format!("<body {attr_str}>\n\t{content_str}\n</body>")
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<body")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        out.block(|out| self.content.render_into(out))?;
+        out.write_str("</body>")
+    }
+}
+
+impl Body {
+    pub fn new() -> Body {
+        Body::default()
+    }
+}
+
+impl WithAttributes for Body {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+impl WithChildren for Body {
+    fn push_child(&mut self, child: HtmlElement) {
+        push_content(&mut self.content, child)
     }
 }
 
 #[cfg(test)]
 mod body_tests {
 
-    use super::{Attribute, Body, HtmlElement, Render};
+    use super::{Attribute, Body, HtmlElement, Render, WithAttributes, WithChildren};
+    use crate::elements::Build;
     use std::rc::Rc;
 
     fn example_body() -> Body {
@@ -66,4 +93,15 @@ mod body_tests {
         let expected = "<body >\n\tan element\n</body>";
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn build_body() {
+        let result = Body::new().id("body").text("a").text("b").build();
+        let expected = Body {
+            attributes: vec![Attribute::Id("body".to_owned())],
+            content: Rc::new(vec!["a".to_owned().into(), "b".to_owned().into()].into()),
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
 }
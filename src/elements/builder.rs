@@ -0,0 +1,115 @@
+use super::HtmlElement;
+use crate::attribute::Attribute;
+use std::rc::Rc;
+
+/// Elements whose attributes can be set in a builder chain
+pub trait WithAttributes: Sized {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute>;
+
+    fn attr(mut self, attribute: Attribute) -> Self {
+        self.attributes_mut().push(attribute);
+        self
+    }
+
+    fn id(self, id: &str) -> Self {
+        self.attr(Attribute::Id(id.to_owned()))
+    }
+
+    /// Adds `class` to the class attribute, creating it if necessary
+    fn class(mut self, class: &str) -> Self {
+        let existing = self
+            .attributes_mut()
+            .iter_mut()
+            .find_map(|attr| match attr {
+                Attribute::Class(classes) => Some(classes),
+                _ => None,
+            });
+        match existing {
+            Some(classes) => {
+                classes.push(class.to_owned());
+                self
+            }
+            None => self.attr(Attribute::Class(vec![class.to_owned()])),
+        }
+    }
+
+    fn style(self, style: &str) -> Self {
+        self.attr(Attribute::Style(style.to_owned()))
+    }
+}
+
+/// Elements that children can be appended to in a builder chain
+pub trait WithChildren: Sized {
+    fn push_child(&mut self, child: HtmlElement);
+
+    fn child<T: Into<HtmlElement>>(mut self, child: T) -> Self {
+        self.push_child(child.into());
+        self
+    }
+
+    fn children<T, I>(mut self, children: I) -> Self
+    where
+        T: Into<HtmlElement>,
+        I: IntoIterator<Item = T>,
+    {
+        for child in children {
+            self.push_child(child.into());
+        }
+        self
+    }
+
+    fn text(self, text: &str) -> Self {
+        self.child(text.to_owned())
+    }
+}
+
+/// Finishes a builder chain
+pub trait Build: Into<HtmlElement> {
+    fn build(self) -> HtmlElement {
+        self.into()
+    }
+}
+
+impl<T: Into<HtmlElement>> Build for T {}
+
+/// Appends `child` to `content`.
+/// The first child becomes the content itself, later ones turn it into a component list.
+pub(crate) fn push_content(content: &mut Rc<HtmlElement>, child: HtmlElement) {
+    match Rc::make_mut(content) {
+        HtmlElement::ComponentList(elems) if !elems.is_empty() => elems.push(child),
+        HtmlElement::ComponentList(_) => *content = Rc::new(child),
+        existing => {
+            let first = std::mem::replace(existing, HtmlElement::ComponentList(vec![]));
+            *existing = HtmlElement::ComponentList(vec![first, child]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod builder_tests {
+    use super::push_content;
+    use crate::elements::HtmlElement;
+    use std::rc::Rc;
+
+    #[test]
+    fn push_first_child() {
+        let mut result = Rc::default();
+        push_content(&mut result, "a".to_owned().into());
+        let expected = Rc::new("a".to_owned().into());
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn push_more_children() {
+        let mut result = Rc::default();
+        push_content(&mut result, "a".to_owned().into());
+        push_content(&mut result, "b".to_owned().into());
+        push_content(&mut result, "c".to_owned().into());
+        let expected = Rc::new(HtmlElement::ComponentList(vec![
+            "a".to_owned().into(),
+            "b".to_owned().into(),
+            "c".to_owned().into(),
+        ]));
+        assert_eq!(result, expected)
+    }
+}
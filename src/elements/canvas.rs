@@ -1,15 +1,20 @@
-use super::HtmlElement;
-use crate::{attribute::Attribute, render::Render};
+use super::{builder::WithAttributes, HtmlElement};
+use crate::{
+    attribute::Attribute,
+    render::{Render, Renderer},
+};
+use std::fmt;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Canvas {
     pub attributes: Vec<Attribute>,
 }
 
 impl Render for Canvas {
-    fn render(&self) -> String {
-        let attr_str = self.attributes.render().replace('\n', " ");
-        format!("<canvas {attr_str}></canvas>")
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<canvas")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str("></canvas>")
     }
 }
 
@@ -19,10 +24,23 @@ impl From<Canvas> for HtmlElement {
     }
 }
 
+impl Canvas {
+    pub fn new() -> Canvas {
+        Canvas::default()
+    }
+}
+
+impl WithAttributes for Canvas {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
 #[cfg(test)]
 mod canvas_tests {
 
-    use super::{Attribute, Canvas, HtmlElement, Render};
+    use super::{Attribute, Canvas, HtmlElement, Render, WithAttributes};
+    use crate::elements::Build;
 
     fn example_canvas() -> Canvas {
         Canvas {
@@ -43,4 +61,14 @@ mod canvas_tests {
         let expected = example_canvas().render();
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn build_canvas() {
+        let result = Canvas::new().id("canvas").build();
+        let expected = Canvas {
+            attributes: vec![Attribute::Id("canvas".to_owned())],
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
 }
@@ -1,8 +1,14 @@
-use super::HtmlElement;
-use crate::{attribute::Attribute, render::Render};
-use std::rc::Rc;
+use super::{
+    builder::{push_content, WithAttributes, WithChildren},
+    HtmlElement,
+};
+use crate::{
+    attribute::Attribute,
+    render::{Render, Renderer},
+};
+use std::{fmt, rc::Rc};
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Div {
     pub attributes: Vec<Attribute>,
     pub content: Rc<HtmlElement>,
@@ -15,17 +21,38 @@ impl From<Div> for HtmlElement {
 }
 
 impl Render for Div {
-    fn render(&self) -> String {
-        let content_str = self.content.render().replace('\n', "\n\t");
-        let attr_str = self.attributes.render().replace('\n', " ");
-        format!("<div {attr_str}>\n\t{content_str}\n</div>")
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<div")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        out.block(|out| self.content.render_into(out))?;
+        out.write_str("</div>")
+    }
+}
+
+impl Div {
+    pub fn new() -> Div {
+        Div::default()
+    }
+}
+
+impl WithAttributes for Div {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+impl WithChildren for Div {
+    fn push_child(&mut self, child: HtmlElement) {
+        push_content(&mut self.content, child)
     }
 }
 
 #[cfg(test)]
 mod div_tests {
 
-    use super::{Attribute, Div, HtmlElement, Render};
+    use super::{Attribute, Div, HtmlElement, Render, WithAttributes, WithChildren};
+    use crate::elements::Build;
     use std::rc::Rc;
 
     fn example_div() -> Div {
@@ -48,4 +75,42 @@ mod div_tests {
         let expected = example_div().render();
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn build_div() {
+        let result = Div::new()
+            .id("x")
+            .class("a")
+            .class("b")
+            .child(Div::new().text("inner"))
+            .children(["c", "d"].map(str::to_owned))
+            .build();
+        let expected = Div {
+            attributes: vec![
+                Attribute::Id("x".to_owned()),
+                Attribute::Class(vec!["a".to_owned(), "b".to_owned()]),
+            ],
+            content: Rc::new(
+                vec![
+                    Div {
+                        attributes: vec![],
+                        content: Rc::new("inner".to_owned().into()),
+                    }
+                    .into(),
+                    "c".to_owned().into(),
+                    "d".to_owned().into(),
+                ]
+                .into(),
+            ),
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn build_empty_div() {
+        let result = Div::new().render();
+        let expected = "<div >\n\t\n</div>";
+        assert_eq!(result, expected)
+    }
 }
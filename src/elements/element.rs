@@ -0,0 +1,936 @@
+use super::{
+    builder::{WithAttributes, WithChildren},
+    Abbr, Article, Aside, Audio, Blockquote, Body, Button, Canvas, Caption, Cell, Code, Col,
+    Colgroup, Datalist, Div, Em, Fieldset, Figure, Footer, Form, Head, Header, Headline, Hr,
+    HtmlElement, Img, Input, Label, Legend, Li, Link, Main, Mark, Meta, Nav, Ol, Output, Picture,
+    Pre, Script, Section, Select, SelectOption, Small, Source, Span, Strong, Style, Sub, Sup,
+    Table, TableSection, Td, Textarea, Th, Tr, Track, Ul, Video, A, P,
+};
+use crate::{
+    attribute::Attribute,
+    render::{is_whitespace_sensitive, Render, Renderer},
+};
+use std::{fmt, rc::Rc};
+
+/// Elements that never have content or a closing tag
+pub const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Elements whose content is taken as is until the matching closing tag
+pub const RAW_TEXT_ELEMENTS: [&str; 4] = ["script", "style", "textarea", "title"];
+
+/// Raw text elements whose content is no html, so text in them is written without escaping
+const UNESCAPED_ELEMENTS: [&str; 2] = ["script", "style"];
+
+/// How an [`Element`] is rendered
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ElementKind {
+    /// Children between an opening and a closing tag
+    #[default]
+    Normal,
+    /// A single self-closed tag, children are ignored
+    Void,
+    /// Children are written on a single line without re-indenting them, as for `<textarea>`
+    RawText,
+}
+
+impl ElementKind {
+    /// The kind html gives to `tag`
+    pub fn of(tag: &str) -> ElementKind {
+        let tag = tag.to_ascii_lowercase();
+        if VOID_ELEMENTS.contains(&tag.as_str()) {
+            ElementKind::Void
+        } else if RAW_TEXT_ELEMENTS.contains(&tag.as_str()) {
+            ElementKind::RawText
+        } else {
+            ElementKind::Normal
+        }
+    }
+}
+
+/// Any element, including tags without a typed struct and custom elements such as `<my-widget>`.
+/// A tag that is no valid name, see [`is_valid_tag`], is left out and only the children are
+/// rendered.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Element {
+    pub tag: String,
+    pub attributes: Vec<Attribute>,
+    pub children: Vec<HtmlElement>,
+    pub kind: ElementKind,
+}
+
+/// Whether `tag` starts with an ascii letter and contains no whitespace, control characters,
+/// quotes, `/`, `=`, `<` or `>`
+pub fn is_valid_tag(tag: &str) -> bool {
+    tag.starts_with(|c: char| c.is_ascii_alphabetic())
+        && !tag.chars().any(|c| {
+            c.is_whitespace() || c.is_control() || matches!(c, '"' | '\'' | '/' | '=' | '<' | '>')
+        })
+}
+
+impl Render for Element {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        if !is_valid_tag(&self.tag) {
+            return self.children.render_into(out);
+        }
+        out.write_str(&format!("<{}", self.tag))?;
+        out.write_attributes(&self.attributes)?;
+        match self.kind {
+            ElementKind::Void => return out.write_str("/>"),
+            ElementKind::Normal if !is_whitespace_sensitive(&self.tag) => {
+                out.write_str(">")?;
+                if !self.children.is_empty() {
+                    out.block(|out| self.children.render_into(out))?;
+                }
+            }
+            _ => {
+                out.write_str(">")?;
+                let unescaped =
+                    UNESCAPED_ELEMENTS.contains(&self.tag.to_ascii_lowercase().as_str());
+                for child in self.children.iter() {
+                    match child {
+                        HtmlElement::Literal(lit) if unescaped => out.write_raw(lit.content())?,
+                        child => out.write_unindented(child)?,
+                    }
+                }
+            }
+        }
+        out.write_str(&format!("</{}>", self.tag))
+    }
+}
+
+impl From<Element> for HtmlElement {
+    fn from(elem: Element) -> HtmlElement {
+        HtmlElement::Element(elem)
+    }
+}
+
+impl Element {
+    /// An element of the kind html gives to `tag`
+    pub fn new(tag: &str) -> Element {
+        Element::with_kind(tag, ElementKind::of(tag))
+    }
+
+    pub fn with_kind(tag: &str, kind: ElementKind) -> Element {
+        Element {
+            tag: tag.to_owned(),
+            attributes: vec![],
+            children: vec![],
+            kind,
+        }
+    }
+
+    fn typed(tag: &str, attributes: Vec<Attribute>, children: Vec<HtmlElement>) -> Element {
+        Element {
+            attributes,
+            children,
+            ..Element::new(tag)
+        }
+    }
+}
+
+impl WithAttributes for Element {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+impl WithChildren for Element {
+    fn push_child(&mut self, child: HtmlElement) {
+        self.children.push(child)
+    }
+}
+
+/// Converts `elem` and everything below it into generic elements.
+/// Component lists are flattened, text and raw markup are kept as they are.
+fn generic(elem: HtmlElement) -> Vec<HtmlElement> {
+    match elem {
+        HtmlElement::ComponentList(elems) => elems.into_iter().flat_map(generic).collect(),
+        elem => match Element::try_from(elem) {
+            Ok(elem) => vec![elem.into()],
+            Err(elem) => vec![elem],
+        },
+    }
+}
+
+/// Converts parts that are stored directly in their parents
+fn elements<T: Into<Element>>(
+    parts: impl IntoIterator<Item = T>,
+) -> impl Iterator<Item = HtmlElement> {
+    parts.into_iter().map(|part| part.into().into())
+}
+
+/// The children stored in a content field, converted into generic elements
+fn content_children(content: Rc<HtmlElement>) -> Vec<HtmlElement> {
+    generic(Rc::unwrap_or_clone(content))
+}
+
+/// Converts the element and all of its descendants.
+/// Nodes that are no elements, i.e. text, raw markup and component lists, are given back unchanged.
+impl TryFrom<HtmlElement> for Element {
+    type Error = HtmlElement;
+    fn try_from(elem: HtmlElement) -> Result<Element, HtmlElement> {
+        match elem {
+            HtmlElement::Element(elem) => Ok(Element {
+                children: elem.children.into_iter().flat_map(generic).collect(),
+                ..elem
+            }),
+            HtmlElement::Head(head) => Ok(head.into()),
+            HtmlElement::Body(body) => Ok(body.into()),
+            HtmlElement::Div(div) => Ok(div.into()),
+            HtmlElement::Link(link) => Ok(link.into()),
+            HtmlElement::A(a) => Ok(a.into()),
+            HtmlElement::Table(table) => Ok(table.into()),
+            HtmlElement::Tr(tr) => Ok(tr.into()),
+            HtmlElement::Td(td) => Ok(td.into()),
+            HtmlElement::Th(th) => Ok(th.into()),
+            HtmlElement::TableSection(section) => Ok(section.into()),
+            HtmlElement::Caption(caption) => Ok(caption.into()),
+            HtmlElement::Colgroup(colgroup) => Ok(colgroup.into()),
+            HtmlElement::Col(col) => Ok(col.into()),
+            HtmlElement::Headline(headline) => Ok(headline.into()),
+            HtmlElement::Br => Ok(Element::new("br")),
+            HtmlElement::Figure(figure) => Ok(figure.into()),
+            HtmlElement::Img(img) => Ok(img.into()),
+            HtmlElement::Canvas(canvas) => Ok(canvas.into()),
+            HtmlElement::Input(input) => Ok(input.into()),
+            HtmlElement::Select(select) => Ok(select.into()),
+            HtmlElement::Script(script) => Ok(script.into()),
+            HtmlElement::Style(style) => Ok(style.into()),
+            HtmlElement::Ul(ul) => Ok(ul.into()),
+            HtmlElement::Ol(ol) => Ok(ol.into()),
+            HtmlElement::Section(section) => Ok(section.into()),
+            HtmlElement::Article(article) => Ok(article.into()),
+            HtmlElement::Nav(nav) => Ok(nav.into()),
+            HtmlElement::Header(header) => Ok(header.into()),
+            HtmlElement::Footer(footer) => Ok(footer.into()),
+            HtmlElement::Main(main) => Ok(main.into()),
+            HtmlElement::Aside(aside) => Ok(aside.into()),
+            HtmlElement::P(p) => Ok(p.into()),
+            HtmlElement::Blockquote(blockquote) => Ok(blockquote.into()),
+            HtmlElement::Pre(pre) => Ok(pre.into()),
+            HtmlElement::Span(span) => Ok(span.into()),
+            HtmlElement::Code(code) => Ok(code.into()),
+            HtmlElement::Strong(strong) => Ok(strong.into()),
+            HtmlElement::Em(em) => Ok(em.into()),
+            HtmlElement::Small(small) => Ok(small.into()),
+            HtmlElement::Mark(mark) => Ok(mark.into()),
+            HtmlElement::Sub(sub) => Ok(sub.into()),
+            HtmlElement::Sup(sup) => Ok(sup.into()),
+            HtmlElement::Abbr(abbr) => Ok(abbr.into()),
+            HtmlElement::Hr(hr) => Ok(hr.into()),
+            HtmlElement::Form(form) => Ok(form.into()),
+            HtmlElement::Label(label) => Ok(label.into()),
+            HtmlElement::Button(button) => Ok(button.into()),
+            HtmlElement::Textarea(textarea) => Ok(textarea.into()),
+            HtmlElement::Fieldset(fieldset) => Ok(fieldset.into()),
+            HtmlElement::Legend(legend) => Ok(legend.into()),
+            HtmlElement::Datalist(datalist) => Ok(datalist.into()),
+            HtmlElement::Output(output) => Ok(output.into()),
+            HtmlElement::Video(video) => Ok(video.into()),
+            HtmlElement::Audio(audio) => Ok(audio.into()),
+            HtmlElement::Source(source) => Ok(source.into()),
+            HtmlElement::Track(track) => Ok(track.into()),
+            HtmlElement::Picture(picture) => Ok(picture.into()),
+            HtmlElement::Meta(meta) => Ok(meta.into()),
+            elem @ (HtmlElement::Literal(_)
+            | HtmlElement::Raw(_)
+            | HtmlElement::ComponentList(_)) => Err(elem),
+        }
+    }
+}
+
+/// The title becomes a `<title>` child, which follows the charset and precedes the rest of the
+/// metadata and the content.
+/// The markup is the same as that of [`Head`], only the whitespace differs: the opening tag gets
+/// the space of every generic tag and the title stays on the line of its tags.
+impl From<Head> for Element {
+    fn from(head: Head) -> Element {
+        let title = Element::typed("title", vec![], vec![head.title.into()]);
+        let mut metadata = head.metadata.elements().into_iter().flat_map(generic);
+        let mut children: Vec<HtmlElement> = vec![];
+        if head.metadata.charset.is_some() {
+            children.extend(metadata.next());
+        }
+        children.push(title.into());
+        children.extend(metadata);
+        children.extend(content_children(head.content));
+        Element::typed("head", vec![], children)
+    }
+}
+
+impl From<Body> for Element {
+    fn from(body: Body) -> Element {
+        Element::typed("body", body.attributes, content_children(body.content))
+    }
+}
+
+impl From<Div> for Element {
+    fn from(div: Div) -> Element {
+        Element::typed("div", div.attributes, content_children(div.content))
+    }
+}
+
+impl From<Link> for Element {
+    fn from(link: Link) -> Element {
+        Element::typed("link", link.attributes, vec![])
+    }
+}
+
+impl From<A> for Element {
+    fn from(a: A) -> Element {
+        Element::typed("a", a.attributes, content_children(a.content))
+    }
+}
+
+/// The parts of the table become children in the order they are rendered
+impl From<Table> for Element {
+    fn from(table: Table) -> Element {
+        let children = elements(table.caption)
+            .chain(elements(table.colgroups))
+            .chain(elements(table.head))
+            .chain(elements(table.rows))
+            .chain(elements(table.bodies))
+            .chain(elements(table.foot))
+            .collect();
+        Element::typed("table", table.attributes, children)
+    }
+}
+
+impl From<TableSection> for Element {
+    fn from(section: TableSection) -> Element {
+        let rows = elements(section.rows).collect();
+        Element::typed(section.kind.tag(), section.attributes, rows)
+    }
+}
+
+impl From<Tr> for Element {
+    fn from(tr: Tr) -> Element {
+        Element::typed("tr", tr.attributes, elements(tr.cols).collect())
+    }
+}
+
+impl From<Cell> for Element {
+    fn from(cell: Cell) -> Element {
+        match cell {
+            Cell::Td(td) => td.into(),
+            Cell::Th(th) => th.into(),
+        }
+    }
+}
+
+impl From<Td> for Element {
+    fn from(td: Td) -> Element {
+        Element::typed("td", td.attributes, content_children(td.content))
+    }
+}
+
+impl From<Th> for Element {
+    fn from(th: Th) -> Element {
+        Element::typed("th", th.attributes, content_children(th.content))
+    }
+}
+
+impl From<Caption> for Element {
+    fn from(caption: Caption) -> Element {
+        Element::typed(
+            "caption",
+            caption.attributes,
+            content_children(caption.content),
+        )
+    }
+}
+
+impl From<Colgroup> for Element {
+    fn from(colgroup: Colgroup) -> Element {
+        let cols = elements(colgroup.cols).collect();
+        Element::typed("colgroup", colgroup.attributes, cols)
+    }
+}
+
+impl From<Col> for Element {
+    fn from(col: Col) -> Element {
+        Element::typed("col", col.attributes, vec![])
+    }
+}
+
+impl From<Headline> for Element {
+    fn from(headline: Headline) -> Element {
+        Element::typed(
+            &headline.size.render(),
+            headline.attributes,
+            content_children(headline.content),
+        )
+    }
+}
+
+/// The caption becomes a `<figcaption>` child after the content
+impl From<Figure> for Element {
+    fn from(figure: Figure) -> Element {
+        let caption = Element::typed("figcaption", vec![], content_children(figure.caption));
+        let mut children = content_children(figure.content);
+        children.push(caption.into());
+        Element::typed("figure", figure.attributes, children)
+    }
+}
+
+impl From<Img> for Element {
+    fn from(img: Img) -> Element {
+        Element::typed("img", img.attributes, vec![])
+    }
+}
+
+impl From<Canvas> for Element {
+    fn from(canvas: Canvas) -> Element {
+        Element::typed("canvas", canvas.attributes, vec![])
+    }
+}
+
+impl From<Input> for Element {
+    fn from(input: Input) -> Element {
+        Element::typed("input", input.all_attributes(), vec![])
+    }
+}
+
+impl From<Select> for Element {
+    fn from(select: Select) -> Element {
+        let options = select
+            .options
+            .into_iter()
+            .map(|option| Element::from(option).into())
+            .collect();
+        Element::typed("select", select.attributes, options)
+    }
+}
+
+impl From<SelectOption> for Element {
+    fn from(option: SelectOption) -> Element {
+        Element::typed(
+            "option",
+            vec![Attribute::Value(option.value)],
+            content_children(option.content),
+        )
+    }
+}
+
+/// The content stays raw markup, so the output is the same as that of [`Script`]
+impl From<Script> for Element {
+    fn from(script: Script) -> Element {
+        Element::typed(
+            "script",
+            script.attributes,
+            vec![HtmlElement::Raw(script.content)],
+        )
+    }
+}
+
+impl From<Style> for Element {
+    fn from(style: Style) -> Element {
+        Element::typed(
+            "style",
+            vec![],
+            vec![HtmlElement::Raw(style.style.render())],
+        )
+    }
+}
+
+impl From<Li> for Element {
+    fn from(li: Li) -> Element {
+        Element::typed("li", li.attributes, content_children(li.content))
+    }
+}
+
+impl From<Ul> for Element {
+    fn from(ul: Ul) -> Element {
+        let items = ul
+            .items
+            .into_iter()
+            .map(|li| Element::from(li).into())
+            .collect();
+        Element::typed("ul", ul.attributes, items)
+    }
+}
+
+impl From<Ol> for Element {
+    fn from(ol: Ol) -> Element {
+        let items = ol
+            .items
+            .into_iter()
+            .map(|li| Element::from(li).into())
+            .collect();
+        Element::typed("ol", ol.attributes, items)
+    }
+}
+
+impl From<Section> for Element {
+    fn from(section: Section) -> Element {
+        Element::typed(
+            "section",
+            section.attributes,
+            content_children(section.content),
+        )
+    }
+}
+
+impl From<Article> for Element {
+    fn from(article: Article) -> Element {
+        Element::typed(
+            "article",
+            article.attributes,
+            content_children(article.content),
+        )
+    }
+}
+
+impl From<Nav> for Element {
+    fn from(nav: Nav) -> Element {
+        Element::typed("nav", nav.attributes, content_children(nav.content))
+    }
+}
+
+impl From<Header> for Element {
+    fn from(header: Header) -> Element {
+        Element::typed(
+            "header",
+            header.attributes,
+            content_children(header.content),
+        )
+    }
+}
+
+impl From<Footer> for Element {
+    fn from(footer: Footer) -> Element {
+        Element::typed(
+            "footer",
+            footer.attributes,
+            content_children(footer.content),
+        )
+    }
+}
+
+impl From<Main> for Element {
+    fn from(main: Main) -> Element {
+        Element::typed("main", main.attributes, content_children(main.content))
+    }
+}
+
+impl From<Aside> for Element {
+    fn from(aside: Aside) -> Element {
+        Element::typed("aside", aside.attributes, content_children(aside.content))
+    }
+}
+
+impl From<P> for Element {
+    fn from(p: P) -> Element {
+        Element::typed("p", p.attributes, content_children(p.content))
+    }
+}
+
+impl From<Blockquote> for Element {
+    fn from(blockquote: Blockquote) -> Element {
+        Element::typed(
+            "blockquote",
+            blockquote.attributes,
+            content_children(blockquote.content),
+        )
+    }
+}
+
+impl From<Pre> for Element {
+    fn from(pre: Pre) -> Element {
+        Element::typed("pre", pre.attributes, content_children(pre.content))
+    }
+}
+
+impl From<Span> for Element {
+    fn from(span: Span) -> Element {
+        Element::typed("span", span.attributes, content_children(span.content))
+    }
+}
+
+impl From<Code> for Element {
+    fn from(code: Code) -> Element {
+        Element::typed("code", code.attributes, content_children(code.content))
+    }
+}
+
+impl From<Strong> for Element {
+    fn from(strong: Strong) -> Element {
+        Element::typed(
+            "strong",
+            strong.attributes,
+            content_children(strong.content),
+        )
+    }
+}
+
+impl From<Em> for Element {
+    fn from(em: Em) -> Element {
+        Element::typed("em", em.attributes, content_children(em.content))
+    }
+}
+
+impl From<Small> for Element {
+    fn from(small: Small) -> Element {
+        Element::typed("small", small.attributes, content_children(small.content))
+    }
+}
+
+impl From<Mark> for Element {
+    fn from(mark: Mark) -> Element {
+        Element::typed("mark", mark.attributes, content_children(mark.content))
+    }
+}
+
+impl From<Sub> for Element {
+    fn from(sub: Sub) -> Element {
+        Element::typed("sub", sub.attributes, content_children(sub.content))
+    }
+}
+
+impl From<Sup> for Element {
+    fn from(sup: Sup) -> Element {
+        Element::typed("sup", sup.attributes, content_children(sup.content))
+    }
+}
+
+impl From<Abbr> for Element {
+    fn from(abbr: Abbr) -> Element {
+        Element::typed("abbr", abbr.attributes, content_children(abbr.content))
+    }
+}
+
+impl From<Hr> for Element {
+    fn from(hr: Hr) -> Element {
+        Element::typed("hr", hr.attributes, vec![])
+    }
+}
+
+impl From<Form> for Element {
+    fn from(form: Form) -> Element {
+        Element::typed("form", form.attributes, content_children(form.content))
+    }
+}
+
+impl From<Label> for Element {
+    fn from(label: Label) -> Element {
+        Element::typed("label", label.attributes, content_children(label.content))
+    }
+}
+
+impl From<Button> for Element {
+    fn from(button: Button) -> Element {
+        Element::typed(
+            "button",
+            button.attributes,
+            content_children(button.content),
+        )
+    }
+}
+
+impl From<Textarea> for Element {
+    fn from(textarea: Textarea) -> Element {
+        let children = if textarea.content.is_empty() {
+            vec![]
+        } else {
+            vec![textarea.content.into()]
+        };
+        Element::typed("textarea", textarea.attributes, children)
+    }
+}
+
+/// The legend becomes the first child
+impl From<Fieldset> for Element {
+    fn from(fieldset: Fieldset) -> Element {
+        let mut children: Vec<HtmlElement> = fieldset
+            .legend
+            .into_iter()
+            .map(|legend| Element::from(legend).into())
+            .collect();
+        children.extend(content_children(fieldset.content));
+        Element::typed("fieldset", fieldset.attributes, children)
+    }
+}
+
+impl From<Legend> for Element {
+    fn from(legend: Legend) -> Element {
+        Element::typed(
+            "legend",
+            legend.attributes,
+            content_children(legend.content),
+        )
+    }
+}
+
+impl From<Datalist> for Element {
+    fn from(datalist: Datalist) -> Element {
+        let options = datalist
+            .options
+            .into_iter()
+            .map(|option| Element::from(option).into())
+            .collect();
+        Element::typed("datalist", datalist.attributes, options)
+    }
+}
+
+impl From<Output> for Element {
+    fn from(output: Output) -> Element {
+        Element::typed(
+            "output",
+            output.attributes,
+            content_children(output.content),
+        )
+    }
+}
+
+impl From<Video> for Element {
+    fn from(video: Video) -> Element {
+        let mut children: Vec<HtmlElement> = elements(video.sources)
+            .chain(elements(video.tracks))
+            .collect();
+        children.extend(content_children(video.content));
+        Element::typed("video", video.attributes, children)
+    }
+}
+
+impl From<Audio> for Element {
+    fn from(audio: Audio) -> Element {
+        let mut children: Vec<HtmlElement> = elements(audio.sources)
+            .chain(elements(audio.tracks))
+            .collect();
+        children.extend(content_children(audio.content));
+        Element::typed("audio", audio.attributes, children)
+    }
+}
+
+impl From<Source> for Element {
+    fn from(source: Source) -> Element {
+        Element::typed("source", source.attributes, vec![])
+    }
+}
+
+impl From<Track> for Element {
+    fn from(track: Track) -> Element {
+        Element::typed("track", track.attributes, vec![])
+    }
+}
+
+impl From<Meta> for Element {
+    fn from(meta: Meta) -> Element {
+        Element::typed("meta", meta.attributes, vec![])
+    }
+}
+
+impl From<Picture> for Element {
+    fn from(picture: Picture) -> Element {
+        let mut children: Vec<HtmlElement> = elements(picture.sources).collect();
+        children.extend(content_children(picture.content));
+        Element::typed("picture", picture.attributes, children)
+    }
+}
+
+#[cfg(test)]
+mod element_tests {
+    use super::{Element, ElementKind, HtmlElement, Render, WithAttributes, WithChildren};
+    use crate::{
+        attribute::Attribute,
+        elements::{
+            Build, Div, Figure, Head, Input, Literal, Metadata, Script, Source, Track, Ul, Video,
+        },
+        render::RenderOptions,
+    };
+
+    fn example_element() -> Element {
+        Element {
+            tag: "section".to_owned(),
+            attributes: vec![Attribute::Id("intro".to_owned())],
+            children: vec!["hello".into(), HtmlElement::Br],
+            kind: ElementKind::Normal,
+        }
+    }
+
+    #[test]
+    fn render_invalid_tag() {
+        let result = Element::new("x onclick=alert(1)")
+            .id("a")
+            .text("hello")
+            .render();
+        let expected = "hello";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_normal() {
+        let result = example_element().render();
+        let expected = "<section id=\"intro\">\n\thello\n\t<br />\n</section>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_empty() {
+        let result = Element::new("my-widget").render();
+        let expected = "<my-widget ></my-widget>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_void() {
+        let result = Element::new("hr").class("rule").render();
+        let expected = "<hr class=\"rule\"/>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_raw_text() {
+        let result = Element::new("textarea").text("a < b\n  c").render();
+        let expected = "<textarea >a &lt; b\n  c</textarea>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_unescaped_text() {
+        let result = [
+            Element::new("script").text("a < b"),
+            Element::new("style").text("a > b{}"),
+            Element::new("title").text("a < b"),
+        ]
+        .map(|elem| elem.render());
+        let expected = [
+            "<script >a < b</script>",
+            "<style >a > b{}</style>",
+            "<title >a &lt; b</title>",
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_raw_text_with_options() {
+        let result = Element::new("pre")
+            .class("x")
+            .child(Element::new("b").text("a"))
+            .render_with(&RenderOptions::minified());
+        let expected = "<pre class=\"x\"><b>a</b></pre>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_whitespace_sensitive() {
+        let result = Div::new()
+            .child(Element::new("pre").child(Literal::preformatted("a\n  b".to_owned())))
+            .render();
+        let expected = "<div >\n\t<pre >a\n  b</pre>\n</div>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_into() {
+        let result = <Element as Into<HtmlElement>>::into(example_element()).render();
+        let expected = example_element().render();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn kind_of_tag() {
+        let result = ["p", "HR", "textarea", "my-widget"].map(ElementKind::of);
+        let expected = [
+            ElementKind::Normal,
+            ElementKind::Void,
+            ElementKind::RawText,
+            ElementKind::Normal,
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn build_element() {
+        let result = Element::new("nav").id("menu").text("home").build();
+        let expected = Element {
+            tag: "nav".to_owned(),
+            attributes: vec![Attribute::Id("menu".to_owned())],
+            children: vec!["home".into()],
+            kind: ElementKind::Normal,
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn convert_typed() {
+        let result: Element = Div::new().id("main").text("a").text("b").into();
+        let expected = Element::new("div").id("main").text("a").text("b");
+        assert_eq!(result, expected);
+        assert_eq!(
+            result.render(),
+            Div::new().id("main").text("a").text("b").render()
+        )
+    }
+
+    #[test]
+    fn convert_void() {
+        let result: Element = Input::new().id("name").into();
+        let expected = Element::new("input").id("name");
+        assert_eq!(result, expected);
+        assert_eq!(result.render(), Input::new().id("name").render())
+    }
+
+    #[test]
+    fn convert_nested() {
+        let result: Element = Ul::new().child(Figure::new().caption("c")).into();
+        let expected = Element::new("ul").child(
+            Element::new("li")
+                .child(Element::new("figure").child(Element::new("figcaption").text("c"))),
+        );
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn convert_script() {
+        let result: Element = Script::new().code("a < b").into();
+        let expected = Element::new("script").child(HtmlElement::Raw("a < b".to_owned()));
+        assert_eq!(result, expected);
+        assert_eq!(result.render(), "<script >a < b</script>");
+        assert_eq!(result.render(), Script::new().code("a < b").render())
+    }
+
+    #[test]
+    fn convert_head() {
+        let head = Head {
+            title: "a & b".to_owned(),
+            metadata: Metadata {
+                charset: Some("utf-8".to_owned()),
+                ..Metadata::default()
+            },
+            ..Head::default()
+        };
+        let result = [head.render(), Element::from(head).render()];
+        let expected = [
+            "<head>\n\t<meta charset=\"utf-8\"/>\n\t<title>\n\t\ta &amp; b\n\t</title>\n\t\n</head>",
+            "<head >\n\t<meta charset=\"utf-8\"/>\n\t<title >a &amp; b</title>\n</head>",
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn convert_video() {
+        let result: Element = Video::new()
+            .text("fallback")
+            .child(Track::new().src("en.vtt"))
+            .child(Source::new().src("a.webm"))
+            .into();
+        let expected = Element::new("video")
+            .child(Element::new("source").attr(Attribute::Src("a.webm".to_owned())))
+            .child(Element::new("track").attr(Attribute::Src("en.vtt".to_owned())))
+            .text("fallback");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn convert_html_element() {
+        let result = Element::try_from(HtmlElement::Br);
+        assert_eq!(result, Ok(Element::new("br")));
+        let result = Element::try_from(HtmlElement::from("text"));
+        assert_eq!(result, Err(HtmlElement::from("text")))
+    }
+}
@@ -1,8 +1,14 @@
-use super::HtmlElement;
-use crate::{attribute::Attribute, render::Render};
-use std::rc::Rc;
+use super::{
+    builder::{push_content, WithAttributes, WithChildren},
+    HtmlElement,
+};
+use crate::{
+    attribute::Attribute,
+    render::{Render, Renderer},
+};
+use std::{fmt, rc::Rc};
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Figure {
     pub attributes: Vec<Attribute>,
     pub content: Rc<HtmlElement>,
@@ -10,11 +16,18 @@ pub struct Figure {
 }
 
 impl Render for Figure {
-    fn render(&self) -> String {
-        let attr_str = self.attributes.render().replace('\n', " ");
-        let contents_str = self.content.render().replace('\n', "\n\t");
-        let caption_str = self.caption.render().replace('\n', "\n\t\t");
-        format!("<figure {attr_str}>\n\t{contents_str}\n\t<figcaption>\n\t\t{caption_str}\n\t</figcaption>\n</figure>")
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<figure")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        out.block(|out| {
+            self.content.render_into(out)?;
+            out.newline()?;
+            out.write_str("<figcaption>")?;
+            out.block(|out| self.caption.render_into(out))?;
+            out.write_str("</figcaption>")
+        })?;
+        out.write_str("</figure>")
     }
 }
 
@@ -24,10 +37,34 @@ impl From<Figure> for HtmlElement {
     }
 }
 
+impl Figure {
+    pub fn new() -> Figure {
+        Figure::default()
+    }
+
+    pub fn caption<T: Into<HtmlElement>>(mut self, caption: T) -> Figure {
+        push_content(&mut self.caption, caption.into());
+        self
+    }
+}
+
+impl WithAttributes for Figure {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+impl WithChildren for Figure {
+    fn push_child(&mut self, child: HtmlElement) {
+        push_content(&mut self.content, child)
+    }
+}
+
 #[cfg(test)]
 mod figure_tests {
 
-    use super::{Attribute, Figure, HtmlElement, Render};
+    use super::{Attribute, Figure, HtmlElement, Render, WithChildren};
+    use crate::elements::Build;
     use std::rc::Rc;
 
     fn example_figure() -> Figure {
@@ -52,4 +89,19 @@ mod figure_tests {
         let expected = example_figure().render();
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn build_figure() {
+        let result = Figure::new()
+            .text("content")
+            .caption("caption".to_owned())
+            .build();
+        let expected = Figure {
+            attributes: vec![],
+            content: Rc::new("content".to_owned().into()),
+            caption: Rc::new("caption".to_owned().into()),
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
 }
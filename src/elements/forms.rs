@@ -0,0 +1,401 @@
+use super::{
+    builder::{push_content, WithAttributes, WithChildren},
+    HtmlElement, Input, Select, SelectOption,
+};
+use crate::{
+    attribute::{Attribute, Method},
+    escape::escape_text,
+    render::{Render, Renderer},
+};
+use std::{fmt, rc::Rc};
+
+/// Attributes shared by the elements whose values are submitted with a form
+pub trait FormControl: WithAttributes {
+    /// The key the value is submitted under
+    fn name(self, name: &str) -> Self {
+        self.attr(Attribute::Name(name.to_owned()))
+    }
+
+    fn disabled(self) -> Self {
+        self.attr(Attribute::Disabled)
+    }
+
+    fn required(self) -> Self {
+        self.attr(Attribute::Required)
+    }
+}
+
+content_elements! {
+    block Form("form");
+    /// Caption of a form control, pointing to the id of the control with `for`
+    inline Label("label");
+    inline Button("button");
+    inline Legend("legend");
+    /// Result of a calculation, pointing to the ids of its inputs with `for`
+    inline Output("output");
+}
+
+/// Multi-line text input, its content is the initial value
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Textarea {
+    pub attributes: Vec<Attribute>,
+    pub content: String,
+}
+
+/// Group of form controls, captioned by its legend
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Fieldset {
+    pub attributes: Vec<Attribute>,
+    pub legend: Option<Legend>,
+    pub content: Rc<HtmlElement>,
+}
+
+/// Values suggested for an input with a `list` attribute
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Datalist {
+    pub attributes: Vec<Attribute>,
+    pub options: Vec<SelectOption>,
+}
+
+impl Render for Textarea {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<textarea")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        // line breaks are part of the value, so they must not be indented.
+        // Browsers drop a line break right after the opening tag, which is made up for here
+        if self.content.starts_with('\n') {
+            out.write_raw("\n")?;
+        }
+        out.write_raw(&escape_text(&self.content))?;
+        out.write_str("</textarea>")
+    }
+}
+
+impl Render for Fieldset {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<fieldset")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        out.block(|out| {
+            if let Some(legend) = &self.legend {
+                legend.render_into(out)?;
+                out.newline()?;
+            }
+            self.content.render_into(out)
+        })?;
+        out.write_str("</fieldset>")
+    }
+}
+
+impl Render for Datalist {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<datalist")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        out.block(|out| out.write_lines(&self.options))?;
+        out.write_str("</datalist>")
+    }
+}
+
+impl From<Textarea> for HtmlElement {
+    fn from(textarea: Textarea) -> HtmlElement {
+        HtmlElement::Textarea(textarea)
+    }
+}
+
+impl From<Fieldset> for HtmlElement {
+    fn from(fieldset: Fieldset) -> HtmlElement {
+        HtmlElement::Fieldset(fieldset)
+    }
+}
+
+impl From<Datalist> for HtmlElement {
+    fn from(datalist: Datalist) -> HtmlElement {
+        HtmlElement::Datalist(datalist)
+    }
+}
+
+impl Form {
+    /// The url the form is submitted to
+    pub fn action(self, action: &str) -> Form {
+        self.attr(Attribute::Action(action.to_owned()))
+    }
+
+    pub fn method(self, method: Method) -> Form {
+        self.attr(Attribute::Method(method))
+    }
+}
+
+impl Label {
+    /// Labels the control with id `id`
+    pub fn for_id(self, id: &str) -> Label {
+        self.attr(Attribute::For(id.to_owned()))
+    }
+}
+
+impl Button {
+    /// `submit`, `reset` or `button`
+    pub fn button_type(self, ty: &str) -> Button {
+        self.attr(Attribute::Type(ty.to_owned()))
+    }
+
+    /// Submitted under the name of the button when it submits the form
+    pub fn value(self, value: &str) -> Button {
+        self.attr(Attribute::Value(value.to_owned()))
+    }
+}
+
+impl FormControl for Button {}
+
+impl Textarea {
+    pub fn new() -> Textarea {
+        Textarea::default()
+    }
+
+    pub fn content(self, content: &str) -> Textarea {
+        Textarea {
+            content: content.to_owned(),
+            ..self
+        }
+    }
+
+    pub fn placeholder(self, placeholder: &str) -> Textarea {
+        self.attr(Attribute::Placeholder(placeholder.to_owned()))
+    }
+}
+
+impl WithAttributes for Textarea {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+impl FormControl for Textarea {}
+
+impl Fieldset {
+    pub fn new() -> Fieldset {
+        Fieldset::default()
+    }
+
+    pub fn legend(self, legend: Legend) -> Fieldset {
+        Fieldset {
+            legend: Some(legend),
+            ..self
+        }
+    }
+}
+
+impl WithAttributes for Fieldset {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+impl WithChildren for Fieldset {
+    fn push_child(&mut self, child: HtmlElement) {
+        push_content(&mut self.content, child)
+    }
+}
+
+impl FormControl for Fieldset {}
+
+impl Datalist {
+    pub fn new() -> Datalist {
+        Datalist::default()
+    }
+
+    pub fn option(mut self, option: SelectOption) -> Datalist {
+        self.options.push(option);
+        self
+    }
+
+    pub fn options<I: IntoIterator<Item = SelectOption>>(mut self, options: I) -> Datalist {
+        self.options.extend(options);
+        self
+    }
+}
+
+impl WithAttributes for Datalist {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+impl Output {
+    /// Ids of the controls the output is calculated from
+    pub fn for_ids(self, ids: &[&str]) -> Output {
+        self.attr(Attribute::For(ids.join(" ")))
+    }
+}
+
+impl FormControl for Output {}
+
+impl Input {
+    pub fn value(self, value: &str) -> Input {
+        self.attr(Attribute::Value(value.to_owned()))
+    }
+
+    pub fn placeholder(self, placeholder: &str) -> Input {
+        self.attr(Attribute::Placeholder(placeholder.to_owned()))
+    }
+
+    /// Suggests the values of the datalist with id `id`
+    pub fn list(self, id: &str) -> Input {
+        self.attr(Attribute::List(id.to_owned()))
+    }
+}
+
+impl FormControl for Input {}
+
+impl FormControl for Select {}
+
+#[cfg(test)]
+mod forms_tests {
+    use super::{
+        Attribute, Button, Datalist, Fieldset, Form, FormControl, HtmlElement, Input, Label,
+        Legend, Method, Output, Render, SelectOption, Textarea, WithAttributes, WithChildren,
+    };
+    use crate::elements::Build;
+    use std::rc::Rc;
+
+    fn example_form() -> Form {
+        Form {
+            attributes: vec![
+                Attribute::Action("/login".to_owned()),
+                Attribute::Method(Method::Post),
+            ],
+            content: Rc::new(
+                vec![
+                    Label::new().for_id("user").text("User").into(),
+                    Input::new().id("user").name("user").required().into(),
+                    Button::new().button_type("submit").text("Login").into(),
+                ]
+                .into(),
+            ),
+        }
+    }
+
+    #[test]
+    fn render_form() {
+        let result = example_form().render();
+        let expected = "<form action=\"/login\" method=\"post\">\n\t<label for=\"user\">User</label>\n\t<input id=\"user\" name=\"user\" required/>\n\t<button type=\"submit\">Login</button>\n</form>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn build_form() {
+        let result = Form::new()
+            .action("/login")
+            .method(Method::Post)
+            .child(Label::new().for_id("user").text("User"))
+            .child(Input::new().id("user").name("user").required())
+            .child(Button::new().button_type("submit").text("Login"))
+            .build();
+        let expected = example_form().into();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_label() {
+        let result = Label::new().for_id("name").text("Name").render();
+        let expected = "<label for=\"name\">Name</label>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_button() {
+        let result = Button::new()
+            .name("action")
+            .value("save")
+            .disabled()
+            .text("Save")
+            .render();
+        let expected = "<button name=\"action\" value=\"save\" disabled>Save</button>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_textarea() {
+        let result = Form::new()
+            .child(
+                Textarea::new()
+                    .name("bio")
+                    .placeholder("About you")
+                    .content("a < b\n  c"),
+            )
+            .render();
+        let expected = "<form >\n\t<textarea name=\"bio\" placeholder=\"About you\">a &lt; b\n  c</textarea>\n</form>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_textarea_leading_newline() {
+        let result = Textarea::new().content("\nfirst").render();
+        let expected = "<textarea >\n\nfirst</textarea>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_fieldset() {
+        let result = Fieldset::new()
+            .legend(Legend::new().text("Address"))
+            .disabled()
+            .child(Input::new().name("street"))
+            .render();
+        let expected = "<fieldset disabled>\n\t<legend >Address</legend>\n\t<input name=\"street\"/>\n</fieldset>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_fieldset_without_legend() {
+        let result = Fieldset::new().child(Input::new()).render();
+        let expected = "<fieldset >\n\t<input />\n</fieldset>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_datalist() {
+        let result = Datalist::new()
+            .id("domains")
+            .option(SelectOption::new("example.com"))
+            .option(SelectOption::new("example.org"))
+            .render();
+        let expected = "<datalist id=\"domains\">\n\t<option value=\"example.com\"></option>\n\t<option value=\"example.org\"></option>\n</datalist>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_input_list() {
+        let result = Input::new().list("domains").placeholder("domain").render();
+        let expected = "<input list=\"domains\" placeholder=\"domain\"/>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_output() {
+        let result = Output::new()
+            .name("sum")
+            .for_ids(&["a", "b"])
+            .text("3")
+            .render();
+        let expected = "<output name=\"sum\" for=\"a b\">3</output>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_into() {
+        let elems: Vec<HtmlElement> = vec![
+            Textarea::new().content("c").into(),
+            Fieldset::new().text("d").into(),
+            Datalist::new().into(),
+        ];
+        let result: Vec<String> = elems.iter().map(HtmlElement::render).collect();
+        let expected = vec![
+            Textarea::new().content("c").render(),
+            Fieldset::new().text("d").render(),
+            Datalist::new().render(),
+        ];
+        assert_eq!(result, expected)
+    }
+}
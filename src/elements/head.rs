@@ -1,13 +1,51 @@
-use super::HtmlElement;
-use crate::render::Render;
-use std::rc::Rc;
+use super::{
+    builder::{push_content, WithAttributes, WithChildren},
+    Element, HtmlElement, Link, Meta,
+};
+use crate::{
+    attribute::Attribute,
+    escape::escape_text,
+    render::{Render, Renderer},
+};
+use std::{fmt, rc::Rc};
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Head {
     pub title: String,
+    pub metadata: Metadata,
     pub content: Rc<HtmlElement>,
 }
 
+/// Typed metadata of a [`Head`].
+///
+/// The charset is rendered before the title, everything else right after it in the order of the
+/// fields, all of it before the free-form content.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Metadata {
+    pub charset: Option<String>,
+    /// Such as `width=device-width, initial-scale=1`
+    pub viewport: Option<String>,
+    /// Url relative urls are resolved against
+    pub base: Option<String>,
+    pub description: Option<String>,
+    pub keywords: Vec<String>,
+    /// Such as `noindex, nofollow`
+    pub robots: Option<String>,
+    pub theme_color: Option<String>,
+    pub canonical: Option<String>,
+    pub icons: Vec<Icon>,
+}
+
+/// A `<link>` to an icon of the page
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Icon {
+    /// `icon` unless set otherwise, e.g. to `apple-touch-icon`
+    pub rel: String,
+    pub href: String,
+    pub mime_type: Option<String>,
+    pub sizes: Option<String>,
+}
+
 impl From<Head> for HtmlElement {
     fn from(head: Head) -> HtmlElement {
         HtmlElement::Head(head)
@@ -15,21 +53,278 @@ impl From<Head> for HtmlElement {
 }
 
 impl Render for Head {
-    fn render(&self) -> String {
-        let title = self.title.clone();
-        let content_str = self.content.render().replace('\n', "\n\t");
-        format!("<head>\n\t<title>\n\t\t{title}\n\t</title>\n\t{content_str}\n</head>")
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<head>")?;
+        out.block(|out| {
+            if let Some(charset) = self.metadata.charset_meta() {
+                charset.render_into(out)?;
+                out.newline()?;
+            }
+            out.write_str("<title>")?;
+            out.block(|out| out.write_str(&escape_text(&self.title)))?;
+            out.write_str("</title>")?;
+            out.newline()?;
+            for elem in self.metadata.after_title() {
+                elem.render_into(out)?;
+                out.newline()?;
+            }
+            self.content.render_into(out)
+        })?;
+        out.write_str("</head>")
+    }
+}
+
+impl Icon {
+    pub fn new(href: &str) -> Icon {
+        Icon {
+            rel: "icon".to_owned(),
+            href: href.to_owned(),
+            mime_type: None,
+            sizes: None,
+        }
+    }
+
+    pub fn rel(self, rel: &str) -> Icon {
+        Icon {
+            rel: rel.to_owned(),
+            ..self
+        }
+    }
+
+    pub fn mime_type(self, mime_type: &str) -> Icon {
+        Icon {
+            mime_type: Some(mime_type.to_owned()),
+            ..self
+        }
+    }
+
+    /// Such as `32x32` or `any`
+    pub fn sizes(self, sizes: &str) -> Icon {
+        Icon {
+            sizes: Some(sizes.to_owned()),
+            ..self
+        }
+    }
+
+    fn link(&self) -> Link {
+        let link = Link::new().rel(&self.rel).href(&self.href);
+        let link = match &self.mime_type {
+            Some(mime_type) => link.attr(Attribute::Type(mime_type.clone())),
+            None => link,
+        };
+        match &self.sizes {
+            Some(sizes) => link.attr(Attribute::Sizes(sizes.clone())),
+            None => link,
+        }
+    }
+
+    /// An icon link with no attributes other than rel, href, type and sizes
+    fn from_link(link: &Link) -> Option<Icon> {
+        let (mut rel, mut href, mut mime_type, mut sizes) = (None, None, None, None);
+        for attr in &link.attributes {
+            match attr {
+                Attribute::Rel(val) if rel.is_none() => rel = Some(val.clone()),
+                Attribute::Href(val) if href.is_none() => href = Some(val.clone()),
+                Attribute::Type(val) if mime_type.is_none() => mime_type = Some(val.clone()),
+                Attribute::Sizes(val) if sizes.is_none() => sizes = Some(val.clone()),
+                _ => return None,
+            }
+        }
+        let rel = rel.filter(|rel| rel.split_whitespace().any(|token| token.ends_with("icon")))?;
+        Some(Icon {
+            rel,
+            href: href?,
+            mime_type,
+            sizes,
+        })
+    }
+}
+
+impl Metadata {
+    fn charset_meta(&self) -> Option<Meta> {
+        self.charset.as_deref().map(Meta::charset)
+    }
+
+    /// Everything but the charset, in the order it is rendered
+    fn after_title(&self) -> Vec<HtmlElement> {
+        let mut elems: Vec<HtmlElement> = vec![];
+        if let Some(viewport) = &self.viewport {
+            elems.push(Meta::named("viewport", viewport).into());
+        }
+        if let Some(base) = &self.base {
+            elems.push(
+                Element::new("base")
+                    .attr(Attribute::Href(base.clone()))
+                    .into(),
+            );
+        }
+        if let Some(description) = &self.description {
+            elems.push(Meta::named("description", description).into());
+        }
+        if !self.keywords.is_empty() {
+            elems.push(Meta::named("keywords", &self.keywords.join(", ")).into());
+        }
+        if let Some(robots) = &self.robots {
+            elems.push(Meta::named("robots", robots).into());
+        }
+        if let Some(theme_color) = &self.theme_color {
+            elems.push(Meta::named("theme-color", theme_color).into());
+        }
+        if let Some(canonical) = &self.canonical {
+            elems.push(Link::new().rel("canonical").href(canonical).into());
+        }
+        elems.extend(self.icons.iter().map(|icon| icon.link().into()));
+        elems
+    }
+
+    /// All metadata as elements, in the order it is rendered
+    pub fn elements(&self) -> Vec<HtmlElement> {
+        let mut elems: Vec<HtmlElement> = self.charset_meta().into_iter().map(Into::into).collect();
+        elems.extend(self.after_title());
+        elems
+    }
+
+    /// Takes over `elem` if it is metadata with a field that is still empty.
+    /// Elements with further attributes are left alone.
+    pub(crate) fn absorb(&mut self, elem: &HtmlElement) -> bool {
+        fn set(field: &mut Option<String>, value: &str) -> bool {
+            *field = Some(value.to_owned());
+            true
+        }
+        match elem {
+            HtmlElement::Meta(meta) => match (meta.attributes.as_slice(), meta.name_content()) {
+                ([Attribute::Charset(charset)], _) if self.charset.is_none() => {
+                    set(&mut self.charset, charset)
+                }
+                (_, Some(("viewport", content))) if self.viewport.is_none() => {
+                    set(&mut self.viewport, content)
+                }
+                (_, Some(("description", content))) if self.description.is_none() => {
+                    set(&mut self.description, content)
+                }
+                (_, Some(("keywords", content))) if self.keywords.is_empty() => {
+                    self.keywords = content
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|keyword| !keyword.is_empty())
+                        .map(str::to_owned)
+                        .collect();
+                    true
+                }
+                (_, Some(("robots", content))) if self.robots.is_none() => {
+                    set(&mut self.robots, content)
+                }
+                (_, Some(("theme-color", content))) if self.theme_color.is_none() => {
+                    set(&mut self.theme_color, content)
+                }
+                _ => false,
+            },
+            HtmlElement::Link(link) => match link.attributes.as_slice() {
+                [Attribute::Rel(rel), Attribute::Href(href)]
+                | [Attribute::Href(href), Attribute::Rel(rel)]
+                    if rel == "canonical" && self.canonical.is_none() =>
+                {
+                    set(&mut self.canonical, href)
+                }
+                _ => match Icon::from_link(link) {
+                    Some(icon) => {
+                        self.icons.push(icon);
+                        true
+                    }
+                    None => false,
+                },
+            },
+            HtmlElement::Element(elem) if elem.tag == "base" && elem.children.is_empty() => {
+                match elem.attributes.as_slice() {
+                    [Attribute::Href(href)] if self.base.is_none() => set(&mut self.base, href),
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Head {
+    pub fn new() -> Head {
+        Head::default()
+    }
+
+    pub fn title(self, title: &str) -> Head {
+        Head {
+            title: title.to_owned(),
+            ..self
+        }
+    }
+
+    pub fn charset(mut self, charset: &str) -> Head {
+        self.metadata.charset = Some(charset.to_owned());
+        self
+    }
+
+    pub fn viewport(mut self, viewport: &str) -> Head {
+        self.metadata.viewport = Some(viewport.to_owned());
+        self
+    }
+
+    pub fn base(mut self, href: &str) -> Head {
+        self.metadata.base = Some(href.to_owned());
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Head {
+        self.metadata.description = Some(description.to_owned());
+        self
+    }
+
+    pub fn keywords<'a, I: IntoIterator<Item = &'a str>>(mut self, keywords: I) -> Head {
+        self.metadata
+            .keywords
+            .extend(keywords.into_iter().map(str::to_owned));
+        self
+    }
+
+    pub fn robots(mut self, robots: &str) -> Head {
+        self.metadata.robots = Some(robots.to_owned());
+        self
+    }
+
+    pub fn theme_color(mut self, color: &str) -> Head {
+        self.metadata.theme_color = Some(color.to_owned());
+        self
+    }
+
+    pub fn canonical(mut self, href: &str) -> Head {
+        self.metadata.canonical = Some(href.to_owned());
+        self
+    }
+
+    pub fn icon(mut self, icon: Icon) -> Head {
+        self.metadata.icons.push(icon);
+        self
+    }
+}
+
+impl WithChildren for Head {
+    fn push_child(&mut self, child: HtmlElement) {
+        push_content(&mut self.content, child)
     }
 }
 
 #[cfg(test)]
 mod head_tests {
-    use super::{Head, HtmlElement, Render};
+    use super::{Head, HtmlElement, Icon, Metadata, Render, WithChildren};
+    use crate::{
+        attribute::Attribute,
+        elements::{Build, Element, Link, Meta, WithAttributes},
+        render::RenderOptions,
+    };
     use std::rc::Rc;
 
     fn example_head() -> Head {
         Head {
             title: "a page".to_owned(),
+            metadata: Metadata::default(),
             content: Rc::new("no other loads".to_owned().into()),
         }
     }
@@ -47,4 +342,97 @@ mod head_tests {
         let expected = example_head().render();
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn render_escaped_title() {
+        let head = Head {
+            title: "Q&A <beta>".to_owned(),
+            metadata: Metadata::default(),
+            content: Rc::new(vec![].into()),
+        };
+        let result = head.render();
+        let expected = "<head>\n\t<title>\n\t\tQ&amp;A &lt;beta&gt;\n\t</title>\n\t\n</head>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn build_head() {
+        let result = Head::new().title("title").text("content").build();
+        let expected = Head {
+            title: "title".to_owned(),
+            metadata: Metadata::default(),
+            content: Rc::new("content".to_owned().into()),
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
+
+    fn example_metadata() -> Head {
+        Head::new()
+            .title("page")
+            .icon(Icon::new("/favicon.svg").mime_type("image/svg+xml"))
+            .theme_color("#ffffff")
+            .canonical("https://example.com/")
+            .robots("noindex")
+            .keywords(["a", "b"])
+            .description("A page")
+            .base("/docs/")
+            .viewport("width=device-width, initial-scale=1")
+            .charset("utf-8")
+            .text("content")
+    }
+
+    #[test]
+    fn render_metadata_in_order() {
+        let result = example_metadata().render_with(&RenderOptions::minified());
+        let expected = "<head><meta charset=\"utf-8\"/><title>page</title>\
+            <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\"/>\
+            <base href=\"/docs/\"/>\
+            <meta name=\"description\" content=\"A page\"/>\
+            <meta name=\"keywords\" content=\"a, b\"/>\
+            <meta name=\"robots\" content=\"noindex\"/>\
+            <meta name=\"theme-color\" content=\"#ffffff\"/>\
+            <link rel=\"canonical\" href=\"https://example.com/\"/>\
+            <link rel=\"icon\" href=\"/favicon.svg\" type=\"image/svg+xml\"/>\
+            content</head>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_charset_indented() {
+        let result = Head::new().title("t").charset("utf-8").render();
+        let expected =
+            "<head>\n\t<meta charset=\"utf-8\"/>\n\t<title>\n\t\tt\n\t</title>\n\t\n</head>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn absorb_metadata() {
+        let elems: Vec<HtmlElement> = vec![
+            Meta::charset("utf-8").into(),
+            Meta::named("keywords", "a, b,").into(),
+            Meta::named("description", "first").into(),
+            Meta::named("description", "second").into(),
+            Meta::named("author", "me").into(),
+            Link::new().href("/").rel("canonical").into(),
+            Link::new().rel("apple-touch-icon").href("/a.png").into(),
+            Link::new().rel("stylesheet").href("a.css").into(),
+            Element::new("base")
+                .attr(Attribute::Href("/x/".to_owned()))
+                .into(),
+        ];
+        let mut metadata = Metadata::default();
+        let result: Vec<bool> = elems.iter().map(|elem| metadata.absorb(elem)).collect();
+        let expected = vec![true, true, true, false, false, true, true, false, true];
+        assert_eq!(result, expected);
+        let expected = Head::new()
+            .charset("utf-8")
+            .keywords(["a", "b"])
+            .description("first")
+            .canonical("/")
+            .icon(Icon::new("/a.png").rel("apple-touch-icon"))
+            .base("/x/")
+            .metadata;
+        assert_eq!(metadata, expected)
+    }
 }
@@ -1,8 +1,14 @@
-use super::HtmlElement;
-use crate::{attribute::Attribute, render::Render};
-use std::rc::Rc;
+use super::{
+    builder::{push_content, WithAttributes, WithChildren},
+    HtmlElement,
+};
+use crate::{
+    attribute::Attribute,
+    render::{Render, Renderer},
+};
+use std::{fmt, rc::Rc};
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum HeaderSize {
     H1,
     H2,
@@ -12,7 +18,7 @@ pub enum HeaderSize {
     H6,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Headline {
     pub size: HeaderSize,
     pub attributes: Vec<Attribute>,
@@ -25,30 +31,57 @@ impl From<Headline> for HtmlElement {
     }
 }
 impl Render for Headline {
-    fn render(&self) -> String {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
         let size_tag = self.size.render();
-        let content_str = self.content.render().replace('\n', "\n\t");
-        let attr_str = self.attributes.render().replace('\n', " ");
-        format!("<{size_tag} {attr_str}>\n\t{content_str}\n</{size_tag}>")
+        out.write_str(&format!("<{size_tag}"))?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        out.block(|out| self.content.render_into(out))?;
+        out.write_str(&format!("</{size_tag}>"))
     }
 }
 
 impl Render for HeaderSize {
-    fn render(&self) -> String {
-        match self {
-            HeaderSize::H1 => "h1".to_owned(),
-            HeaderSize::H2 => "h2".to_owned(),
-            HeaderSize::H3 => "h3".to_owned(),
-            HeaderSize::H4 => "h4".to_owned(),
-            HeaderSize::H5 => "h5".to_owned(),
-            HeaderSize::H6 => "h6".to_owned(),
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str(match self {
+            HeaderSize::H1 => "h1",
+            HeaderSize::H2 => "h2",
+            HeaderSize::H3 => "h3",
+            HeaderSize::H4 => "h4",
+            HeaderSize::H5 => "h5",
+            HeaderSize::H6 => "h6",
+        })
+    }
+}
+
+impl Headline {
+    pub fn new(size: HeaderSize) -> Headline {
+        Headline {
+            size,
+            attributes: vec![],
+            content: Rc::default(),
         }
     }
 }
 
+impl WithAttributes for Headline {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+impl WithChildren for Headline {
+    fn push_child(&mut self, child: HtmlElement) {
+        push_content(&mut self.content, child)
+    }
+}
+
 #[cfg(test)]
 mod headline_tests {
-    use super::{Attribute, HeaderSize, Headline, HtmlElement, Render};
+    use super::{
+        Attribute, HeaderSize, Headline, HtmlElement, Render, WithAttributes, WithChildren,
+    };
+    use crate::elements::Build;
     use std::rc::Rc;
 
     fn example_headline() -> Headline {
@@ -114,4 +147,19 @@ mod headline_tests {
         let expected = "h6";
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn build_headline() {
+        let result = Headline::new(HeaderSize::H2)
+            .id("title")
+            .text("title")
+            .build();
+        let expected = Headline {
+            size: HeaderSize::H2,
+            attributes: vec![Attribute::Id("title".to_owned())],
+            content: Rc::new("title".to_owned().into()),
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
 }
@@ -1,15 +1,21 @@
-use super::HtmlElement;
-use crate::{attribute::Attribute, render::Render};
+use super::{builder::WithAttributes, HtmlElement};
+use crate::{
+    attribute::Attribute,
+    render::{Render, Renderer},
+};
+use std::fmt;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Img {
     pub attributes: Vec<Attribute>,
 }
 
 impl Render for Img {
-    fn render(&self) -> String {
-        let attr_str = self.attributes.render().replace('\n', " ");
-        format!("<img {attr_str} />")
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<img")?;
+        out.write_attributes(&self.attributes)?;
+        out.space()?;
+        out.write_str("/>")
     }
 }
 
@@ -19,10 +25,28 @@ impl From<Img> for HtmlElement {
     }
 }
 
+impl Img {
+    pub fn new() -> Img {
+        Img::default()
+    }
+
+    pub fn src(self, src: &str) -> Img {
+        self.attr(Attribute::Src(src.to_owned()))
+    }
+}
+
+impl WithAttributes for Img {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
 #[cfg(test)]
 mod img_tests {
 
-    use super::{Attribute, HtmlElement, Img, Render};
+    use super::{Attribute, HtmlElement, Img, Render, WithAttributes};
+    use crate::elements::Build;
+    use crate::render::RenderOptions;
 
     fn example_img() -> Img {
         Img {
@@ -46,4 +70,24 @@ mod img_tests {
         let expected = example_img().render();
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn render_minified() {
+        let result = Img { attributes: vec![] }.render_with(&RenderOptions::minified());
+        let expected = "<img/>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn build_img() {
+        let result = Img::new().src("a.png").class("logo").build();
+        let expected = Img {
+            attributes: vec![
+                Attribute::Src("a.png".to_owned()),
+                Attribute::Class(vec!["logo".to_owned()]),
+            ],
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
 }
@@ -1,15 +1,397 @@
-use super::HtmlElement;
-use crate::{attribute::Attribute, render::Render};
+use super::{builder::WithAttributes, HtmlElement};
+use crate::{
+    attribute::Attribute,
+    parse::ParseError,
+    render::{Render, Renderer},
+};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use std::{error::Error, fmt, str::FromStr};
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Input {
+    /// Rendered as `type` in front of the other attributes, `None` leaves the type to the
+    /// attributes
+    pub kind: Option<InputKind>,
     pub attributes: Vec<Attribute>,
 }
 
+/// The type of an input, together with the attributes that only apply to that type
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputKind {
+    Text,
+    Search,
+    Tel,
+    Url,
+    Password,
+    Email {
+        /// Accepts a comma separated list of addresses
+        multiple: bool,
+    },
+    Number(Bounded<f64>),
+    Range(Bounded<f64>),
+    Date(Bounded<NaiveDate>),
+    DatetimeLocal(Bounded<NaiveDateTime>),
+    Time(Bounded<NaiveTime>),
+    Checkbox {
+        checked: bool,
+    },
+    Radio {
+        checked: bool,
+    },
+    File {
+        /// File extensions such as `.pdf` or mime types such as `image/*`
+        accept: Vec<String>,
+        multiple: bool,
+    },
+    Hidden,
+    Color,
+    Submit,
+    Reset,
+    Button,
+}
+
+/// Value, bounds and step of the numeric and date kinds
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bounded<T> {
+    pub value: Option<T>,
+    pub min: Option<T>,
+    pub max: Option<T>,
+    /// In seconds for the time kinds and in days for dates
+    pub step: Option<f64>,
+}
+
+/// Values that can be written to the attributes of an input
+pub trait InputValue: Copy + PartialOrd {
+    fn to_attribute(&self) -> String;
+    fn from_attribute(value: &str) -> Option<Self>;
+}
+
+/// Contradictions found by [`Input::validate`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputError {
+    /// `min` is greater than `max`
+    EmptyRange,
+    /// The value lies below `min` or above `max`
+    OutOfRange,
+    /// `step` is not a positive number
+    InvalidStep,
+    /// A `type` attribute next to the kind
+    ConflictingType,
+    /// An attribute that does not apply to the kind of the input
+    Unsupported(String),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::EmptyRange => write!(f, "min is greater than max"),
+            InputError::OutOfRange => write!(f, "value lies outside of min and max"),
+            InputError::InvalidStep => write!(f, "step has to be a positive number"),
+            InputError::ConflictingType => write!(f, "type attribute next to the kind"),
+            InputError::Unsupported(name) => write!(f, "{name} does not apply to this kind"),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+impl InputValue for f64 {
+    fn to_attribute(&self) -> String {
+        self.to_string()
+    }
+    fn from_attribute(value: &str) -> Option<f64> {
+        value.trim().parse().ok()
+    }
+}
+
+impl InputValue for NaiveDate {
+    fn to_attribute(&self) -> String {
+        self.format("%Y-%m-%d").to_string()
+    }
+    fn from_attribute(value: &str) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").ok()
+    }
+}
+
+/// Seconds are only written when they are not zero
+impl InputValue for NaiveDateTime {
+    fn to_attribute(&self) -> String {
+        format!(
+            "{}T{}",
+            self.date().to_attribute(),
+            self.time().to_attribute()
+        )
+    }
+    fn from_attribute(value: &str) -> Option<NaiveDateTime> {
+        let (date, time) = value.trim().split_once('T')?;
+        Some(NaiveDate::from_attribute(date)?.and_time(NaiveTime::from_attribute(time)?))
+    }
+}
+
+/// Seconds are only written when they are not zero
+impl InputValue for NaiveTime {
+    fn to_attribute(&self) -> String {
+        if self.second() == 0 && self.nanosecond() == 0 {
+            self.format("%H:%M").to_string()
+        } else {
+            self.format("%H:%M:%S%.f").to_string()
+        }
+    }
+    fn from_attribute(value: &str) -> Option<NaiveTime> {
+        let value = value.trim();
+        NaiveTime::parse_from_str(value, "%H:%M:%S%.f")
+            .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M"))
+            .ok()
+    }
+}
+
+impl<T> Default for Bounded<T> {
+    fn default() -> Bounded<T> {
+        Bounded {
+            value: None,
+            min: None,
+            max: None,
+            step: None,
+        }
+    }
+}
+
+impl<T: InputValue> Bounded<T> {
+    pub fn new() -> Bounded<T> {
+        Bounded::default()
+    }
+
+    pub fn value(self, value: T) -> Bounded<T> {
+        Bounded {
+            value: Some(value),
+            ..self
+        }
+    }
+
+    pub fn min(self, min: T) -> Bounded<T> {
+        Bounded {
+            min: Some(min),
+            ..self
+        }
+    }
+
+    pub fn max(self, max: T) -> Bounded<T> {
+        Bounded {
+            max: Some(max),
+            ..self
+        }
+    }
+
+    pub fn step(self, step: f64) -> Bounded<T> {
+        Bounded {
+            step: Some(step),
+            ..self
+        }
+    }
+
+    fn validate(&self) -> Result<(), InputError> {
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            if min > max {
+                return Err(InputError::EmptyRange);
+            }
+        }
+        if let Some(value) = self.value {
+            if self.min.is_some_and(|min| value < min) || self.max.is_some_and(|max| value > max) {
+                return Err(InputError::OutOfRange);
+            }
+        }
+        match self.step {
+            Some(step) if !(step.is_finite() && step > 0.0) => Err(InputError::InvalidStep),
+            _ => Ok(()),
+        }
+    }
+
+    fn attributes(&self) -> Vec<Attribute> {
+        let mut attributes = vec![];
+        if let Some(min) = self.min {
+            attributes.push(Attribute::Min(min.to_attribute()));
+        }
+        if let Some(max) = self.max {
+            attributes.push(Attribute::Max(max.to_attribute()));
+        }
+        if let Some(step) = self.step {
+            attributes.push(Attribute::Step(step.to_attribute()));
+        }
+        if let Some(value) = self.value {
+            attributes.push(Attribute::Value(value.to_attribute()));
+        }
+        attributes
+    }
+
+    /// Takes min, max, step and value out of `attributes`, `None` if one of them is malformed
+    fn take(attributes: &mut Vec<Attribute>) -> Option<Bounded<T>> {
+        let mut bounded = Bounded::new();
+        let mut rest = vec![];
+        for attr in attributes.drain(..) {
+            match attr {
+                Attribute::Min(min) => bounded.min = Some(T::from_attribute(&min)?),
+                Attribute::Max(max) => bounded.max = Some(T::from_attribute(&max)?),
+                Attribute::Step(step) => bounded.step = Some(f64::from_attribute(&step)?),
+                Attribute::Value(value) => bounded.value = Some(T::from_attribute(&value)?),
+                attr => rest.push(attr),
+            }
+        }
+        *attributes = rest;
+        Some(bounded)
+    }
+}
+
+impl InputKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            InputKind::Text => "text",
+            InputKind::Search => "search",
+            InputKind::Tel => "tel",
+            InputKind::Url => "url",
+            InputKind::Password => "password",
+            InputKind::Email { .. } => "email",
+            InputKind::Number(_) => "number",
+            InputKind::Range(_) => "range",
+            InputKind::Date(_) => "date",
+            InputKind::DatetimeLocal(_) => "datetime-local",
+            InputKind::Time(_) => "time",
+            InputKind::Checkbox { .. } => "checkbox",
+            InputKind::Radio { .. } => "radio",
+            InputKind::File { .. } => "file",
+            InputKind::Hidden => "hidden",
+            InputKind::Color => "color",
+            InputKind::Submit => "submit",
+            InputKind::Reset => "reset",
+            InputKind::Button => "button",
+        }
+    }
+
+    /// The type followed by the attributes of the kind
+    pub fn attributes(&self) -> Vec<Attribute> {
+        let mut attributes = vec![Attribute::Type(self.name().to_owned())];
+        match self {
+            InputKind::Number(bounded) | InputKind::Range(bounded) => {
+                attributes.extend(bounded.attributes())
+            }
+            InputKind::Date(bounded) => attributes.extend(bounded.attributes()),
+            InputKind::DatetimeLocal(bounded) => attributes.extend(bounded.attributes()),
+            InputKind::Time(bounded) => attributes.extend(bounded.attributes()),
+            InputKind::Checkbox { checked: true } | InputKind::Radio { checked: true } => {
+                attributes.push(Attribute::Checked)
+            }
+            InputKind::File { accept, multiple } => {
+                if !accept.is_empty() {
+                    attributes.push(Attribute::Accept(accept.join(",")));
+                }
+                if *multiple {
+                    attributes.push(Attribute::Multiple);
+                }
+            }
+            InputKind::Email { multiple: true } => attributes.push(Attribute::Multiple),
+            _ => (),
+        }
+        attributes
+    }
+
+    fn validate(&self) -> Result<(), InputError> {
+        match self {
+            InputKind::Number(bounded) | InputKind::Range(bounded) => bounded.validate(),
+            InputKind::Date(bounded) => bounded.validate(),
+            InputKind::DatetimeLocal(bounded) => bounded.validate(),
+            InputKind::Time(bounded) => bounded.validate(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether `attribute` can be used next to this kind
+    fn supports(&self, attribute: &Attribute) -> bool {
+        let text = matches!(
+            self,
+            InputKind::Text
+                | InputKind::Search
+                | InputKind::Tel
+                | InputKind::Url
+                | InputKind::Password
+                | InputKind::Email { .. }
+        );
+        let button = matches!(
+            self,
+            InputKind::Submit | InputKind::Reset | InputKind::Button
+        );
+        match attribute {
+            Attribute::Placeholder(_) => text || matches!(self, InputKind::Number(_)),
+            Attribute::List(_) => {
+                !(button
+                    || matches!(
+                        self,
+                        InputKind::Password
+                            | InputKind::Checkbox { .. }
+                            | InputKind::Radio { .. }
+                            | InputKind::File { .. }
+                            | InputKind::Hidden
+                    ))
+            }
+            Attribute::Required => {
+                !(button
+                    || matches!(
+                        self,
+                        InputKind::Range(_) | InputKind::Color | InputKind::Hidden
+                    ))
+            }
+            // these kinds keep their value in the kind itself
+            Attribute::Value(_) => !matches!(
+                self,
+                InputKind::File { .. }
+                    | InputKind::Number(_)
+                    | InputKind::Range(_)
+                    | InputKind::Date(_)
+                    | InputKind::DatetimeLocal(_)
+                    | InputKind::Time(_)
+            ),
+            Attribute::Min(_) | Attribute::Max(_) | Attribute::Step(_) => false,
+            Attribute::Accept(_) | Attribute::Multiple | Attribute::Checked => false,
+            _ => true,
+        }
+    }
+}
+
+/// Kinds are parsed without their attributes, which start out empty
+impl FromStr for InputKind {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<InputKind, ParseError> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" => Ok(InputKind::Text),
+            "search" => Ok(InputKind::Search),
+            "tel" => Ok(InputKind::Tel),
+            "url" => Ok(InputKind::Url),
+            "password" => Ok(InputKind::Password),
+            "email" => Ok(InputKind::Email { multiple: false }),
+            "number" => Ok(InputKind::Number(Bounded::new())),
+            "range" => Ok(InputKind::Range(Bounded::new())),
+            "date" => Ok(InputKind::Date(Bounded::new())),
+            "datetime-local" => Ok(InputKind::DatetimeLocal(Bounded::new())),
+            "time" => Ok(InputKind::Time(Bounded::new())),
+            "checkbox" => Ok(InputKind::Checkbox { checked: false }),
+            "radio" => Ok(InputKind::Radio { checked: false }),
+            "file" => Ok(InputKind::File {
+                accept: vec![],
+                multiple: false,
+            }),
+            "hidden" => Ok(InputKind::Hidden),
+            "color" => Ok(InputKind::Color),
+            "submit" => Ok(InputKind::Submit),
+            "reset" => Ok(InputKind::Reset),
+            "button" => Ok(InputKind::Button),
+            _ => Err(ParseError::at(s, 0, &format!("unknown input type `{s}`"))),
+        }
+    }
+}
+
 impl Render for Input {
-    fn render(&self) -> String {
-        let attr_str = self.attributes.render().replace('\n', " ");
-        format!("<input {attr_str}/>")
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<input")?;
+        out.write_attributes(&self.all_attributes())?;
+        out.write_str("/>")
     }
 }
 impl From<Input> for HtmlElement {
@@ -18,17 +400,149 @@ impl From<Input> for HtmlElement {
     }
 }
 
+impl Input {
+    pub fn new() -> Input {
+        Input::default()
+    }
+
+    pub fn kind(self, kind: InputKind) -> Input {
+        Input {
+            kind: Some(kind),
+            ..self
+        }
+    }
+
+    /// The attributes of the kind followed by the other attributes
+    pub fn all_attributes(&self) -> Vec<Attribute> {
+        let mut attributes = self
+            .kind
+            .as_ref()
+            .map(InputKind::attributes)
+            .unwrap_or_default();
+        attributes.extend(self.attributes.iter().cloned());
+        attributes
+    }
+
+    /// Moves a known `type` attribute and the attributes belonging to it into the kind.
+    /// Nothing is moved when one of them is malformed.
+    pub fn typed(self) -> Input {
+        if self.kind.is_some() {
+            return self;
+        }
+        let known = self
+            .attributes
+            .iter()
+            .enumerate()
+            .find_map(|(pos, attr)| match attr {
+                Attribute::Type(ty) => InputKind::from_str(ty).ok().map(|kind| (pos, kind)),
+                _ => None,
+            });
+        let Some((pos, kind)) = known else {
+            return self;
+        };
+        let mut attributes = self.attributes.clone();
+        attributes.remove(pos);
+        let kind = match kind {
+            InputKind::Number(_) => Bounded::take(&mut attributes).map(InputKind::Number),
+            InputKind::Range(_) => Bounded::take(&mut attributes).map(InputKind::Range),
+            InputKind::Date(_) => Bounded::take(&mut attributes).map(InputKind::Date),
+            InputKind::DatetimeLocal(_) => {
+                Bounded::take(&mut attributes).map(InputKind::DatetimeLocal)
+            }
+            InputKind::Time(_) => Bounded::take(&mut attributes).map(InputKind::Time),
+            InputKind::Checkbox { .. } => Some(InputKind::Checkbox {
+                checked: take_flag(&mut attributes, &Attribute::Checked),
+            }),
+            InputKind::Radio { .. } => Some(InputKind::Radio {
+                checked: take_flag(&mut attributes, &Attribute::Checked),
+            }),
+            InputKind::Email { .. } => Some(InputKind::Email {
+                multiple: take_flag(&mut attributes, &Attribute::Multiple),
+            }),
+            InputKind::File { .. } => {
+                let accept = attributes
+                    .iter()
+                    .find_map(|attr| match attr {
+                        Attribute::Accept(accept) => Some(accept.clone()),
+                        _ => None,
+                    })
+                    .map(|accept| {
+                        accept
+                            .split(',')
+                            .map(|ty| ty.trim().to_owned())
+                            .filter(|ty| !ty.is_empty())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                attributes.retain(|attr| !matches!(attr, Attribute::Accept(_)));
+                Some(InputKind::File {
+                    accept,
+                    multiple: take_flag(&mut attributes, &Attribute::Multiple),
+                })
+            }
+            kind => Some(kind),
+        };
+        match kind {
+            Some(kind) => Input {
+                kind: Some(kind),
+                attributes,
+            },
+            None => self,
+        }
+    }
+
+    /// Checks the kind for contradicting bounds and the attributes for ones that do not apply
+    /// to the kind
+    pub fn validate(&self) -> Result<(), InputError> {
+        let Some(kind) = &self.kind else {
+            return Ok(());
+        };
+        kind.validate()?;
+        for attr in self.attributes.iter() {
+            if let Attribute::Type(_) = attr {
+                return Err(InputError::ConflictingType);
+            }
+            if !kind.supports(attr) {
+                return Err(InputError::Unsupported(attr.name().into_owned()));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Removes all occurrences of the boolean attribute `flag`, true if there were any
+fn take_flag(attributes: &mut Vec<Attribute>, flag: &Attribute) -> bool {
+    let len = attributes.len();
+    attributes.retain(|attr| attr != flag);
+    attributes.len() != len
+}
+
+impl WithAttributes for Input {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
 #[cfg(test)]
 mod input_test {
 
-    use super::{Attribute, HtmlElement, Input, Render};
+    use super::{
+        Attribute, Bounded, HtmlElement, Input, InputError, InputKind, Render, WithAttributes,
+    };
+    use crate::elements::{Build, FormControl};
+    use chrono::{NaiveDate, NaiveTime};
 
     fn example_input() -> Input {
         Input {
+            kind: None,
             attributes: vec![Attribute::Id("name".to_owned())],
         }
     }
 
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
     #[test]
     fn render_input() {
         let result = example_input().render();
@@ -42,4 +556,142 @@ mod input_test {
         let expected = example_input().render();
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn build_input() {
+        let result = Input::new().id("name").build();
+        let expected = Input {
+            kind: None,
+            attributes: vec![Attribute::Id("name".to_owned())],
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_number() {
+        let result = Input::new()
+            .kind(InputKind::Number(
+                Bounded::new().min(0.0).max(10.0).step(0.5).value(2.5),
+            ))
+            .name("amount")
+            .render();
+        let expected =
+            "<input type=\"number\" min=\"0\" max=\"10\" step=\"0.5\" value=\"2.5\" name=\"amount\"/>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_date() {
+        let result = Input::new()
+            .kind(InputKind::Date(
+                Bounded::new().min(date(2024, 1, 1)).value(date(2024, 3, 9)),
+            ))
+            .render();
+        let expected = "<input type=\"date\" min=\"2024-01-01\" value=\"2024-03-09\"/>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_datetime_local() {
+        let start = date(2024, 3, 9).and_hms_opt(8, 30, 0).unwrap();
+        let end = date(2024, 3, 9).and_hms_opt(17, 0, 15).unwrap();
+        let result = Input::new()
+            .kind(InputKind::DatetimeLocal(Bounded::new().min(start).max(end)))
+            .render();
+        let expected =
+            "<input type=\"datetime-local\" min=\"2024-03-09T08:30\" max=\"2024-03-09T17:00:15\"/>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_checkbox_file() {
+        let result = [
+            Input::new().kind(InputKind::Checkbox { checked: true }),
+            Input::new().kind(InputKind::File {
+                accept: vec!["image/*".to_owned(), ".pdf".to_owned()],
+                multiple: true,
+            }),
+        ]
+        .map(|input| input.render());
+        let expected = [
+            "<input type=\"checkbox\" checked/>",
+            "<input type=\"file\" accept=\"image/*,.pdf\" multiple/>",
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn typed_from_attributes() {
+        let result = Input::new()
+            .id("start")
+            .attr(Attribute::Type("time".to_owned()))
+            .attr(Attribute::Min("09:00".to_owned()))
+            .attr(Attribute::Step("900".to_owned()))
+            .typed();
+        let expected = Input::new()
+            .kind(InputKind::Time(
+                Bounded::new()
+                    .min(NaiveTime::from_hms_opt(9, 0, 0).unwrap())
+                    .step(900.0),
+            ))
+            .id("start");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn typed_keeps_malformed() {
+        let input = Input::new()
+            .attr(Attribute::Type("number".to_owned()))
+            .attr(Attribute::Min("low".to_owned()));
+        let result = input.clone().typed();
+        assert_eq!(result, input)
+    }
+
+    #[test]
+    fn validate_ok() {
+        let result = Input::new()
+            .kind(InputKind::Range(Bounded::new().min(0.0).max(1.0).step(0.1)))
+            .name("volume")
+            .validate();
+        assert_eq!(result, Ok(()))
+    }
+
+    #[test]
+    fn validate_bounds() {
+        let result = [
+            InputKind::Number(Bounded::new().min(5.0).max(1.0)),
+            InputKind::Date(Bounded::new().max(date(2024, 1, 1)).value(date(2024, 2, 1))),
+            InputKind::Range(Bounded::new().step(0.0)),
+        ]
+        .map(|kind| Input::new().kind(kind).validate());
+        let expected = [
+            Err(InputError::EmptyRange),
+            Err(InputError::OutOfRange),
+            Err(InputError::InvalidStep),
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn validate_attributes() {
+        let result = [
+            Input::new()
+                .kind(InputKind::Checkbox { checked: false })
+                .placeholder("no"),
+            Input::new().kind(InputKind::Hidden).required(),
+            Input::new()
+                .kind(InputKind::Text)
+                .attr(Attribute::Type("email".to_owned())),
+            Input::new().kind(InputKind::Text).attr(Attribute::Multiple),
+        ]
+        .map(|input| input.validate());
+        let expected = [
+            Err(InputError::Unsupported("placeholder".to_owned())),
+            Err(InputError::Unsupported("required".to_owned())),
+            Err(InputError::ConflictingType),
+            Err(InputError::Unsupported("multiple".to_owned())),
+        ];
+        assert_eq!(result, expected)
+    }
 }
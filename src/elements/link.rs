@@ -1,15 +1,20 @@
-use super::HtmlElement;
-use crate::{attribute::Attribute, render::Render};
+use super::{builder::WithAttributes, HtmlElement};
+use crate::{
+    attribute::Attribute,
+    render::{Render, Renderer},
+};
+use std::fmt;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Link {
     pub attributes: Vec<Attribute>,
 }
 
 impl Render for Link {
-    fn render(&self) -> String {
-        let attr_str = self.attributes.render().replace('\n', " ");
-        format!("<link {attr_str}/>")
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<link")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str("/>")
     }
 }
 
@@ -19,10 +24,31 @@ impl From<Link> for HtmlElement {
     }
 }
 
+impl Link {
+    pub fn new() -> Link {
+        Link::default()
+    }
+
+    pub fn href(self, href: &str) -> Link {
+        self.attr(Attribute::Href(href.to_owned()))
+    }
+
+    pub fn rel(self, rel: &str) -> Link {
+        self.attr(Attribute::Rel(rel.to_owned()))
+    }
+}
+
+impl WithAttributes for Link {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
 #[cfg(test)]
 mod link_tests {
 
     use super::{Attribute, HtmlElement, Link, Render};
+    use crate::elements::Build;
 
     fn example_link() -> Link {
         Link {
@@ -43,4 +69,17 @@ mod link_tests {
         let expected = example_link().render();
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn build_link() {
+        let result = Link::new().rel("stylesheet").href("main.css").build();
+        let expected = Link {
+            attributes: vec![
+                Attribute::Rel("stylesheet".to_owned()),
+                Attribute::Href("main.css".to_owned()),
+            ],
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
 }
@@ -1,46 +1,58 @@
-use super::HtmlElement;
-use crate::{attribute::Attribute, render::Render};
-use std::rc::Rc;
+use super::{
+    builder::{push_content, WithAttributes, WithChildren},
+    HtmlElement,
+};
+use crate::{
+    attribute::Attribute,
+    render::{Render, Renderer},
+};
+use std::{fmt, rc::Rc};
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Li {
     pub attributes: Vec<Attribute>,
     pub content: Rc<HtmlElement>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Ul {
     pub attributes: Vec<Attribute>,
     pub items: Vec<Li>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Ol {
     pub attributes: Vec<Attribute>,
     pub items: Vec<Li>,
 }
 
 impl Render for Li {
-    fn render(&self) -> String {
-        let attr_str = self.attributes.render();
-        let content_str = self.content.render();
-        format!("<li {attr_str}>{content_str}</li>")
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<li")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        self.content.render_into(out)?;
+        out.write_str("</li>")
     }
 }
 
 impl Render for Ul {
-    fn render(&self) -> String {
-        let attr_str = self.attributes.render();
-        let li_strs: Vec<String> = self.items.iter().map(|item| item.render()).collect();
-        format!("<ul {attr_str}>\n\t{}\n</ul>", li_strs.join("\n\t"))
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<ul")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        out.block(|out| out.write_lines(&self.items))?;
+        out.write_str("</ul>")
     }
 }
 
 impl Render for Ol {
-    fn render(&self) -> String {
-        let attr_str = self.attributes.render();
-        let li_strs: Vec<String> = self.items.iter().map(|item| item.render()).collect();
-        format!("<ol {attr_str}>\n\t{}\n</ol>", li_strs.join("\n\t"))
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<ol")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        out.block(|out| out.write_lines(&self.items))?;
+        out.write_str("</ol>")
     }
 }
 
@@ -55,3 +67,132 @@ impl From<Ol> for HtmlElement {
         HtmlElement::Ol(ol)
     }
 }
+
+impl Li {
+    pub fn new() -> Li {
+        Li::default()
+    }
+}
+
+impl WithAttributes for Li {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+impl WithChildren for Li {
+    fn push_child(&mut self, child: HtmlElement) {
+        push_content(&mut self.content, child)
+    }
+}
+
+impl Ul {
+    pub fn new() -> Ul {
+        Ul::default()
+    }
+
+    pub fn item(mut self, item: Li) -> Ul {
+        self.items.push(item);
+        self
+    }
+
+    pub fn items<I: IntoIterator<Item = Li>>(mut self, items: I) -> Ul {
+        self.items.extend(items);
+        self
+    }
+}
+
+impl WithAttributes for Ul {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+/// Children are wrapped in list items
+impl WithChildren for Ul {
+    fn push_child(&mut self, child: HtmlElement) {
+        self.items.push(Li::new().child(child))
+    }
+}
+
+impl Ol {
+    pub fn new() -> Ol {
+        Ol::default()
+    }
+
+    pub fn item(mut self, item: Li) -> Ol {
+        self.items.push(item);
+        self
+    }
+
+    pub fn items<I: IntoIterator<Item = Li>>(mut self, items: I) -> Ol {
+        self.items.extend(items);
+        self
+    }
+}
+
+impl WithAttributes for Ol {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+/// Children are wrapped in list items
+impl WithChildren for Ol {
+    fn push_child(&mut self, child: HtmlElement) {
+        self.items.push(Li::new().child(child))
+    }
+}
+
+#[cfg(test)]
+mod lists_tests {
+    use super::{Attribute, HtmlElement, Li, Ol, Render, Ul, WithAttributes, WithChildren};
+    use crate::elements::Build;
+    use std::rc::Rc;
+
+    fn example_li(content: &str) -> Li {
+        Li {
+            attributes: vec![],
+            content: Rc::new(content.to_owned().into()),
+        }
+    }
+
+    #[test]
+    fn render_ul() {
+        let result = Ul {
+            attributes: vec![Attribute::Id("list".to_owned())],
+            items: vec![example_li("a"), example_li("b")],
+        }
+        .render();
+        let expected = "<ul id=\"list\">\n\t<li >a</li>\n\t<li >b</li>\n</ul>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn build_ul() {
+        let result = Ul::new()
+            .id("list")
+            .item(Li::new().text("a"))
+            .text("b")
+            .build();
+        let expected = Ul {
+            attributes: vec![Attribute::Id("list".to_owned())],
+            items: vec![example_li("a"), example_li("b")],
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn build_ol() {
+        let result = Ol::new()
+            .items(["a", "b"].map(|item| Li::new().text(item)))
+            .build();
+        let expected: HtmlElement = Ol {
+            attributes: vec![],
+            items: vec![example_li("a"), example_li("b")],
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
+}
@@ -1,14 +1,43 @@
 use super::HtmlElement;
-use crate::render::Render;
+use crate::{
+    escape::escape_text,
+    render::{Render, Renderer},
+};
+use std::fmt;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Literal {
     content: String,
+    preformatted: bool,
+}
+
+impl Literal {
+    /// Text that is emitted with its whitespace untouched, e.g. the content of a `<pre>`
+    pub fn preformatted(content: String) -> Literal {
+        Literal {
+            content,
+            preformatted: true,
+        }
+    }
+
+    pub fn is_preformatted(&self) -> bool {
+        self.preformatted
+    }
+
+    /// The unescaped text
+    pub fn content(&self) -> &str {
+        &self.content
+    }
 }
 
 impl Render for Literal {
-    fn render(&self) -> String {
-        self.content.clone()
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        let escaped = escape_text(&self.content);
+        if self.preformatted {
+            out.write_raw(&escaped)
+        } else {
+            out.write_str(&escaped)
+        }
     }
 }
 impl From<Literal> for HtmlElement {
@@ -18,7 +47,10 @@ impl From<Literal> for HtmlElement {
 }
 impl From<String> for Literal {
     fn from(s: String) -> Literal {
-        Literal { content: s }
+        Literal {
+            content: s,
+            preformatted: false,
+        }
     }
 }
 
@@ -26,10 +58,13 @@ impl From<String> for Literal {
 mod literal_tests {
 
     use super::{HtmlElement, Literal, Render};
+    use crate::elements::Div;
+    use std::rc::Rc;
 
     fn example_lit() -> Literal {
         Literal {
             content: "a literal".to_owned(),
+            preformatted: false,
         }
     }
 
@@ -53,4 +88,36 @@ mod literal_tests {
         let expected = example_lit().render();
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn render_escaped() {
+        let result = <String as Into<Literal>>::into("<b>bold</b> & co".to_owned()).render();
+        let expected = "&lt;b&gt;bold&lt;/b&gt; &amp; co";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_preformatted_verbatim() {
+        let code = "fn main() {\n    println!(\"<hi>\");\n}";
+        let lit = Literal::preformatted(code.to_owned());
+        let result = HtmlElement::Div(Div {
+            attributes: vec![],
+            content: Rc::new(lit.into()),
+        })
+        .render();
+        let expected = "<div >\n\tfn main() {\n    println!(\"&lt;hi&gt;\");\n}\n</div>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_plain_reindented() {
+        let lit: Literal = "line one\nline two".to_owned().into();
+        let result = HtmlElement::Div(Div {
+            attributes: vec![],
+            content: Rc::new(lit.into()),
+        })
+        .render();
+        let expected = "<div >\n\tline one\n\tline two\n</div>";
+        assert_eq!(result, expected)
+    }
 }
@@ -0,0 +1,716 @@
+use super::{
+    builder::{push_content, WithAttributes, WithChildren},
+    HtmlElement, Img,
+};
+use crate::{
+    attribute::{Attribute, Loading, Preload, TrackKind},
+    render::{Render, Renderer},
+};
+use std::{fmt, rc::Rc};
+
+/// Playback attributes shared by `<video>` and `<audio>`
+pub trait MediaElement: WithAttributes {
+    fn controls(self) -> Self {
+        self.attr(Attribute::Controls)
+    }
+
+    fn autoplay(self) -> Self {
+        self.attr(Attribute::Autoplay)
+    }
+
+    fn muted(self) -> Self {
+        self.attr(Attribute::Muted)
+    }
+
+    /// Starts over at the end
+    fn looped(self) -> Self {
+        self.attr(Attribute::Loop)
+    }
+
+    fn preload(self, preload: Preload) -> Self {
+        self.attr(Attribute::Preload(preload))
+    }
+}
+
+/// Sources and tracks come first, the content is shown by browsers that can not play any source
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Video {
+    pub attributes: Vec<Attribute>,
+    pub sources: Vec<Source>,
+    pub tracks: Vec<Track>,
+    pub content: Rc<HtmlElement>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Audio {
+    pub attributes: Vec<Attribute>,
+    pub sources: Vec<Source>,
+    pub tracks: Vec<Track>,
+    pub content: Rc<HtmlElement>,
+}
+
+/// An alternative resource of a `<video>`, `<audio>` or `<picture>`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Source {
+    pub attributes: Vec<Attribute>,
+}
+
+/// Timed text such as subtitles for a `<video>` or `<audio>`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Track {
+    pub attributes: Vec<Attribute>,
+}
+
+/// Sources for an image, the content is usually the `<img>` used when no source matches
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Picture {
+    pub attributes: Vec<Attribute>,
+    pub sources: Vec<Source>,
+    pub content: Rc<HtmlElement>,
+}
+
+fn render_media<W: fmt::Write>(
+    out: &mut Renderer<W>,
+    tag: &str,
+    attributes: &[Attribute],
+    sources: &[Source],
+    tracks: &[Track],
+    content: &HtmlElement,
+) -> fmt::Result {
+    out.write_str("<")?;
+    out.write_str(tag)?;
+    out.write_attributes(attributes)?;
+    out.write_str(">")?;
+    out.block(|out| {
+        out.write_lines(sources)?;
+        if !sources.is_empty() && !tracks.is_empty() {
+            out.newline()?;
+        }
+        out.write_lines(tracks)?;
+        let empty = matches!(content, HtmlElement::ComponentList(elems) if elems.is_empty());
+        if (!sources.is_empty() || !tracks.is_empty()) && !empty {
+            out.newline()?;
+        }
+        content.render_into(out)
+    })?;
+    out.write_str("</")?;
+    out.write_str(tag)?;
+    out.write_str(">")
+}
+
+impl Render for Video {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        render_media(
+            out,
+            "video",
+            &self.attributes,
+            &self.sources,
+            &self.tracks,
+            &self.content,
+        )
+    }
+}
+
+impl Render for Audio {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        render_media(
+            out,
+            "audio",
+            &self.attributes,
+            &self.sources,
+            &self.tracks,
+            &self.content,
+        )
+    }
+}
+
+impl Render for Picture {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        render_media(
+            out,
+            "picture",
+            &self.attributes,
+            &self.sources,
+            &[],
+            &self.content,
+        )
+    }
+}
+
+impl Render for Source {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<source")?;
+        out.write_attributes(&self.attributes)?;
+        out.space()?;
+        out.write_str("/>")
+    }
+}
+
+impl Render for Track {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<track")?;
+        out.write_attributes(&self.attributes)?;
+        out.space()?;
+        out.write_str("/>")
+    }
+}
+
+impl From<Video> for HtmlElement {
+    fn from(video: Video) -> HtmlElement {
+        HtmlElement::Video(video)
+    }
+}
+
+impl From<Audio> for HtmlElement {
+    fn from(audio: Audio) -> HtmlElement {
+        HtmlElement::Audio(audio)
+    }
+}
+
+impl From<Source> for HtmlElement {
+    fn from(source: Source) -> HtmlElement {
+        HtmlElement::Source(source)
+    }
+}
+
+impl From<Track> for HtmlElement {
+    fn from(track: Track) -> HtmlElement {
+        HtmlElement::Track(track)
+    }
+}
+
+impl From<Picture> for HtmlElement {
+    fn from(picture: Picture) -> HtmlElement {
+        HtmlElement::Picture(picture)
+    }
+}
+
+impl Video {
+    pub fn new() -> Video {
+        Video::default()
+    }
+
+    pub fn src(self, src: &str) -> Video {
+        self.attr(Attribute::Src(src.to_owned()))
+    }
+
+    /// Image shown until playback starts
+    pub fn poster(self, poster: &str) -> Video {
+        self.attr(Attribute::Poster(poster.to_owned()))
+    }
+
+    pub fn width(self, width: u32) -> Video {
+        self.attr(Attribute::Width(width))
+    }
+
+    pub fn height(self, height: u32) -> Video {
+        self.attr(Attribute::Height(height))
+    }
+
+    pub fn source(mut self, source: Source) -> Video {
+        self.sources.push(source);
+        self
+    }
+
+    pub fn track(mut self, track: Track) -> Video {
+        self.tracks.push(track);
+        self
+    }
+}
+
+impl WithAttributes for Video {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+/// Sources and tracks are added as such, anything else becomes fallback content
+impl WithChildren for Video {
+    fn push_child(&mut self, child: HtmlElement) {
+        match child {
+            HtmlElement::Source(source) => self.sources.push(source),
+            HtmlElement::Track(track) => self.tracks.push(track),
+            child => push_content(&mut self.content, child),
+        }
+    }
+}
+
+impl MediaElement for Video {}
+
+impl Audio {
+    pub fn new() -> Audio {
+        Audio::default()
+    }
+
+    pub fn src(self, src: &str) -> Audio {
+        self.attr(Attribute::Src(src.to_owned()))
+    }
+
+    pub fn source(mut self, source: Source) -> Audio {
+        self.sources.push(source);
+        self
+    }
+
+    pub fn track(mut self, track: Track) -> Audio {
+        self.tracks.push(track);
+        self
+    }
+}
+
+impl WithAttributes for Audio {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+/// Sources and tracks are added as such, anything else becomes fallback content
+impl WithChildren for Audio {
+    fn push_child(&mut self, child: HtmlElement) {
+        match child {
+            HtmlElement::Source(source) => self.sources.push(source),
+            HtmlElement::Track(track) => self.tracks.push(track),
+            child => push_content(&mut self.content, child),
+        }
+    }
+}
+
+impl MediaElement for Audio {}
+
+impl Source {
+    pub fn new() -> Source {
+        Source::default()
+    }
+
+    /// The resource of a `<video>` or `<audio>` source
+    pub fn src(self, src: &str) -> Source {
+        self.attr(Attribute::Src(src.to_owned()))
+    }
+
+    /// The mime type, letting browsers skip sources they can not play
+    pub fn source_type(self, mime_type: &str) -> Source {
+        self.attr(Attribute::Type(mime_type.to_owned()))
+    }
+
+    /// Media query the source is used for
+    pub fn media(self, query: &str) -> Source {
+        self.attr(Attribute::Media(query.to_owned()))
+    }
+
+    /// Candidate images of a `<picture>` source
+    pub fn srcset(self, srcset: &str) -> Source {
+        self.attr(Attribute::Srcset(srcset.to_owned()))
+    }
+
+    pub fn sizes(self, sizes: &str) -> Source {
+        self.attr(Attribute::Sizes(sizes.to_owned()))
+    }
+}
+
+impl WithAttributes for Source {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+impl Track {
+    pub fn new() -> Track {
+        Track::default()
+    }
+
+    pub fn src(self, src: &str) -> Track {
+        self.attr(Attribute::Src(src.to_owned()))
+    }
+
+    pub fn kind(self, kind: TrackKind) -> Track {
+        self.attr(Attribute::Kind(kind))
+    }
+
+    /// Language of the track, such as `en`
+    pub fn srclang(self, lang: &str) -> Track {
+        self.attr(Attribute::Srclang(lang.to_owned()))
+    }
+
+    /// Title shown when choosing between tracks
+    pub fn label(self, label: &str) -> Track {
+        self.attr(Attribute::Label(label.to_owned()))
+    }
+
+    /// Enables the track unless the user prefers another one
+    pub fn default_track(self) -> Track {
+        self.attr(Attribute::Default)
+    }
+}
+
+impl WithAttributes for Track {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+impl Picture {
+    pub fn new() -> Picture {
+        Picture::default()
+    }
+
+    pub fn source(mut self, source: Source) -> Picture {
+        self.sources.push(source);
+        self
+    }
+}
+
+impl WithAttributes for Picture {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+/// Sources are added as such, anything else becomes the fallback content
+impl WithChildren for Picture {
+    fn push_child(&mut self, child: HtmlElement) {
+        match child {
+            HtmlElement::Source(source) => self.sources.push(source),
+            child => push_content(&mut self.content, child),
+        }
+    }
+}
+
+impl Img {
+    /// Text replacing the image when it can not be shown
+    pub fn alt(self, alt: &str) -> Img {
+        self.attr(Attribute::Alt(alt.to_owned()))
+    }
+
+    pub fn width(self, width: u32) -> Img {
+        self.attr(Attribute::Width(width))
+    }
+
+    pub fn height(self, height: u32) -> Img {
+        self.attr(Attribute::Height(height))
+    }
+
+    /// Candidate images, such as `a-480.jpg 480w, a-800.jpg 800w`
+    pub fn srcset(self, srcset: &str) -> Img {
+        self.attr(Attribute::Srcset(srcset.to_owned()))
+    }
+
+    /// Width the image is shown at, depending on media conditions
+    pub fn sizes(self, sizes: &str) -> Img {
+        self.attr(Attribute::Sizes(sizes.to_owned()))
+    }
+
+    pub fn loading(self, loading: Loading) -> Img {
+        self.attr(Attribute::Loading(loading))
+    }
+}
+
+/// One file of a [`ResponsiveImage`], `width` is its intrinsic width in pixels
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImageVariant {
+    pub src: String,
+    pub width: u32,
+    pub mime_type: Option<String>,
+}
+
+/// An image available in several widths and possibly formats.
+///
+/// Variants without a mime type are the fallback used by the `<img>`.
+/// If all variants have a type, those of the type added last are the fallback.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ResponsiveImage {
+    alt: String,
+    variants: Vec<ImageVariant>,
+    sizes: Vec<(Option<String>, String)>,
+    loading: Option<Loading>,
+}
+
+impl ResponsiveImage {
+    pub fn new(alt: &str) -> ResponsiveImage {
+        ResponsiveImage {
+            alt: alt.to_owned(),
+            ..ResponsiveImage::default()
+        }
+    }
+
+    pub fn variant(mut self, src: &str, width: u32) -> ResponsiveImage {
+        self.variants.push(ImageVariant {
+            src: src.to_owned(),
+            width,
+            mime_type: None,
+        });
+        self
+    }
+
+    /// A variant in a format not every browser supports, such as `image/avif`
+    pub fn typed_variant(mut self, src: &str, width: u32, mime_type: &str) -> ResponsiveImage {
+        self.variants.push(ImageVariant {
+            src: src.to_owned(),
+            width,
+            mime_type: Some(mime_type.to_owned()),
+        });
+        self
+    }
+
+    /// The width the image is shown at when `media` matches, conditions are tried in order
+    pub fn size(mut self, media: &str, width: &str) -> ResponsiveImage {
+        self.sizes.push((Some(media.to_owned()), width.to_owned()));
+        self
+    }
+
+    /// The width the image is shown at when no condition matches
+    pub fn default_size(mut self, width: &str) -> ResponsiveImage {
+        self.sizes.push((None, width.to_owned()));
+        self
+    }
+
+    pub fn loading(mut self, loading: Loading) -> ResponsiveImage {
+        self.loading = Some(loading);
+        self
+    }
+
+    /// The value of the `sizes` attribute, the default size last
+    pub fn sizes(&self) -> String {
+        let conditions = self
+            .sizes
+            .iter()
+            .filter_map(|(media, width)| media.as_ref().map(|media| format!("{media} {width}")));
+        let default = self.sizes.iter().find(|(media, _)| media.is_none());
+        conditions
+            .chain(default.map(|(_, width)| width.clone()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// The fallback variants and the other types with their variants, in the order added
+    fn groups(&self) -> (Vec<&ImageVariant>, Vec<(&str, Vec<&ImageVariant>)>) {
+        let mut types: Vec<(&str, Vec<&ImageVariant>)> = vec![];
+        let mut fallback = vec![];
+        for variant in &self.variants {
+            match &variant.mime_type {
+                None => fallback.push(variant),
+                Some(mime_type) => match types.iter_mut().find(|(ty, _)| ty == mime_type) {
+                    Some((_, variants)) => variants.push(variant),
+                    None => types.push((mime_type, vec![variant])),
+                },
+            }
+        }
+        if fallback.is_empty() {
+            fallback = types
+                .pop()
+                .map(|(_, variants)| variants)
+                .unwrap_or_default();
+        }
+        (fallback, types)
+    }
+
+    /// An `<img>` choosing between the fallback variants, with the widest one as `src`
+    pub fn img(&self) -> Img {
+        let (fallback, _) = self.groups();
+        let mut img = Img::new();
+        if let Some(widest) = fallback.iter().max_by_key(|variant| variant.width) {
+            img = img.src(&widest.src);
+        }
+        img = img.alt(&self.alt);
+        // sizes only applies to the widths of a srcset
+        if fallback.len() > 1 {
+            img = img.srcset(&srcset(&fallback));
+            if !self.sizes.is_empty() {
+                img = img.sizes(&self.sizes());
+            }
+        }
+        match self.loading {
+            Some(loading) => img.loading(loading),
+            None => img,
+        }
+    }
+
+    /// A `<picture>` with a source for every other type and [`ResponsiveImage::img`] as fallback
+    pub fn picture(&self) -> Picture {
+        let (_, types) = self.groups();
+        types
+            .iter()
+            .fold(Picture::new(), |picture, (mime_type, variants)| {
+                let source = Source::new()
+                    .source_type(mime_type)
+                    .srcset(&srcset(variants));
+                picture.source(if self.sizes.is_empty() {
+                    source
+                } else {
+                    source.sizes(&self.sizes())
+                })
+            })
+            .child(self.img())
+    }
+}
+
+/// Candidates ordered by width
+fn srcset(variants: &[&ImageVariant]) -> String {
+    let mut variants = variants.to_vec();
+    variants.sort_by_key(|variant| variant.width);
+    variants
+        .iter()
+        .map(|variant| format!("{} {}w", variant.src, variant.width))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod media_tests {
+    use super::{Audio, MediaElement, Picture, ResponsiveImage, Source, Track, Video};
+    use crate::{
+        attribute::{Attribute, Loading, Preload, TrackKind},
+        elements::{Build, Img, WithChildren},
+        render::{Render, RenderOptions},
+    };
+
+    #[test]
+    fn render_video() {
+        let result = Video::new()
+            .controls()
+            .poster("poster.jpg")
+            .width(640)
+            .child(Source::new().src("a.webm").source_type("video/webm"))
+            .child(
+                Track::new()
+                    .kind(TrackKind::Subtitles)
+                    .src("en.vtt")
+                    .srclang("en")
+                    .label("English")
+                    .default_track(),
+            )
+            .text("no video")
+            .render();
+        let expected = "<video controls poster=\"poster.jpg\" width=\"640\">\n\t<source src=\"a.webm\" type=\"video/webm\" />\n\t<track kind=\"subtitles\" src=\"en.vtt\" srclang=\"en\" label=\"English\" default />\n\tno video\n</video>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_audio() {
+        let result = Audio::new()
+            .src("a.mp3")
+            .autoplay()
+            .muted()
+            .looped()
+            .preload(Preload::None)
+            .render();
+        let expected = "<audio src=\"a.mp3\" autoplay muted loop preload=\"none\">\n\t\n</audio>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_audio_minified() {
+        let result = Audio::new()
+            .source(Source::new().src("a.ogg"))
+            .render_with(&RenderOptions::minified());
+        let expected = "<audio><source src=\"a.ogg\"/></audio>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_picture() {
+        let result = Picture::new()
+            .child(Source::new().media("(min-width: 800px)").srcset("wide.jpg"))
+            .child(Img::new().src("narrow.jpg").alt("A cat"))
+            .render();
+        let expected = "<picture >\n\t<source media=\"(min-width: 800px)\" srcset=\"wide.jpg\" />\n\t<img src=\"narrow.jpg\" alt=\"A cat\" />\n</picture>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn build_img_attributes() {
+        let result = Img::new()
+            .width(10)
+            .height(20)
+            .loading(Loading::Lazy)
+            .build();
+        let expected = Img {
+            attributes: vec![
+                Attribute::Width(10),
+                Attribute::Height(20),
+                Attribute::Loading(Loading::Lazy),
+            ],
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
+
+    fn cat() -> ResponsiveImage {
+        ResponsiveImage::new("A cat")
+            .variant("cat-800.jpg", 800)
+            .variant("cat-400.jpg", 400)
+            .size("(max-width: 600px)", "100vw")
+            .default_size("50vw")
+    }
+
+    #[test]
+    fn responsive_img() {
+        let result = cat().loading(Loading::Lazy).img();
+        let expected = Img::new()
+            .src("cat-800.jpg")
+            .alt("A cat")
+            .srcset("cat-400.jpg 400w, cat-800.jpg 800w")
+            .sizes("(max-width: 600px) 100vw, 50vw")
+            .loading(Loading::Lazy);
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn responsive_img_single_variant() {
+        let result = ResponsiveImage::new("A cat")
+            .variant("cat-800.jpg", 800)
+            .size("(max-width: 600px)", "100vw")
+            .img();
+        let expected = Img::new().src("cat-800.jpg").alt("A cat");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn responsive_sizes_default_last() {
+        let result = ResponsiveImage::new("")
+            .default_size("50vw")
+            .size("(max-width: 600px)", "100vw")
+            .sizes();
+        let expected = "(max-width: 600px) 100vw, 50vw";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn responsive_picture() {
+        let result = cat()
+            .typed_variant("cat-400.avif", 400, "image/avif")
+            .typed_variant("cat-400.webp", 400, "image/webp")
+            .typed_variant("cat-800.avif", 800, "image/avif")
+            .picture();
+        let sizes = "(max-width: 600px) 100vw, 50vw";
+        let expected = Picture::new()
+            .source(
+                Source::new()
+                    .source_type("image/avif")
+                    .srcset("cat-400.avif 400w, cat-800.avif 800w")
+                    .sizes(sizes),
+            )
+            .source(
+                Source::new()
+                    .source_type("image/webp")
+                    .srcset("cat-400.webp 400w")
+                    .sizes(sizes),
+            )
+            .child(cat().img());
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn responsive_picture_typed_fallback() {
+        let result = ResponsiveImage::new("logo")
+            .typed_variant("logo.webp", 200, "image/webp")
+            .typed_variant("logo.png", 200, "image/png")
+            .picture();
+        let expected = Picture::new()
+            .source(
+                Source::new()
+                    .source_type("image/webp")
+                    .srcset("logo.webp 200w"),
+            )
+            .child(Img::new().src("logo.png").alt("logo"));
+        assert_eq!(result, expected)
+    }
+}
@@ -0,0 +1,99 @@
+use super::{builder::WithAttributes, HtmlElement};
+use crate::{
+    attribute::Attribute,
+    render::{Render, Renderer},
+};
+use std::fmt;
+
+/// Document metadata, either a charset or a name with its content
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Meta {
+    pub attributes: Vec<Attribute>,
+}
+
+impl Render for Meta {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<meta")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str("/>")
+    }
+}
+
+impl From<Meta> for HtmlElement {
+    fn from(meta: Meta) -> HtmlElement {
+        HtmlElement::Meta(meta)
+    }
+}
+
+impl Meta {
+    pub fn new() -> Meta {
+        Meta::default()
+    }
+
+    /// `<meta charset=..>`
+    pub fn charset(charset: &str) -> Meta {
+        Meta::new().attr(Attribute::Charset(charset.to_owned()))
+    }
+
+    /// `<meta name=.. content=..>`
+    pub fn named(name: &str, content: &str) -> Meta {
+        Meta::new()
+            .attr(Attribute::Name(name.to_owned()))
+            .attr(Attribute::Content(content.to_owned()))
+    }
+
+    /// The name and content of a named meta tag without any other attributes
+    pub fn name_content(&self) -> Option<(&str, &str)> {
+        match self.attributes.as_slice() {
+            [Attribute::Name(name), Attribute::Content(content)]
+            | [Attribute::Content(content), Attribute::Name(name)] => Some((name, content)),
+            _ => None,
+        }
+    }
+}
+
+impl WithAttributes for Meta {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+#[cfg(test)]
+mod meta_tests {
+    use super::{Attribute, Meta, Render};
+    use crate::{elements::WithAttributes, render::RenderOptions};
+
+    #[test]
+    fn render_charset() {
+        let result = Meta::charset("utf-8").render();
+        let expected = "<meta charset=\"utf-8\"/>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_named() {
+        let result =
+            Meta::named("description", "A \"quoted\" page").render_with(&RenderOptions::minified());
+        let expected = "<meta name=\"description\" content=\"A &quot;quoted&quot; page\"/>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn name_content() {
+        let metas = [
+            Meta::named("robots", "noindex"),
+            Meta::charset("utf-8"),
+            Meta::named("a", "b").id("c"),
+        ];
+        let result = metas.each_ref().map(Meta::name_content);
+        let expected = [Some(("robots", "noindex")), None, None];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn build_meta() {
+        let result = Meta::new().attr(Attribute::Charset("utf-8".to_owned()));
+        let expected = Meta::charset("utf-8");
+        assert_eq!(result, expected)
+    }
+}
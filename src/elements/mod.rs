@@ -1,8 +1,82 @@
+/// Declares elements holding attributes and a single content, together with their builders.
+/// `inline` elements write the content right after the opening tag, `block` elements on lines of
+/// their own, `custom` ones implement [`Render`] themselves.
+macro_rules! content_elements {
+    ($($(#[$meta:meta])* $layout:ident $name:ident($tag:literal);)*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Clone, Debug, Default, PartialEq)]
+            pub struct $name {
+                pub attributes: Vec<$crate::attribute::Attribute>,
+                pub content: std::rc::Rc<$crate::elements::HtmlElement>,
+            }
+
+            content_elements!(@render $layout $name $tag);
+
+            impl From<$name> for $crate::elements::HtmlElement {
+                fn from(elem: $name) -> $crate::elements::HtmlElement {
+                    $crate::elements::HtmlElement::$name(elem)
+                }
+            }
+
+            impl $name {
+                pub fn new() -> $name {
+                    $name::default()
+                }
+            }
+
+            impl $crate::elements::WithAttributes for $name {
+                fn attributes_mut(&mut self) -> &mut Vec<$crate::attribute::Attribute> {
+                    &mut self.attributes
+                }
+            }
+
+            impl $crate::elements::WithChildren for $name {
+                fn push_child(&mut self, child: $crate::elements::HtmlElement) {
+                    $crate::elements::builder::push_content(&mut self.content, child)
+                }
+            }
+        )*
+    };
+    (@render inline $name:ident $tag:literal) => {
+        impl $crate::render::Render for $name {
+            fn render_into<W: std::fmt::Write>(
+                &self,
+                out: &mut $crate::render::Renderer<W>,
+            ) -> std::fmt::Result {
+                out.write_str(concat!("<", $tag))?;
+                out.write_attributes(&self.attributes)?;
+                out.write_str(">")?;
+                self.content.render_into(out)?;
+                out.write_str(concat!("</", $tag, ">"))
+            }
+        }
+    };
+    (@render block $name:ident $tag:literal) => {
+        impl $crate::render::Render for $name {
+            fn render_into<W: std::fmt::Write>(
+                &self,
+                out: &mut $crate::render::Renderer<W>,
+            ) -> std::fmt::Result {
+                out.write_str(concat!("<", $tag))?;
+                out.write_attributes(&self.attributes)?;
+                out.write_str(">")?;
+                out.block(|out| self.content.render_into(out))?;
+                out.write_str(concat!("</", $tag, ">"))
+            }
+        }
+    };
+    (@render custom $name:ident $tag:literal) => {};
+}
+
 mod a;
 mod body;
+mod builder;
 mod canvas;
 mod div;
+mod element;
 mod figure;
+mod forms;
 mod head;
 mod headline;
 mod img;
@@ -10,31 +84,53 @@ mod input;
 mod link;
 mod lists;
 mod literal;
+mod media;
+mod meta;
+mod phrasing;
 mod script;
+mod sections;
 mod select;
 mod style;
 mod table;
+mod text;
 
 pub use a::A;
 pub use body::Body;
+pub use builder::{Build, WithAttributes, WithChildren};
 pub use canvas::Canvas;
 pub use div::Div;
+pub use element::{is_valid_tag, Element, ElementKind};
 pub use figure::Figure;
-pub use head::Head;
+pub use forms::{Button, Datalist, Fieldset, Form, FormControl, Label, Legend, Output, Textarea};
+pub use head::{Head, Icon, Metadata};
 pub use headline::{HeaderSize, Headline};
 pub use img::Img;
-pub use input::Input;
+pub use input::{Bounded, Input, InputError, InputKind, InputValue};
 pub use link::Link;
 pub use lists::{Li, Ol, Ul};
 pub use literal::Literal;
+pub use media::{
+    Audio, ImageVariant, MediaElement, Picture, ResponsiveImage, Source, Track, Video,
+};
+pub use meta::Meta;
+pub use phrasing::{Abbr, Code, Em, Mark, Small, Span, Strong, Sub, Sup};
 pub use script::Script;
+pub use sections::{Article, Aside, Footer, Header, Main, Nav, Section};
 pub use select::{Select, SelectOption};
 pub use style::Style;
-pub use table::{Table, Td, Tr};
+pub use table::{
+    Caption, Cell, Col, Colgroup, Csv, CsvError, Table, TableCell, TableError, TableSection,
+    TableSectionKind, Td, Th, Tr,
+};
+pub use text::{Blockquote, Hr, Pre, P};
 
-use crate::render::Render;
+use crate::{
+    render::{is_inline_tag, Render, Renderer},
+    visit::Node,
+};
+use std::fmt;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum HtmlElement {
     Head(Head),
     Body(Body),
@@ -42,9 +138,16 @@ pub enum HtmlElement {
     Link(Link),
     A(A),
     Literal(Literal),
+    /// Trusted markup that is rendered verbatim, without any escaping or re-indentation
+    Raw(String),
     Table(Table),
     Tr(Tr),
     Td(Td),
+    Th(Th),
+    TableSection(TableSection),
+    Caption(Caption),
+    Colgroup(Colgroup),
+    Col(Col),
     Headline(Headline),
     Br,
     Figure(Figure),
@@ -57,34 +160,129 @@ pub enum HtmlElement {
     Style(Style),
     Ul(Ul),
     Ol(Ol),
+    Section(Section),
+    Article(Article),
+    Nav(Nav),
+    Header(Header),
+    Footer(Footer),
+    Main(Main),
+    Aside(Aside),
+    P(P),
+    Blockquote(Blockquote),
+    Pre(Pre),
+    Span(Span),
+    Code(Code),
+    Strong(Strong),
+    Em(Em),
+    Small(Small),
+    Mark(Mark),
+    Sub(Sub),
+    Sup(Sup),
+    Abbr(Abbr),
+    Hr(Hr),
+    Form(Form),
+    Label(Label),
+    Button(Button),
+    Textarea(Textarea),
+    Fieldset(Fieldset),
+    Legend(Legend),
+    Datalist(Datalist),
+    Output(Output),
+    Video(Video),
+    Audio(Audio),
+    Source(Source),
+    Track(Track),
+    Picture(Picture),
+    Meta(Meta),
+    /// Any other element, see [`Element`]
+    Element(Element),
 }
 
 impl Render for HtmlElement {
-    fn render(&self) -> String {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
         match self {
-            HtmlElement::Head(hd) => hd.render(),
-            HtmlElement::Body(bd) => bd.render(),
-            HtmlElement::Div(dv) => dv.render(),
-            HtmlElement::A(a) => a.render(),
-            HtmlElement::Link(lnk) => lnk.render(),
-            HtmlElement::Literal(lit) => lit.render(),
-            HtmlElement::Table(tb) => tb.render(),
-            HtmlElement::Tr(tr) => tr.render(),
-            HtmlElement::Td(td) => td.render(),
-            HtmlElement::Headline(hd) => hd.render(),
-            HtmlElement::Br => "<br/>".to_owned(),
-            HtmlElement::Figure(fig) => fig.render(),
-            HtmlElement::Img(img) => img.render(),
-            HtmlElement::Canvas(canvas) => canvas.render(),
-            HtmlElement::Input(input) => input.render(),
-            HtmlElement::Select(select) => select.render(),
-            HtmlElement::Script(script) => script.render(),
-            HtmlElement::Style(style) => style.render(),
-            HtmlElement::ComponentList(ls) => ls.render(),
-            HtmlElement::Ul(ul) => ul.render(),
-            HtmlElement::Ol(ol) => ol.render(),
+            HtmlElement::Head(hd) => hd.render_into(out),
+            HtmlElement::Body(bd) => bd.render_into(out),
+            HtmlElement::Div(dv) => dv.render_into(out),
+            HtmlElement::A(a) => a.render_into(out),
+            HtmlElement::Link(lnk) => lnk.render_into(out),
+            HtmlElement::Literal(lit) => lit.render_into(out),
+            HtmlElement::Raw(raw) => out.write_raw(raw),
+            HtmlElement::Table(tb) => tb.render_into(out),
+            HtmlElement::Tr(tr) => tr.render_into(out),
+            HtmlElement::Td(td) => td.render_into(out),
+            HtmlElement::Th(th) => th.render_into(out),
+            HtmlElement::TableSection(section) => section.render_into(out),
+            HtmlElement::Caption(caption) => caption.render_into(out),
+            HtmlElement::Colgroup(colgroup) => colgroup.render_into(out),
+            HtmlElement::Col(col) => col.render_into(out),
+            HtmlElement::Headline(hd) => hd.render_into(out),
+            HtmlElement::Br => {
+                out.write_str("<br")?;
+                out.write_attributes(&[])?;
+                out.write_str("/>")
+            }
+            HtmlElement::Figure(fig) => fig.render_into(out),
+            HtmlElement::Img(img) => img.render_into(out),
+            HtmlElement::Canvas(canvas) => canvas.render_into(out),
+            HtmlElement::Input(input) => input.render_into(out),
+            HtmlElement::Select(select) => select.render_into(out),
+            HtmlElement::Script(script) => script.render_into(out),
+            HtmlElement::Style(style) => style.render_into(out),
+            HtmlElement::ComponentList(ls) => ls.render_into(out),
+            HtmlElement::Ul(ul) => ul.render_into(out),
+            HtmlElement::Ol(ol) => ol.render_into(out),
+            HtmlElement::Section(section) => section.render_into(out),
+            HtmlElement::Article(article) => article.render_into(out),
+            HtmlElement::Nav(nav) => nav.render_into(out),
+            HtmlElement::Header(header) => header.render_into(out),
+            HtmlElement::Footer(footer) => footer.render_into(out),
+            HtmlElement::Main(main) => main.render_into(out),
+            HtmlElement::Aside(aside) => aside.render_into(out),
+            HtmlElement::P(p) => p.render_into(out),
+            HtmlElement::Blockquote(blockquote) => blockquote.render_into(out),
+            HtmlElement::Pre(pre) => pre.render_into(out),
+            HtmlElement::Span(span) => span.render_into(out),
+            HtmlElement::Code(code) => code.render_into(out),
+            HtmlElement::Strong(strong) => strong.render_into(out),
+            HtmlElement::Em(em) => em.render_into(out),
+            HtmlElement::Small(small) => small.render_into(out),
+            HtmlElement::Mark(mark) => mark.render_into(out),
+            HtmlElement::Sub(sub) => sub.render_into(out),
+            HtmlElement::Sup(sup) => sup.render_into(out),
+            HtmlElement::Abbr(abbr) => abbr.render_into(out),
+            HtmlElement::Hr(hr) => hr.render_into(out),
+            HtmlElement::Form(form) => form.render_into(out),
+            HtmlElement::Label(label) => label.render_into(out),
+            HtmlElement::Button(button) => button.render_into(out),
+            HtmlElement::Textarea(textarea) => textarea.render_into(out),
+            HtmlElement::Fieldset(fieldset) => fieldset.render_into(out),
+            HtmlElement::Legend(legend) => legend.render_into(out),
+            HtmlElement::Datalist(datalist) => datalist.render_into(out),
+            HtmlElement::Output(output) => output.render_into(out),
+            HtmlElement::Video(video) => video.render_into(out),
+            HtmlElement::Audio(audio) => audio.render_into(out),
+            HtmlElement::Source(source) => source.render_into(out),
+            HtmlElement::Track(track) => track.render_into(out),
+            HtmlElement::Picture(picture) => picture.render_into(out),
+            HtmlElement::Meta(meta) => meta.render_into(out),
+            HtmlElement::Element(elem) => elem.render_into(out),
         }
     }
+
+    fn is_inline(&self) -> bool {
+        match self {
+            HtmlElement::Literal(_) => true,
+            elem => Node::from(elem).tag_name().is_some_and(is_inline_tag),
+        }
+    }
+}
+
+/// An empty list of components
+impl Default for HtmlElement {
+    fn default() -> HtmlElement {
+        HtmlElement::ComponentList(vec![])
+    }
 }
 
 impl From<String> for HtmlElement {
@@ -93,6 +291,12 @@ impl From<String> for HtmlElement {
     }
 }
 
+impl From<&str> for HtmlElement {
+    fn from(s: &str) -> HtmlElement {
+        s.to_owned().into()
+    }
+}
+
 impl From<Vec<HtmlElement>> for HtmlElement {
     fn from(ls: Vec<HtmlElement>) -> HtmlElement {
         HtmlElement::ComponentList(ls)
@@ -102,16 +306,69 @@ impl From<Vec<HtmlElement>> for HtmlElement {
 #[cfg(test)]
 mod elements_tests {
     use super::{
-        Body, Canvas, Div, Figure, Head, HeaderSize, Headline, HtmlElement, Img, Input, Link,
-        Literal, Render, Script, Select, Style, Table, Td, Tr, A,
+        Abbr, Article, Aside, Blockquote, Body, Build, Button, Canvas, Code, Div, Element, Em,
+        Figure, Footer, Form, Head, Header, HeaderSize, Headline, HtmlElement, Img, Input, Label,
+        Legend, Link, Literal, Main, Mark, Metadata, Nav, Output, Render, Script, Section, Select,
+        Small, Span, Strong, Style, Sub, Sup, Table, Td, Tr, WithAttributes, WithChildren, A, P,
     };
     use crate::css::CssDocument;
     use std::rc::Rc;
 
+    #[test]
+    fn render_content_elements() {
+        let result = [
+            Span::new().id("x").text("a").build(),
+            Code::new().id("x").text("a").build(),
+            Strong::new().id("x").text("a").build(),
+            Em::new().id("x").text("a").build(),
+            Small::new().id("x").text("a").build(),
+            Mark::new().id("x").text("a").build(),
+            Sub::new().id("x").text("a").build(),
+            Sup::new().id("x").text("a").build(),
+            Abbr::new().id("x").text("a").build(),
+            Label::new().id("x").text("a").build(),
+            Button::new().id("x").text("a").build(),
+            Legend::new().id("x").text("a").build(),
+            Output::new().id("x").text("a").build(),
+            Section::new().id("x").text("a").build(),
+            Article::new().id("x").text("a").build(),
+            Nav::new().id("x").text("a").build(),
+            Header::new().id("x").text("a").build(),
+            Footer::new().id("x").text("a").build(),
+            Main::new().id("x").text("a").build(),
+            Aside::new().id("x").text("a").build(),
+            P::new().id("x").text("a").build(),
+            Blockquote::new().id("x").text("a").build(),
+            Form::new().id("x").text("a").build(),
+        ]
+        .map(|elem| elem.render());
+        let inline = [
+            "span", "code", "strong", "em", "small", "mark", "sub", "sup", "abbr", "label",
+            "button", "legend", "output",
+        ]
+        .map(|tag| format!("<{tag} id=\"x\">a</{tag}>"));
+        let block = [
+            "section",
+            "article",
+            "nav",
+            "header",
+            "footer",
+            "main",
+            "aside",
+            "p",
+            "blockquote",
+            "form",
+        ]
+        .map(|tag| format!("<{tag} id=\"x\">\n\ta\n</{tag}>"));
+        let expected: Vec<String> = inline.into_iter().chain(block).collect();
+        assert_eq!(result.to_vec(), expected)
+    }
+
     #[test]
     fn render_head() {
         let result = HtmlElement::Head(Head {
             title: "a head".to_owned(),
+            metadata: Metadata::default(),
             content: Rc::new(vec![].into()),
         })
         .render();
@@ -167,11 +424,46 @@ mod elements_tests {
         assert_eq!(result, expected)
     }
 
+    #[test]
+    fn render_raw() {
+        let result = HtmlElement::Raw("<b>bold</b> &amp; raw".to_owned()).render();
+        let expected = "<b>bold</b> &amp; raw";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_raw_nested() {
+        let result = HtmlElement::Div(Div {
+            attributes: vec![],
+            content: Rc::new(
+                vec![
+                    HtmlElement::Raw("<em>trusted</em>".to_owned()),
+                    "<em>untrusted</em>".to_owned().into(),
+                ]
+                .into(),
+            ),
+        })
+        .render();
+        let expected = "<div >\n\t<em>trusted</em>\n\t&lt;em&gt;untrusted&lt;/em&gt;\n</div>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_raw_verbatim() {
+        let result = HtmlElement::Div(Div {
+            attributes: vec![],
+            content: Rc::new(HtmlElement::Raw("<pre>a\n  b</pre>".to_owned())),
+        })
+        .render();
+        let expected = "<div >\n\t<pre>a\n  b</pre>\n</div>";
+        assert_eq!(result, expected)
+    }
+
     #[test]
     fn render_table() {
         let result = HtmlElement::Table(Table {
             attributes: vec![],
-            rows: vec![],
+            ..Table::default()
         })
         .render();
         let expected = "<table >\n\t\n</table>";
@@ -192,6 +484,7 @@ mod elements_tests {
     #[test]
     fn render_td() {
         let result = HtmlElement::Td(Td {
+            attributes: vec![],
             content: Rc::new("td".to_owned().into()),
         })
         .render();
@@ -214,7 +507,7 @@ mod elements_tests {
     #[test]
     fn render_br() {
         let result = HtmlElement::Br.render();
-        let expected = "<br/>";
+        let expected = "<br />";
         assert_eq!(result, expected)
     }
 
@@ -247,7 +540,7 @@ mod elements_tests {
 
     #[test]
     fn text_input() {
-        let result = HtmlElement::Input(Input { attributes: vec![] }).render();
+        let result = HtmlElement::Input(Input::new()).render();
         let expected = "<input />";
         assert_eq!(result, expected)
     }
@@ -270,7 +563,7 @@ mod elements_tests {
             content: "".to_owned(),
         })
         .render();
-        let expected = "<script >\n\t\n</script>";
+        let expected = "<script ></script>";
         assert_eq!(result, expected)
     }
 
@@ -284,6 +577,13 @@ mod elements_tests {
         assert_eq!(result, expected)
     }
 
+    #[test]
+    fn render_element() {
+        let result = HtmlElement::Element(Element::new("hr")).render();
+        let expected = "<hr />";
+        assert_eq!(result, expected)
+    }
+
     #[test]
     fn render_component_list() {
         let result = HtmlElement::ComponentList(vec![]).render();
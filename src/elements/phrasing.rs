@@ -0,0 +1,41 @@
+use super::WithAttributes;
+use crate::attribute::Attribute;
+
+content_elements! {
+    inline Span("span");
+    inline Code("code");
+    inline Strong("strong");
+    inline Em("em");
+    /// Side comments such as fine print
+    inline Small("small");
+    /// Text highlighted for reference
+    inline Mark("mark");
+    /// Subscript
+    inline Sub("sub");
+    /// Superscript
+    inline Sup("sup");
+    inline Abbr("abbr");
+}
+
+impl Abbr {
+    /// Sets the expansion of the abbreviation, shown as a tooltip
+    pub fn title(self, title: &str) -> Abbr {
+        self.attr(Attribute::Title(title.to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod phrasing_tests {
+    use super::Abbr;
+    use crate::{elements::WithChildren, render::Render};
+
+    #[test]
+    fn build_abbr_title() {
+        let result = Abbr::new()
+            .title("HyperText Markup Language")
+            .text("HTML")
+            .render();
+        let expected = "<abbr title=\"HyperText Markup Language\">HTML</abbr>";
+        assert_eq!(result, expected)
+    }
+}
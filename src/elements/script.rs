@@ -1,17 +1,24 @@
-use super::HtmlElement;
-use crate::{attribute::Attribute, render::Render};
+use super::{builder::WithAttributes, HtmlElement};
+use crate::{
+    attribute::Attribute,
+    render::{Render, Renderer},
+};
+use std::fmt;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Script {
     pub attributes: Vec<Attribute>,
     pub content: String,
 }
 
 impl Render for Script {
-    fn render(&self) -> String {
-        let content_str = self.content.clone().replace('\n', "\n\t");
-        let attr_str = self.attributes.render().replace('\n', " ");
-        format!("<script {attr_str}>\n\t{content_str}\n</script>")
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<script")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        // the script is emitted byte for byte, without indentation or surrounding line breaks
+        out.write_raw(&self.content)?;
+        out.write_str("</script>")
     }
 }
 
@@ -21,9 +28,35 @@ impl From<Script> for HtmlElement {
     }
 }
 
+impl Script {
+    pub fn new() -> Script {
+        Script::default()
+    }
+
+    pub fn src(self, src: &str) -> Script {
+        self.attr(Attribute::Src(src.to_owned()))
+    }
+
+    pub fn code(self, code: &str) -> Script {
+        Script {
+            content: code.to_owned(),
+            ..self
+        }
+    }
+}
+
+impl WithAttributes for Script {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
 #[cfg(test)]
 mod script_tests {
-    use super::{Attribute, HtmlElement, Render, Script};
+    use super::{Attribute, HtmlElement, Render, Script, WithAttributes};
+    use crate::elements::Build;
+    use crate::elements::Div;
+    use std::rc::Rc;
 
     fn example_script() -> Script {
         Script {
@@ -35,7 +68,7 @@ mod script_tests {
     #[test]
     fn render_script() {
         let result = example_script().render();
-        let expected = "<script type=\"javascript\">\n\talert(\"hello\")\n</script>";
+        let expected = "<script type=\"javascript\">alert(\"hello\")</script>";
         assert_eq!(result, expected)
     }
 
@@ -45,4 +78,34 @@ mod script_tests {
         let expected = example_script().render();
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn render_multiline_verbatim() {
+        let content = "function greet() {\n    if (true) {\n\talert(\"hi\");\n    }\n}";
+        let script = Script {
+            attributes: vec![],
+            content: content.to_owned(),
+        };
+        let result = HtmlElement::Div(Div {
+            attributes: vec![],
+            content: Rc::new(HtmlElement::Div(Div {
+                attributes: vec![],
+                content: Rc::new(script.into()),
+            })),
+        })
+        .render();
+        let expected =
+            format!("<div >\n\t<div >\n\t\t<script >{content}</script>\n\t</div>\n</div>");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn build_script() {
+        let result = Script::new()
+            .attr(Attribute::Type("javascript".to_owned()))
+            .code("alert(\"hello\")")
+            .build();
+        let expected = example_script().into();
+        assert_eq!(result, expected)
+    }
 }
@@ -0,0 +1,11 @@
+content_elements! {
+    block Section("section");
+    block Article("article");
+    block Nav("nav");
+    block Header("header");
+    block Footer("footer");
+    /// The dominant content of the body, there should only be one
+    block Main("main");
+    /// Content only indirectly related to the surrounding content
+    block Aside("aside");
+}
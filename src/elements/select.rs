@@ -1,34 +1,41 @@
-use super::HtmlElement;
-use crate::{attribute::Attribute, render::Render};
-use std::rc::Rc;
+use super::{
+    builder::{push_content, WithAttributes, WithChildren},
+    HtmlElement,
+};
+use crate::{
+    attribute::Attribute,
+    escape::escape_attribute,
+    render::{Render, Renderer},
+};
+use std::{fmt, rc::Rc};
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct SelectOption {
     pub value: String,
     pub content: Rc<HtmlElement>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Select {
     pub attributes: Vec<Attribute>,
     pub options: Vec<SelectOption>,
 }
 
 impl Render for Select {
-    fn render(&self) -> String {
-        let attr_str = self.attributes.render().replace('\n', " ");
-        let option_strs: Vec<String> = self.options.iter().map(|opt| opt.render()).collect();
-        format!(
-            "<select {attr_str}>\n\t{}\n</select>",
-            option_strs.join("\n\t")
-        )
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<select")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        out.block(|out| out.write_lines(&self.options))?;
+        out.write_str("</select>")
     }
 }
 impl Render for SelectOption {
-    fn render(&self) -> String {
-        let content_str = self.content.render();
-        let value_str = self.value.clone();
-        format!("<option value=\"{value_str}\">{content_str}</option>")
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        let value_str = escape_attribute(&self.value);
+        out.write_str(&format!("<option value=\"{value_str}\">"))?;
+        self.content.render_into(out)?;
+        out.write_str("</option>")
     }
 }
 
@@ -38,10 +45,50 @@ impl From<Select> for HtmlElement {
     }
 }
 
+impl SelectOption {
+    pub fn new(value: &str) -> SelectOption {
+        SelectOption {
+            value: value.to_owned(),
+            content: Rc::default(),
+        }
+    }
+}
+
+impl WithChildren for SelectOption {
+    fn push_child(&mut self, child: HtmlElement) {
+        push_content(&mut self.content, child)
+    }
+}
+
+impl Select {
+    pub fn new() -> Select {
+        Select::default()
+    }
+
+    pub fn option(mut self, option: SelectOption) -> Select {
+        self.options.push(option);
+        self
+    }
+
+    pub fn options<I: IntoIterator<Item = SelectOption>>(mut self, options: I) -> Select {
+        self.options.extend(options);
+        self
+    }
+}
+
+impl WithAttributes for Select {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
 #[cfg(test)]
 mod select_tests {
 
-    use super::{Attribute, HtmlElement, Render, Select, SelectOption};
+    use super::{
+        Attribute, HtmlElement, Render, Select, SelectOption, WithAttributes, WithChildren,
+    };
+    use crate::elements::Build;
     use std::rc::Rc;
 
     fn example_option() -> SelectOption {
@@ -84,4 +131,26 @@ mod select_tests {
         let expected = "<option value=\"option1\">option one</option>";
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn render_option_escaped() {
+        let result = SelectOption {
+            value: "a\"b".to_owned(),
+            content: Rc::new("a < b".to_owned().into()),
+        }
+        .render();
+        let expected = "<option value=\"a&quot;b\">a &lt; b</option>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn build_select() {
+        let result = Select::new()
+            .id("selector")
+            .option(SelectOption::new("option1").text("option one"))
+            .options([SelectOption::new("option2").text("option two")])
+            .build();
+        let expected = example_select().into();
+        assert_eq!(result, expected)
+    }
 }
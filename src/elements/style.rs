@@ -1,15 +1,20 @@
 use crate::elements::HtmlElement;
-use crate::{css::CssDocument, render::Render};
+use crate::{
+    css::CssDocument,
+    render::{Render, Renderer},
+};
+use std::fmt;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Style {
     pub style: CssDocument,
 }
 
 impl Render for Style {
-    fn render(&self) -> String {
-        let blocks_str = self.style.render().replace('\n', "\n\t");
-        format!("<style>\n\t{blocks_str}\n</style>")
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<style>")?;
+        out.block(|out| self.style.render_into(out))?;
+        out.write_str("</style>")
     }
 }
 
@@ -19,6 +24,12 @@ impl From<Style> for HtmlElement {
     }
 }
 
+impl Style {
+    pub fn new(style: CssDocument) -> Style {
+        Style { style }
+    }
+}
+
 #[cfg(test)]
 mod styles_tests {
 
@@ -39,7 +50,7 @@ mod styles_tests {
                         selector: TopSelector::All.into(),
                         decls: vec![
                             (Property::Display, Keyword::Non.into()).into(),
-                            (Size::Height.into(), (0.0, Unit::Percent.into()).into()).into(),
+                            (Size::Height.into(), (0.0, Unit::Percent).into()).into(),
                         ],
                     },
                     CssBlock {
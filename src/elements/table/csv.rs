@@ -0,0 +1,452 @@
+use super::{Table, Tr};
+use crate::{
+    elements::{HtmlElement, Literal},
+    tabular::{aligned_table, CellValue},
+    visit::{walk_element, Visitor},
+};
+use chrono::NaiveDate;
+use std::{error::Error, fmt};
+
+/// The dialect of csv read by [`Table::from_csv`] and written by [`Table::to_csv`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Csv {
+    delimiter: char,
+    quote: Option<char>,
+    header: bool,
+}
+
+/// Comma separated, quoted with `"` and a header row
+impl Default for Csv {
+    fn default() -> Csv {
+        Csv {
+            delimiter: ',',
+            quote: Some('"'),
+            header: true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CsvError {
+    /// A quoted field that is still open at the end of the input, lines are counted from 1
+    UnterminatedQuote { line: usize },
+    /// A record with a different number of fields than the first one
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CsvError::UnterminatedQuote { line } => {
+                write!(f, "quoted field starting on line {line} is never closed")
+            }
+            CsvError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(f, "line {line} has {found} fields, expected {expected}"),
+        }
+    }
+}
+
+impl Error for CsvError {}
+
+impl Csv {
+    pub fn new() -> Csv {
+        Csv::default()
+    }
+
+    pub fn delimiter(mut self, delimiter: char) -> Csv {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// `None` reads quotes as ordinary characters and writes fields as they are
+    pub fn quote(mut self, quote: Option<char>) -> Csv {
+        self.quote = quote;
+        self
+    }
+
+    /// Whether the first record holds the column headers
+    pub fn header(mut self, header: bool) -> Csv {
+        self.header = header;
+        self
+    }
+
+    /// Splits the input into records, skipping empty lines
+    fn records(&self, input: &str) -> Result<Vec<(usize, Vec<String>)>, CsvError> {
+        let mut records = vec![];
+        let mut record = vec![];
+        let mut field = String::new();
+        // whether the current field was quoted, i.e. is not empty even without content
+        let mut quoted = false;
+        let mut line = 1;
+        let mut record_line = 1;
+        let mut chars = input.chars().peekable();
+        while let Some(c) = chars.next() {
+            if Some(c) == self.quote && field.is_empty() && !quoted {
+                quoted = true;
+                let start = line;
+                loop {
+                    match chars.next() {
+                        None => return Err(CsvError::UnterminatedQuote { line: start }),
+                        Some(c) if Some(c) == self.quote => {
+                            if chars.peek().copied() == self.quote {
+                                field.push(c);
+                                chars.next();
+                            } else {
+                                break;
+                            }
+                        }
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            field.push(c)
+                        }
+                    }
+                }
+            } else if c == self.delimiter {
+                record.push(std::mem::take(&mut field));
+                quoted = false;
+            } else if c == '\n' || c == '\r' {
+                if c == '\r' && chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+                if !record.is_empty() || !field.is_empty() || quoted {
+                    record.push(std::mem::take(&mut field));
+                    records.push((record_line, std::mem::take(&mut record)));
+                }
+                quoted = false;
+                line += 1;
+                record_line = line;
+            } else {
+                field.push(c);
+            }
+        }
+        if !record.is_empty() || !field.is_empty() || quoted {
+            record.push(field);
+            records.push((record_line, record));
+        }
+        Ok(records)
+    }
+
+    fn write_field(&self, field: &str, out: &mut String) {
+        let Some(quote) = self.quote else {
+            out.push_str(field);
+            return;
+        };
+        if field.contains([self.delimiter, quote, '\n', '\r']) {
+            out.push(quote);
+            for c in field.chars() {
+                if c == quote {
+                    out.push(quote);
+                }
+                out.push(c);
+            }
+            out.push(quote);
+        } else {
+            out.push_str(field);
+        }
+    }
+}
+
+/// The kind of value a field holds, deciding its alignment
+fn classify(field: &str) -> CellValue {
+    let trimmed = field.trim();
+    if trimmed.is_empty() {
+        CellValue::Empty
+    } else if let Ok(n) = trimmed.parse::<i128>() {
+        CellValue::Integer(n)
+    } else if let Some(x) = trimmed.parse::<f64>().ok().filter(|x| x.is_finite()) {
+        CellValue::Float(x)
+    } else if let Ok(date) = NaiveDate::parse_from_str(trimmed, "%Y-%m-%d") {
+        CellValue::Date(date)
+    } else {
+        CellValue::Text(field.to_owned())
+    }
+}
+
+/// Collects the text of all literals in a cell
+#[derive(Default)]
+struct TextContent(String);
+
+impl<'a> Visitor<'a> for TextContent {
+    fn enter_literal(&mut self, literal: &'a Literal) {
+        self.0.push_str(literal.content())
+    }
+}
+
+impl Table {
+    /// A table from csv, with the first record as header unless configured otherwise
+    ///
+    /// Fields keep their text, numeric fields are right aligned as in [`Table::from_rows`].
+    pub fn from_csv(input: &str, csv: &Csv) -> Result<Table, CsvError> {
+        let mut records = csv.records(input)?.into_iter();
+        let headers = if csv.header {
+            match records.next() {
+                Some((_, headers)) => Some(headers),
+                None => return Ok(Table::new()),
+            }
+        } else {
+            None
+        };
+        let mut expected = headers.as_ref().map(Vec::len);
+        let mut rows = vec![];
+        for (line, record) in records {
+            let expected = *expected.get_or_insert(record.len());
+            if record.len() != expected {
+                return Err(CsvError::RaggedRow {
+                    line,
+                    expected,
+                    found: record.len(),
+                });
+            }
+            rows.push(
+                record
+                    .into_iter()
+                    .map(|field| {
+                        let content = if field.is_empty() {
+                            HtmlElement::default()
+                        } else {
+                            field.as_str().into()
+                        };
+                        (classify(&field), content)
+                    })
+                    .collect(),
+            );
+        }
+        Ok(aligned_table(headers, rows))
+    }
+
+    /// Writes every row as a record, header and footer rows included, each ending in `\n`
+    ///
+    /// Cells hold the text of their literals, the other columns a cell spans across or down
+    /// are empty fields. The caption and column groups are left out.
+    pub fn to_csv(&self, csv: &Csv) -> String {
+        let sections = self
+            .head
+            .iter()
+            .map(|head| head.rows.as_slice())
+            .chain([self.rows.as_slice()])
+            .chain(self.bodies.iter().map(|body| body.rows.as_slice()))
+            .chain(self.foot.iter().map(|foot| foot.rows.as_slice()));
+        let mut out = String::new();
+        for rows in sections {
+            // spans do not reach across sections
+            let mut covered = vec![];
+            for row in rows {
+                write_record(csv, row, &mut covered, &mut out);
+            }
+        }
+        out
+    }
+}
+
+/// Writes `row`, leaving the columns `covered` by cells of rows above empty
+fn write_record(csv: &Csv, row: &Tr, covered: &mut Vec<usize>, out: &mut String) {
+    let mut fields = vec![];
+    let mut col = 0;
+    for cell in &row.cols {
+        while covered.get(col).is_some_and(|&rows| rows > 0) {
+            fields.push(String::new());
+            col += 1;
+        }
+        let mut text = TextContent::default();
+        walk_element(&mut text, cell.content());
+        let (colspan, rowspan) = cell.spans();
+        if covered.len() < col + colspan {
+            covered.resize(col + colspan, 0);
+        }
+        covered[col..col + colspan].fill(rowspan);
+        fields.push(text.0);
+        fields.resize(col + colspan, String::new());
+        col += colspan;
+    }
+    let width = covered
+        .iter()
+        .rposition(|&rows| rows > 0)
+        .map_or(0, |i| i + 1);
+    fields.resize(width.max(fields.len()), String::new());
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            out.push(csv.delimiter);
+        }
+        csv.write_field(field, out);
+    }
+    out.push('\n');
+    for rows in covered.iter_mut() {
+        *rows = rows.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod csv_tests {
+    use super::{Csv, CsvError};
+    use crate::{
+        attribute::Scope,
+        elements::{Table, TableCell, TableSection, Td, Th, Tr, WithAttributes, WithChildren, A},
+        render::Render,
+        tabular::NUMERIC_STYLE,
+    };
+
+    #[test]
+    fn from_csv() {
+        let result = Table::from_csv("name,count\npear,3\n\"apple, red\",\n", &Csv::new()).unwrap();
+        let expected = Table::new()
+            .section(
+                TableSection::head().row(
+                    Tr::new()
+                        .child(Th::new().scope(Scope::Col).text("name"))
+                        .child(
+                            Th::new()
+                                .scope(Scope::Col)
+                                .text("count")
+                                .style(NUMERIC_STYLE),
+                        ),
+                ),
+            )
+            .section(
+                TableSection::body()
+                    .row(
+                        Tr::new()
+                            .child(Td::new().text("pear"))
+                            .child(Td::new().text("3").style(NUMERIC_STYLE)),
+                    )
+                    .row(
+                        Tr::new()
+                            .child(Td::new().text("apple, red"))
+                            .child(Td::new()),
+                    ),
+            );
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn from_csv_without_header() {
+        let csv = Csv::new().header(false).delimiter(';').quote(None);
+        let result = Table::from_csv("\"a\";1.50\r\n\r\nb;-2", &csv).unwrap();
+        let expected = Table::new().section(
+            TableSection::body()
+                .row(
+                    Tr::new()
+                        .child(Td::new().text("\"a\""))
+                        .child(Td::new().text("1.50").style(NUMERIC_STYLE)),
+                )
+                .row(
+                    Tr::new()
+                        .child(Td::new().text("b"))
+                        .child(Td::new().text("-2").style(NUMERIC_STYLE)),
+                ),
+        );
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn from_csv_quotes() {
+        let table = Table::from_csv("a\n\"say \"\"hi\"\"\nthere\"\n\"\"\n", &Csv::new()).unwrap();
+        let result = table.to_csv(&Csv::new());
+        let expected = "a\n\"say \"\"hi\"\"\nthere\"\n\n";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn from_csv_empty() {
+        let result = Table::from_csv("", &Csv::new()).unwrap();
+        let expected = Table::new();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn from_csv_unterminated() {
+        let result = Table::from_csv("a,b\n1,\"2\n3\n", &Csv::new());
+        let expected = Err(CsvError::UnterminatedQuote { line: 2 });
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn from_csv_ragged() {
+        let result = Table::from_csv("a,b\n1,2\n\n3\n", &Csv::new());
+        let expected = Err(CsvError::RaggedRow {
+            line: 4,
+            expected: 2,
+            found: 1,
+        });
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn ragged_display() {
+        let result = CsvError::RaggedRow {
+            line: 4,
+            expected: 2,
+            found: 1,
+        }
+        .to_string();
+        let expected = "line 4 has 1 fields, expected 2";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn to_csv() {
+        let table = Table::new()
+            .section(TableSection::head().row(Tr::new().text("name").text("link")))
+            .row(
+                Tr::new()
+                    .child(Td::new().text("a;b"))
+                    .child(Td::new().child(A::new().href("/x").text("x").text("y"))),
+            )
+            .section(TableSection::foot().row(Tr::new().child(Td::new().colspan(2).text("sum"))));
+        let result = table.to_csv(&Csv::new().delimiter(';'));
+        let expected = "name;link\n\"a;b\";xy\nsum;\n";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn to_csv_rowspan() {
+        let table = Table::new()
+            .row(
+                Tr::new()
+                    .child(Td::new().rowspan(2).text("a"))
+                    .text("b")
+                    .child(Td::new().rowspan(2).text("c")),
+            )
+            .row(Tr::new().text("d"))
+            .row(Tr::new().text("e").text("f").text("g"));
+        let result = table.to_csv(&Csv::new().header(false));
+        let expected = "a,b,c\n,d,\ne,f,g\n";
+        assert_eq!(result, expected);
+        let reparsed = Table::from_csv(&result, &Csv::new().header(false)).unwrap();
+        assert_eq!(reparsed.to_csv(&Csv::new().header(false)), expected)
+    }
+
+    #[test]
+    fn to_csv_clamps_colspan() {
+        let table = Table::new().row(Tr::new().child(Td::new().colspan(u32::MAX).text("a")));
+        let result = table.to_csv(&Csv::new());
+        let expected = format!("a{}\n", ",".repeat(999));
+        assert_eq!(result, expected);
+        let reparsed = Table::from_csv(&result, &Csv::new()).unwrap();
+        assert_eq!(reparsed.to_csv(&Csv::new()), expected)
+    }
+
+    #[test]
+    fn csv_roundtrip() {
+        let input = "name,count\n\"a, b\",1\n\"\"\"c\"\"\",\n";
+        let result = Table::from_csv(input, &Csv::new())
+            .unwrap()
+            .to_csv(&Csv::new());
+        assert_eq!(result, input)
+    }
+
+    #[test]
+    fn render_from_csv() {
+        let result = Table::from_csv("n\n1\n", &Csv::new()).unwrap().render();
+        let expected = "<table >\n\t<thead >\n\t\t<tr >\n\t\t\t<th scope=\"col\" style=\"text-align:right;\">\n\t\t\t\tn\n\t\t\t</th>\n\t\t</tr>\n\t</thead>\n\t<tbody >\n\t\t<tr >\n\t\t\t<td style=\"text-align:right;\">\n\t\t\t\t1\n\t\t\t</td>\n\t\t</tr>\n\t</tbody>\n</table>";
+        assert_eq!(result, expected)
+    }
+}
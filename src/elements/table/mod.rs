@@ -0,0 +1,948 @@
+use super::{
+    builder::{push_content, WithAttributes, WithChildren},
+    HtmlElement,
+};
+use crate::{
+    attribute::{Attribute, Scope},
+    render::{Render, Renderer},
+};
+use std::{error::Error, fmt, rc::Rc};
+
+mod csv;
+pub use csv::{Csv, CsvError};
+
+/// Colspan and rowspan, shared by both kinds of cells
+pub trait TableCell: WithAttributes {
+    fn colspan(self, columns: u32) -> Self {
+        self.attr(Attribute::Colspan(columns))
+    }
+
+    /// A rowspan of 0 spans the remaining rows of the section
+    fn rowspan(self, rows: u32) -> Self {
+        self.attr(Attribute::Rowspan(rows))
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Td {
+    pub attributes: Vec<Attribute>,
+    pub content: Rc<HtmlElement>,
+}
+
+/// Header cell
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Th {
+    pub attributes: Vec<Attribute>,
+    pub content: Rc<HtmlElement>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Cell {
+    Td(Td),
+    Th(Th),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Tr {
+    pub attributes: Vec<Attribute>,
+    pub cols: Vec<Cell>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TableSectionKind {
+    Head,
+    #[default]
+    Body,
+    Foot,
+}
+
+/// `<thead>`, `<tbody>` or `<tfoot>`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableSection {
+    pub kind: TableSectionKind,
+    pub attributes: Vec<Attribute>,
+    pub rows: Vec<Tr>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Caption {
+    pub attributes: Vec<Attribute>,
+    pub content: Rc<HtmlElement>,
+}
+
+/// Group of columns, either spanning a number of columns or made up of `<col>` elements
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Colgroup {
+    pub attributes: Vec<Attribute>,
+    pub cols: Vec<Col>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Col {
+    pub attributes: Vec<Attribute>,
+}
+
+/// The parts of a table are rendered in the order html requires,
+/// rows outside of any section between the head and the bodies
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Table {
+    pub attributes: Vec<Attribute>,
+    pub caption: Option<Caption>,
+    pub colgroups: Vec<Colgroup>,
+    pub head: Option<TableSection>,
+    pub rows: Vec<Tr>,
+    pub bodies: Vec<TableSection>,
+    pub foot: Option<TableSection>,
+}
+
+/// Structural problems found by [`Table::validate`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TableError {
+    /// A row covering a different number of columns than the first one, spans included.
+    /// Rows are counted in document order, starting at 0.
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The column groups declare a different number of columns than the rows cover
+    ColumnGroups { expected: usize, found: usize },
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TableError::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(f, "row {row} covers {found} columns, expected {expected}"),
+            TableError::ColumnGroups { expected, found } => write!(
+                f,
+                "column groups declare {found} columns, expected {expected}"
+            ),
+        }
+    }
+}
+
+impl Error for TableError {}
+
+impl Render for Table {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<table")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        out.block(|out| out.write_lines(&self.parts()))?;
+        out.write_str("</table>")
+    }
+}
+
+/// Borrowed part of a table, in the order they are rendered
+enum Part<'a> {
+    Caption(&'a Caption),
+    Colgroup(&'a Colgroup),
+    Section(&'a TableSection),
+    Row(&'a Tr),
+}
+
+impl Render for Part<'_> {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        match self {
+            Part::Caption(caption) => caption.render_into(out),
+            Part::Colgroup(colgroup) => colgroup.render_into(out),
+            Part::Section(section) => section.render_into(out),
+            Part::Row(row) => row.render_into(out),
+        }
+    }
+}
+
+impl Render for TableSection {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        let tag = self.kind.tag();
+        out.write_str("<")?;
+        out.write_str(tag)?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        out.block(|out| out.write_lines(&self.rows))?;
+        out.write_str("</")?;
+        out.write_str(tag)?;
+        out.write_str(">")
+    }
+}
+
+impl Render for Tr {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<tr")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        out.block(|out| out.write_lines(&self.cols))?;
+        out.write_str("</tr>")
+    }
+}
+
+impl Render for Cell {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        match self {
+            Cell::Td(td) => td.render_into(out),
+            Cell::Th(th) => th.render_into(out),
+        }
+    }
+}
+
+/// Cells without attributes are written as a bare `<td>`
+impl Render for Td {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<td")?;
+        if !self.attributes.is_empty() {
+            out.write_attributes(&self.attributes)?;
+        }
+        out.write_str(">")?;
+        out.block(|out| self.content.render_into(out))?;
+        out.write_str("</td>")
+    }
+}
+
+/// Cells without attributes are written as a bare `<th>`
+impl Render for Th {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<th")?;
+        if !self.attributes.is_empty() {
+            out.write_attributes(&self.attributes)?;
+        }
+        out.write_str(">")?;
+        out.block(|out| self.content.render_into(out))?;
+        out.write_str("</th>")
+    }
+}
+
+impl Render for Caption {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<caption")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        self.content.render_into(out)?;
+        out.write_str("</caption>")
+    }
+}
+
+impl Render for Colgroup {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<colgroup")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        if !self.cols.is_empty() {
+            out.block(|out| out.write_lines(&self.cols))?;
+        }
+        out.write_str("</colgroup>")
+    }
+}
+
+impl Render for Col {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<col")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str("/>")
+    }
+}
+
+impl From<Table> for HtmlElement {
+    fn from(tb: Table) -> HtmlElement {
+        HtmlElement::Table(tb)
+    }
+}
+
+impl From<TableSection> for HtmlElement {
+    fn from(section: TableSection) -> HtmlElement {
+        HtmlElement::TableSection(section)
+    }
+}
+
+impl From<Tr> for HtmlElement {
+    fn from(tr: Tr) -> HtmlElement {
+        HtmlElement::Tr(tr)
+    }
+}
+
+impl From<Td> for HtmlElement {
+    fn from(td: Td) -> HtmlElement {
+        HtmlElement::Td(td)
+    }
+}
+
+impl From<Th> for HtmlElement {
+    fn from(th: Th) -> HtmlElement {
+        HtmlElement::Th(th)
+    }
+}
+
+impl From<Cell> for HtmlElement {
+    fn from(cell: Cell) -> HtmlElement {
+        match cell {
+            Cell::Td(td) => td.into(),
+            Cell::Th(th) => th.into(),
+        }
+    }
+}
+
+impl From<Caption> for HtmlElement {
+    fn from(caption: Caption) -> HtmlElement {
+        HtmlElement::Caption(caption)
+    }
+}
+
+impl From<Colgroup> for HtmlElement {
+    fn from(colgroup: Colgroup) -> HtmlElement {
+        HtmlElement::Colgroup(colgroup)
+    }
+}
+
+impl From<Col> for HtmlElement {
+    fn from(col: Col) -> HtmlElement {
+        HtmlElement::Col(col)
+    }
+}
+
+impl From<Td> for Cell {
+    fn from(td: Td) -> Cell {
+        Cell::Td(td)
+    }
+}
+
+impl From<Th> for Cell {
+    fn from(th: Th) -> Cell {
+        Cell::Th(th)
+    }
+}
+
+impl Table {
+    pub fn new() -> Table {
+        Table::default()
+    }
+
+    pub fn caption(self, caption: Caption) -> Table {
+        Table {
+            caption: Some(caption),
+            ..self
+        }
+    }
+
+    pub fn colgroup(mut self, colgroup: Colgroup) -> Table {
+        self.colgroups.push(colgroup);
+        self
+    }
+
+    /// Adds `section` as head, body or foot depending on its kind
+    pub fn section(mut self, section: TableSection) -> Table {
+        self.push_section(section);
+        self
+    }
+
+    pub fn row(mut self, row: Tr) -> Table {
+        self.rows.push(row);
+        self
+    }
+
+    fn parts(&self) -> Vec<Part<'_>> {
+        let mut parts: Vec<Part> = self.caption.iter().map(Part::Caption).collect();
+        parts.extend(self.colgroups.iter().map(Part::Colgroup));
+        parts.extend(self.head.iter().map(Part::Section));
+        parts.extend(self.rows.iter().map(Part::Row));
+        parts.extend(self.bodies.iter().map(Part::Section));
+        parts.extend(self.foot.iter().map(Part::Section));
+        parts
+    }
+
+    fn push_section(&mut self, section: TableSection) {
+        match section.kind {
+            TableSectionKind::Head => self.head = Some(section),
+            TableSectionKind::Body => self.bodies.push(section),
+            TableSectionKind::Foot => self.foot = Some(section),
+        }
+    }
+
+    /// Checks that all rows cover the same number of columns once spans are taken into account,
+    /// and that the column groups agree with them.
+    /// Spans do not reach across sections.
+    pub fn validate(&self) -> Result<(), TableError> {
+        let sections = self
+            .head
+            .iter()
+            .map(|head| head.rows.as_slice())
+            .chain([self.rows.as_slice()])
+            .chain(self.bodies.iter().map(|body| body.rows.as_slice()))
+            .chain(self.foot.iter().map(|foot| foot.rows.as_slice()));
+        let widths: Vec<usize> = sections.flat_map(row_widths).collect();
+        let Some(&expected) = widths.first() else {
+            return Ok(());
+        };
+        if let Some((row, &found)) = widths
+            .iter()
+            .enumerate()
+            .find(|(_, &width)| width != expected)
+        {
+            return Err(TableError::RaggedRow {
+                row,
+                expected,
+                found,
+            });
+        }
+        if !self.colgroups.is_empty() {
+            let found = self.colgroups.iter().map(Colgroup::columns).sum();
+            if found != expected {
+                return Err(TableError::ColumnGroups { expected, found });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The number of columns each row covers, including cells reaching down from rows above
+fn row_widths(rows: &[Tr]) -> Vec<usize> {
+    // for every column, the number of rows it is still covered for
+    let mut covered: Vec<usize> = vec![];
+    rows.iter()
+        .map(|row| {
+            let mut col = 0;
+            for cell in row.cols.iter() {
+                while covered.get(col).is_some_and(|&rows| rows > 0) {
+                    col += 1;
+                }
+                let (colspan, rowspan) = cell.spans();
+                if covered.len() < col + colspan {
+                    covered.resize(col + colspan, 0);
+                }
+                covered[col..col + colspan].fill(rowspan);
+                col += colspan;
+            }
+            let width = covered
+                .iter()
+                .rposition(|&rows| rows > 0)
+                .map_or(0, |i| i + 1);
+            for rows in covered.iter_mut() {
+                *rows = rows.saturating_sub(1);
+            }
+            width
+        })
+        .collect()
+}
+
+const MAX_COLSPAN: u32 = 1000;
+const MAX_ROWSPAN: u32 = 65534;
+
+/// Value of a span attribute, 1 if there is none
+fn span(attributes: &[Attribute], get: fn(&Attribute) -> Option<u32>) -> u32 {
+    attributes.iter().find_map(get).unwrap_or(1)
+}
+
+impl WithAttributes for Table {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+/// Children are sorted into the parts of the table, anything that is no part becomes a row
+impl WithChildren for Table {
+    fn push_child(&mut self, child: HtmlElement) {
+        match child {
+            HtmlElement::Tr(tr) => self.rows.push(tr),
+            HtmlElement::TableSection(section) => self.push_section(section),
+            HtmlElement::Caption(caption) => self.caption = Some(caption),
+            HtmlElement::Colgroup(colgroup) => self.colgroups.push(colgroup),
+            HtmlElement::Col(col) => self.colgroups.push(Colgroup::new().col(col)),
+            HtmlElement::ComponentList(children) => {
+                for child in children {
+                    self.push_child(child)
+                }
+            }
+            child => self.rows.push(Tr::new().child(child)),
+        }
+    }
+}
+
+impl TableSectionKind {
+    pub fn tag(&self) -> &'static str {
+        match self {
+            TableSectionKind::Head => "thead",
+            TableSectionKind::Body => "tbody",
+            TableSectionKind::Foot => "tfoot",
+        }
+    }
+}
+
+impl TableSection {
+    pub fn new(kind: TableSectionKind) -> TableSection {
+        TableSection {
+            kind,
+            ..TableSection::default()
+        }
+    }
+
+    pub fn head() -> TableSection {
+        TableSection::new(TableSectionKind::Head)
+    }
+
+    pub fn body() -> TableSection {
+        TableSection::new(TableSectionKind::Body)
+    }
+
+    pub fn foot() -> TableSection {
+        TableSection::new(TableSectionKind::Foot)
+    }
+
+    pub fn row(mut self, row: Tr) -> TableSection {
+        self.rows.push(row);
+        self
+    }
+}
+
+impl WithAttributes for TableSection {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+/// Children that are not rows already are wrapped in one
+impl WithChildren for TableSection {
+    fn push_child(&mut self, child: HtmlElement) {
+        match child {
+            HtmlElement::Tr(tr) => self.rows.push(tr),
+            child => self.rows.push(Tr::new().child(child)),
+        }
+    }
+}
+
+impl Tr {
+    pub fn new() -> Tr {
+        Tr::default()
+    }
+}
+
+impl WithAttributes for Tr {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+/// Children that are not cells already are wrapped in one
+impl WithChildren for Tr {
+    fn push_child(&mut self, child: HtmlElement) {
+        match child {
+            HtmlElement::Td(td) => self.cols.push(td.into()),
+            HtmlElement::Th(th) => self.cols.push(th.into()),
+            child => self.cols.push(Td::new().child(child).into()),
+        }
+    }
+}
+
+impl Cell {
+    pub fn attributes(&self) -> &[Attribute] {
+        match self {
+            Cell::Td(td) => &td.attributes,
+            Cell::Th(th) => &th.attributes,
+        }
+    }
+
+    pub fn content(&self) -> &Rc<HtmlElement> {
+        match self {
+            Cell::Td(td) => &td.content,
+            Cell::Th(th) => &th.content,
+        }
+    }
+
+    /// Columns and rows covered by the cell, a rowspan of 0 covers all remaining rows.
+    /// Spans are clamped the way browsers do, to 1000 columns and 65534 rows
+    fn spans(&self) -> (usize, usize) {
+        let attributes = self.attributes();
+        let colspan = span(attributes, |attr| match attr {
+            Attribute::Colspan(n) => Some(*n),
+            _ => None,
+        });
+        let rowspan = span(attributes, |attr| match attr {
+            Attribute::Rowspan(n) => Some(*n),
+            _ => None,
+        });
+        let rowspan = if rowspan == 0 {
+            usize::MAX
+        } else {
+            rowspan.min(MAX_ROWSPAN) as usize
+        };
+        (colspan.clamp(1, MAX_COLSPAN) as usize, rowspan)
+    }
+}
+
+impl Td {
+    pub fn new() -> Td {
+        Td::default()
+    }
+}
+
+impl WithAttributes for Td {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+impl WithChildren for Td {
+    fn push_child(&mut self, child: HtmlElement) {
+        push_content(&mut self.content, child)
+    }
+}
+
+impl TableCell for Td {}
+
+impl Th {
+    pub fn new() -> Th {
+        Th::default()
+    }
+
+    pub fn scope(self, scope: Scope) -> Th {
+        self.attr(Attribute::Scope(scope))
+    }
+}
+
+impl WithAttributes for Th {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+impl WithChildren for Th {
+    fn push_child(&mut self, child: HtmlElement) {
+        push_content(&mut self.content, child)
+    }
+}
+
+impl TableCell for Th {}
+
+impl Caption {
+    pub fn new() -> Caption {
+        Caption::default()
+    }
+}
+
+impl WithAttributes for Caption {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+impl WithChildren for Caption {
+    fn push_child(&mut self, child: HtmlElement) {
+        push_content(&mut self.content, child)
+    }
+}
+
+impl Colgroup {
+    pub fn new() -> Colgroup {
+        Colgroup::default()
+    }
+
+    /// Only used when the group has no `<col>` elements
+    pub fn span(self, columns: u32) -> Colgroup {
+        self.attr(Attribute::Span(columns))
+    }
+
+    pub fn col(mut self, col: Col) -> Colgroup {
+        self.cols.push(col);
+        self
+    }
+
+    /// The number of columns in the group
+    pub fn columns(&self) -> usize {
+        if self.cols.is_empty() {
+            column_span(&self.attributes)
+        } else {
+            self.cols.iter().map(Col::columns).sum()
+        }
+    }
+}
+
+impl WithAttributes for Colgroup {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+impl Col {
+    pub fn new() -> Col {
+        Col::default()
+    }
+
+    pub fn span(self, columns: u32) -> Col {
+        self.attr(Attribute::Span(columns))
+    }
+
+    pub fn columns(&self) -> usize {
+        column_span(&self.attributes)
+    }
+}
+
+impl WithAttributes for Col {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+/// Columns covered by a `<col>` or `<colgroup>`, clamped like colspans
+fn column_span(attributes: &[Attribute]) -> usize {
+    let columns = span(attributes, |attr| match attr {
+        Attribute::Span(n) => Some(*n),
+        _ => None,
+    });
+    columns.clamp(1, MAX_COLSPAN) as usize
+}
+
+#[cfg(test)]
+mod table_tests {
+    use super::{
+        Attribute, Caption, Col, Colgroup, HtmlElement, Render, Scope, Table, TableCell,
+        TableError, TableSection, Td, Th, Tr, WithAttributes, WithChildren,
+    };
+    use crate::elements::Build;
+    use std::rc::Rc;
+
+    fn example_td() -> Td {
+        Td {
+            attributes: vec![],
+            content: Rc::new("td".to_owned().into()),
+        }
+    }
+
+    fn example_tr() -> Tr {
+        Tr {
+            attributes: vec![Attribute::Id("row".to_owned())],
+            cols: vec![example_td().into()],
+        }
+    }
+
+    fn example_table() -> Table {
+        Table {
+            attributes: vec![Attribute::Id("table".to_owned())],
+            rows: vec![example_tr()],
+            ..Table::default()
+        }
+    }
+
+    fn cells(n: usize) -> Tr {
+        (0..n).fold(Tr::new(), |tr, i| tr.text(&i.to_string()))
+    }
+
+    #[test]
+    fn render_table() {
+        let result = example_table().render();
+        let expected =
+            "<table id=\"table\">\n\t<tr id=\"row\">\n\t\t<td>\n\t\t\ttd\n\t\t</td>\n\t</tr>\n</table>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_tr() {
+        let result = example_tr().render();
+        let expected = "<tr id=\"row\">\n\t<td>\n\t\ttd\n\t</td>\n</tr>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_td() {
+        let result = example_td().render();
+        let expected = "<td>\n\ttd\n</td>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_td_attributes() {
+        let result = Td::new().class("num").colspan(2).text("1").render();
+        let expected = "<td class=\"num\" colspan=\"2\">\n\t1\n</td>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_th() {
+        let result = Th::new().scope(Scope::Col).text("Name").render();
+        let expected = "<th scope=\"col\">\n\tName\n</th>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_table_into() {
+        let result = <Table as Into<HtmlElement>>::into(example_table()).render();
+        let expected = example_table().render();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_tr_into() {
+        let result = <Tr as Into<HtmlElement>>::into(example_tr()).render();
+        let expected = example_tr().render();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_td_into() {
+        let result = <Td as Into<HtmlElement>>::into(example_td()).render();
+        let expected = example_td().render();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_full_table() {
+        let result = Table::new()
+            .child(TableSection::foot().child(Tr::new().text("total")))
+            .child(TableSection::head().child(Tr::new().child(Th::new().text("item"))))
+            .child(Colgroup::new().span(1))
+            .child(Caption::new().text("Sales"))
+            .child(TableSection::body().child(Tr::new().text("a")))
+            .render();
+        let expected = "<table >\n\t<caption >Sales</caption>\n\t<colgroup span=\"1\"></colgroup>\n\t<thead >\n\t\t<tr >\n\t\t\t<th>\n\t\t\t\titem\n\t\t\t</th>\n\t\t</tr>\n\t</thead>\n\t<tbody >\n\t\t<tr >\n\t\t\t<td>\n\t\t\t\ta\n\t\t\t</td>\n\t\t</tr>\n\t</tbody>\n\t<tfoot >\n\t\t<tr >\n\t\t\t<td>\n\t\t\t\ttotal\n\t\t\t</td>\n\t\t</tr>\n\t</tfoot>\n</table>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_colgroup() {
+        let result = Colgroup::new()
+            .col(Col::new().span(2))
+            .col(Col::new().class("num"))
+            .render();
+        let expected = "<colgroup >\n\t<col span=\"2\"/>\n\t<col class=\"num\"/>\n</colgroup>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn columns_clamped() {
+        let result = [
+            Col::new().span(0).columns(),
+            Col::new().span(u32::MAX).columns(),
+            Colgroup::new().span(0).columns(),
+            Colgroup::new().span(1001).columns(),
+            Colgroup::new()
+                .col(Col::new().span(0))
+                .col(Col::new())
+                .columns(),
+        ];
+        let expected = [1, 1000, 1, 1000, 2];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn validate_span_zero_col() {
+        let result = Table::new()
+            .colgroup(Colgroup::new().col(Col::new().span(0)).col(Col::new()))
+            .child(
+                Tr::new()
+                    .child(Td::new().text("a"))
+                    .child(Td::new().text("b")),
+            )
+            .validate();
+        assert_eq!(result, Ok(()))
+    }
+
+    #[test]
+    fn build_table() {
+        let result = Table::new()
+            .id("table")
+            .child(Tr::new().id("row").text("td"))
+            .build();
+        let expected = example_table().into();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn build_tr_cells() {
+        let result = Tr::new().child(Td::new().text("a")).text("b");
+        let expected = Tr {
+            attributes: vec![],
+            cols: vec![
+                Td {
+                    attributes: vec![],
+                    content: Rc::new("a".to_owned().into()),
+                }
+                .into(),
+                Td {
+                    attributes: vec![],
+                    content: Rc::new("b".to_owned().into()),
+                }
+                .into(),
+            ],
+        };
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn validate_spans() {
+        // a | b b
+        // a | c | d
+        let table = Table::new()
+            .colgroup(Colgroup::new().col(Col::new()).col(Col::new().span(2)))
+            .section(
+                TableSection::body()
+                    .row(
+                        Tr::new()
+                            .child(Td::new().rowspan(2).text("a"))
+                            .child(Td::new().colspan(2).text("b")),
+                    )
+                    .row(cells(2)),
+            )
+            .section(TableSection::foot().row(cells(3)));
+        assert_eq!(table.validate(), Ok(()))
+    }
+
+    #[test]
+    fn validate_ragged() {
+        let result = Table::new()
+            .row(cells(2))
+            .row(Tr::new().child(Td::new().colspan(2)).text("c"))
+            .validate();
+        let expected = Err(TableError::RaggedRow {
+            row: 1,
+            expected: 2,
+            found: 3,
+        });
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn validate_spans_end_with_section() {
+        let result = Table::new()
+            .section(TableSection::head().row(Tr::new().child(Th::new().rowspan(0)).text("b")))
+            .row(cells(1))
+            .validate();
+        let expected = Err(TableError::RaggedRow {
+            row: 1,
+            expected: 2,
+            found: 1,
+        });
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn validate_clamps_spans() {
+        let result = Table::new()
+            .row(
+                Tr::new()
+                    .child(Td::new().colspan(u32::MAX).rowspan(u32::MAX))
+                    .child(Td::new().colspan(0)),
+            )
+            .row(cells(2))
+            .validate();
+        // the first cell still covers the first 1000 columns of the second row
+        let expected = Err(TableError::RaggedRow {
+            row: 1,
+            expected: 1001,
+            found: 1002,
+        });
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn validate_colgroups() {
+        let result = Table::new()
+            .colgroup(Colgroup::new().span(3))
+            .row(cells(2))
+            .validate();
+        let expected = Err(TableError::ColumnGroups {
+            expected: 2,
+            found: 3,
+        });
+        assert_eq!(result, expected)
+    }
+}
@@ -0,0 +1,185 @@
+use super::{
+    builder::{WithAttributes, WithChildren},
+    HtmlElement, Literal,
+};
+use crate::{
+    attribute::Attribute,
+    render::{Render, Renderer},
+};
+use std::fmt;
+
+content_elements! {
+    /// A paragraph
+    block P("p");
+    block Blockquote("blockquote");
+    /// Preformatted text, its content is rendered without re-indenting it
+    custom Pre("pre");
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Hr {
+    pub attributes: Vec<Attribute>,
+}
+
+impl Render for Pre {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<pre")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str(">")?;
+        // every child is rendered on its own, so no line breaks are added between them
+        match self.content.as_ref() {
+            HtmlElement::ComponentList(elems) => {
+                for elem in elems.iter() {
+                    out.write_unindented(elem)?;
+                }
+            }
+            content => out.write_unindented(content)?,
+        }
+        out.write_str("</pre>")
+    }
+}
+
+impl Render for Hr {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_str("<hr")?;
+        out.write_attributes(&self.attributes)?;
+        out.write_str("/>")
+    }
+}
+
+impl Pre {
+    /// Adds text whose whitespace is kept as it is
+    pub fn preformatted(self, text: &str) -> Pre {
+        self.child(Literal::preformatted(text.to_owned()))
+    }
+}
+
+impl From<Hr> for HtmlElement {
+    fn from(hr: Hr) -> HtmlElement {
+        HtmlElement::Hr(hr)
+    }
+}
+
+impl Hr {
+    pub fn new() -> Hr {
+        Hr::default()
+    }
+}
+
+impl WithAttributes for Hr {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
+    }
+}
+
+#[cfg(test)]
+mod text_tests {
+    use super::{Attribute, Hr, HtmlElement, Literal, Pre, Render, WithAttributes, WithChildren};
+    use crate::{
+        elements::{Build, Code, Div, Element},
+        render::RenderOptions,
+    };
+    use std::rc::Rc;
+
+    fn example_pre() -> Pre {
+        Pre {
+            attributes: vec![],
+            content: Rc::new(Literal::preformatted("fn main() {\n    a < b\n}".to_owned()).into()),
+        }
+    }
+
+    #[test]
+    fn render_pre() {
+        let result = Div::new().child(example_pre()).render();
+        let expected = "<div >\n\t<pre >fn main() {\n    a &lt; b\n}</pre>\n</div>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_pre_children() {
+        let result = Pre::new()
+            .preformatted("let ")
+            .child(Code::new().text("x"))
+            .preformatted(" = 1;\n")
+            .render();
+        let expected = "<pre >let <code >x</code> = 1;\n</pre>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_pre_with_options() {
+        let pre = Pre::new()
+            .preformatted("a ")
+            .child(Div::new().text("x"))
+            .preformatted(" b");
+        let result = [RenderOptions::minified(), RenderOptions::compact()]
+            .map(|options| Div::new().child(pre.clone()).render_with(&options));
+        let expected = [
+            "<div><pre>a <div>x</div> b</pre></div>",
+            "<div >\n<pre >a <div >\nx\n</div> b</pre>\n</div>",
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_pre_into() {
+        let result = <Pre as Into<HtmlElement>>::into(example_pre()).render();
+        let expected = example_pre().render();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn build_pre() {
+        let result = Pre::new().preformatted("fn main() {\n    a < b\n}").build();
+        let expected = example_pre().into();
+        assert_eq!(result, expected)
+    }
+
+    fn example_hr() -> Hr {
+        Hr {
+            attributes: vec![Attribute::Class(vec!["rule".to_owned()])],
+        }
+    }
+
+    #[test]
+    fn render_hr() {
+        let result = example_hr().render();
+        let expected = "<hr class=\"rule\"/>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_hr_into() {
+        let result = <Hr as Into<HtmlElement>>::into(example_hr()).render();
+        let expected = example_hr().render();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_hr_like_generic_void() {
+        let typed: [HtmlElement; 3] = [Hr::new().into(), example_hr().into(), HtmlElement::Br];
+        let generic = [
+            Element::new("hr"),
+            Element::new("hr").class("rule"),
+            Element::new("br"),
+        ];
+        let options = [RenderOptions::pretty(), RenderOptions::minified()];
+        let result = options
+            .clone()
+            .map(|options| typed.clone().map(|elem| elem.render_with(&options)));
+        let expected = [
+            ["<hr />", "<hr class=\"rule\"/>", "<br />"],
+            ["<hr/>", "<hr class=\"rule\"/>", "<br/>"],
+        ];
+        assert_eq!(result, expected);
+        let generic = options.map(|options| generic.clone().map(|elem| elem.render_with(&options)));
+        assert_eq!(generic, expected)
+    }
+
+    #[test]
+    fn build_hr() {
+        let result = Hr::new().class("rule").build();
+        let expected = example_hr().into();
+        assert_eq!(result, expected)
+    }
+}
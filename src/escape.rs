@@ -0,0 +1,157 @@
+/// Escapes text content so it can be placed between tags.
+/// Only `&`, `<` and `>` have to be replaced in this context.
+pub fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Escapes an attribute value so it can be placed inside double quotes.
+/// In addition to the text escapes, both quote characters are replaced
+/// and line breaks are encoded so they survive re-indentation.
+pub fn escape_attribute(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            '\n' => escaped.push_str("&#10;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Decodes character references as they appear in parsed html.
+/// Unknown references are kept as they are.
+pub fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        unescaped.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest.find(';').and_then(|semi| {
+            let reference = &rest[1..semi];
+            let c = match reference {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some('\u{a0}'),
+                _ => match reference.strip_prefix('#') {
+                    Some(hex) if hex.starts_with(['x', 'X']) => u32::from_str_radix(&hex[1..], 16)
+                        .ok()
+                        .and_then(char::from_u32),
+                    Some(dec) => dec.parse().ok().and_then(char::from_u32),
+                    None => None,
+                },
+            };
+            c.map(|c| (c, semi + 1))
+        });
+        match decoded {
+            Some((c, len)) => {
+                unescaped.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
+
+#[cfg(test)]
+mod escape_tests {
+    use super::{escape_attribute, escape_text, unescape};
+
+    #[test]
+    fn text_plain() {
+        let result = escape_text("nothing to escape");
+        let expected = "nothing to escape";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn text_markup() {
+        let result = escape_text("<script>alert(\"x\")</script> & more");
+        let expected = "&lt;script&gt;alert(\"x\")&lt;/script&gt; &amp; more";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn text_entity() {
+        let result = escape_text("&amp;");
+        let expected = "&amp;amp;";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn attribute_plain() {
+        let result = escape_attribute("index.html");
+        let expected = "index.html";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn attribute_quotes() {
+        let result = escape_attribute("\" onload=\"alert('x')");
+        let expected = "&quot; onload=&quot;alert(&#39;x&#39;)";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn attribute_markup() {
+        let result = escape_attribute("a<b>&c");
+        let expected = "a&lt;b&gt;&amp;c";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn attribute_newline() {
+        let result = escape_attribute("line one\nline two");
+        let expected = "line one&#10;line two";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn unescape_named() {
+        let result = unescape("a &lt;b&gt; &amp; &quot;c&quot;");
+        let expected = "a <b> & \"c\"";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn unescape_numeric() {
+        let result = unescape("&#39;&#x41;&#10;");
+        let expected = "'A\n";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn unescape_unknown() {
+        let result = unescape("fish & chips &unknown; &");
+        let expected = "fish & chips &unknown; &";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn unescape_roundtrip() {
+        let text = "<a href=\"x\">'&'</a>\n";
+        let result = unescape(&escape_attribute(text));
+        assert_eq!(result, text)
+    }
+}
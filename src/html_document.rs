@@ -1,36 +1,117 @@
 use super::{
-    elements::{Body, Head},
-    render::Render,
+    attribute::{Attribute, Dir},
+    elements::{Body, Head, WithAttributes},
+    render::{Render, Renderer},
 };
+use std::fmt;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct HtmlDocument {
+    pub doctype: Doctype,
+    /// Attributes of the `<html>` element
+    pub attributes: Vec<Attribute>,
     pub head: Head,
     pub body: Body,
 }
 
+/// The declaration at the start of a document
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Doctype {
+    /// `<!doctype html>`
+    #[default]
+    Html5,
+    /// Anything following `<!DOCTYPE `, such as the public identifier of a legacy doctype
+    Custom(String),
+    /// No declaration, leaving the document in quirks mode
+    None,
+}
+
+impl Render for Doctype {
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        match self {
+            Doctype::Html5 => out.write_str("<!doctype html>"),
+            Doctype::Custom(doctype) => {
+                out.write_str("<!DOCTYPE ")?;
+                out.write_str(doctype)?;
+                out.write_str(">")
+            }
+            Doctype::None => Ok(()),
+        }
+    }
+}
+
 impl Render for HtmlDocument {
-    fn render(&self) -> String {
-        let head_str = self.head.render();
-        let body_str = self.body.render();
-        format!("<!doctype html>\n<html>\n\t{head_str}\n\t{body_str}</html>")
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        if self.doctype != Doctype::None {
+            self.doctype.render_into(out)?;
+            out.newline()?;
+        }
+        out.write_str("<html")?;
+        if !self.attributes.is_empty() {
+            out.write_attributes(&self.attributes)?;
+        }
+        out.write_str(">")?;
+        out.block(|out| {
+            self.head.render_into(out)?;
+            out.newline()?;
+            self.body.render_into(out)
+        })?;
+        out.write_str("</html>")
+    }
+}
+
+impl HtmlDocument {
+    pub fn new() -> HtmlDocument {
+        HtmlDocument::default()
+    }
+
+    pub fn doctype(self, doctype: Doctype) -> HtmlDocument {
+        HtmlDocument { doctype, ..self }
+    }
+
+    pub fn head(self, head: Head) -> HtmlDocument {
+        HtmlDocument { head, ..self }
+    }
+
+    pub fn body(self, body: Body) -> HtmlDocument {
+        HtmlDocument { body, ..self }
+    }
+
+    /// The language of the document, such as `en` or `ar`
+    pub fn lang(self, lang: &str) -> HtmlDocument {
+        self.attr(Attribute::Lang(lang.to_owned()))
+    }
+
+    /// The direction of the document's text
+    pub fn dir(self, dir: Dir) -> HtmlDocument {
+        self.attr(Attribute::Dir(dir))
+    }
+}
+
+impl WithAttributes for HtmlDocument {
+    fn attributes_mut(&mut self) -> &mut Vec<Attribute> {
+        &mut self.attributes
     }
 }
 
 #[cfg(test)]
 mod document_tests {
-    use super::{Body, Head, HtmlDocument, Render};
+    use super::{Body, Doctype, Head, HtmlDocument, Render};
     use crate::{
-        attribute::Attribute,
+        attribute::{Attribute, Dir},
         css::CssDocument,
-        elements::{Div, Style},
+        elements::{Div, Metadata, Style, WithAttributes, WithChildren},
+        render::RenderOptions,
     };
     use std::rc::Rc;
 
     fn example_document1() -> HtmlDocument {
         HtmlDocument {
+            doctype: Doctype::Html5,
+            attributes: vec![],
             head: Head {
                 title: "example document".to_owned(),
+                metadata: Metadata::default(),
                 content: Rc::new(vec![].into()),
             },
             body: Body {
@@ -42,8 +123,11 @@ mod document_tests {
 
     fn example_document2() -> HtmlDocument {
         HtmlDocument {
+            doctype: Doctype::Html5,
+            attributes: vec![],
             head: Head {
                 title: "example document 2".to_owned(),
+                metadata: Metadata::default(),
                 content: Rc::new(
                     Style {
                         style: CssDocument { decls: vec![] },
@@ -66,14 +150,67 @@ mod document_tests {
     #[test]
     fn render_document1() {
         let result = example_document1().render();
-        let expected = "<!doctype html>\n<html>\n\t<head>\n\t<title>\n\t\texample document\n\t</title>\n\t\n</head>\n\t<body id=\"body\">\n\tbody\n</body></html>";
+        let expected = "<!doctype html>\n<html>\n\t<head>\n\t\t<title>\n\t\t\texample document\n\t\t</title>\n\t\t\n\t</head>\n\t<body id=\"body\">\n\t\tbody\n\t</body>\n</html>";
         assert_eq!(result, expected)
     }
 
     #[test]
     fn render_document2() {
         let result = example_document2().render();
-        let expected = "<!doctype html>\n<html>\n\t<head>\n\t<title>\n\t\texample document 2\n\t</title>\n\t<style>\n\t\t\n\t</style>\n</head>\n\t<body >\n\t<div >\n\t\thello\n\t</div>\n</body></html>";
+        let expected = "<!doctype html>\n<html>\n\t<head>\n\t\t<title>\n\t\t\texample document 2\n\t\t</title>\n\t\t<style>\n\t\t\t\n\t\t</style>\n\t</head>\n\t<body >\n\t\t<div >\n\t\t\thello\n\t\t</div>\n\t</body>\n</html>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_to_document() {
+        let mut result = String::new();
+        example_document2().render_to(&mut result, 0).unwrap();
+        let expected = example_document2().render();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_document_minified() {
+        let result = example_document2().render_with(&RenderOptions::minified());
+        let expected = "<!doctype html><html><head><title>example document 2</title><style></style></head><body><div>hello</div></body></html>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_html_attributes() {
+        let result = example_document2()
+            .lang("ar")
+            .dir(Dir::Rtl)
+            .class("dark")
+            .render_with(&RenderOptions::minified());
+        let expected = "<!doctype html><html lang=\"ar\" dir=\"rtl\" class=\"dark\"><head><title>example document 2</title><style></style></head><body><div>hello</div></body></html>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_custom_doctype() {
+        let result = HtmlDocument::new()
+            .doctype(Doctype::Custom(
+                "html PUBLIC \"-//W3C//DTD HTML 4.01//EN\"".to_owned(),
+            ))
+            .render_with(&RenderOptions::minified());
+        let expected = "<!DOCTYPE html PUBLIC \"-//W3C//DTD HTML 4.01//EN\"><html><head><title></title></head><body></body></html>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_without_doctype() {
+        let result = HtmlDocument::new().doctype(Doctype::None).render();
+        let expected = "<html>\n\t<head>\n\t\t<title>\n\t\t\t\n\t\t</title>\n\t\t\n\t</head>\n\t<body >\n\t\t\n\t</body>\n</html>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn build_document() {
+        let result = HtmlDocument::new()
+            .head(Head::new().title("example document"))
+            .body(Body::new().id("body").text("body"));
+        let expected = example_document1();
         assert_eq!(result, expected)
     }
 }
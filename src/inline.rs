@@ -0,0 +1,242 @@
+//! Moving stylesheet rules into `style` attributes, as needed for html emails.
+use crate::{
+    attribute::Attribute,
+    cascade::{cascade, stylesheet_rules},
+    css::{
+        declaration::Declaration,
+        selector::{Selector, SubSelector, TopSelector},
+    },
+    elements::{HtmlElement, Style},
+    html_document::HtmlDocument,
+    query::{matches, walk_document_paths},
+    render::Render,
+    visit::{walk_document_mut, VisitMut},
+};
+
+/// Whether a selector can be replaced by style attributes.
+/// Pseudo classes and `:visited` depend on state that attributes can not express.
+fn is_inlinable(selector: &Selector) -> bool {
+    fn top_inlinable(top: &TopSelector) -> bool {
+        match top {
+            TopSelector::Pseudo(_) => false,
+            TopSelector::Multiple(tops) => tops.iter().all(top_inlinable),
+            _ => true,
+        }
+    }
+    top_inlinable(&selector.top)
+        && match &selector.sub {
+            Some(SubSelector::Visited) => false,
+            Some(SubSelector::ChildCombinator(child)) => is_inlinable(child),
+            _ => true,
+        }
+}
+
+struct Inliner {
+    /// Declarations for every element with attributes, in document order
+    styles: std::vec::IntoIter<Vec<Declaration>>,
+    /// Selectors of rules that have to stay in the stylesheet
+    kept: Vec<Selector>,
+}
+
+impl Inliner {
+    /// Drops every inlined rule from `style`, returning whether any rules are left
+    fn retain_rules(&self, style: &mut Style) -> bool {
+        style.style.decls.retain_mut(|block| match &block.selector {
+            Selector {
+                top: TopSelector::Multiple(tops),
+                sub: None,
+            } => {
+                let mut tops: Vec<TopSelector> = tops
+                    .iter()
+                    .filter(|top| self.kept.contains(&(*top).clone().into()))
+                    .cloned()
+                    .collect();
+                block.selector = match tops.len() {
+                    0 => return false,
+                    1 => tops.remove(0).into(),
+                    _ => TopSelector::Multiple(tops).into(),
+                };
+                true
+            }
+            selector => self.kept.contains(selector),
+        });
+        !style.style.decls.is_empty()
+    }
+}
+
+impl VisitMut for Inliner {
+    fn visit_element_mut(&mut self, elem: &mut HtmlElement) {
+        let emptied = match elem {
+            HtmlElement::ComponentList(elems) => {
+                elems.retain_mut(|elem| match elem {
+                    HtmlElement::Style(style) => self.retain_rules(style),
+                    _ => true,
+                });
+                false
+            }
+            HtmlElement::Style(style) => !self.retain_rules(style),
+            _ => false,
+        };
+        if emptied {
+            *elem = HtmlElement::ComponentList(vec![]);
+        }
+    }
+
+    fn visit_attributes_mut(&mut self, attributes: &mut Vec<Attribute>) {
+        let declarations = match self.styles.next() {
+            Some(declarations) if !declarations.is_empty() => declarations,
+            _ => return,
+        };
+        let style = declarations
+            .iter()
+            .map(Declaration::render)
+            .collect::<Vec<String>>()
+            .join(" ");
+        // replaces the first style attribute and drops the others, which are merged into it
+        let position = attributes
+            .iter()
+            .position(|attr| matches!(attr, Attribute::Style(_)))
+            .unwrap_or(attributes.len());
+        attributes.retain(|attr| !matches!(attr, Attribute::Style(_)));
+        attributes.insert(position, Attribute::Style(style));
+    }
+}
+
+/// Writes the declarations of all matching stylesheet rules into the `style` attribute of each
+/// element, merged with the existing inline style according to the cascade.
+/// Inherited properties are left to inheritance instead of being copied to every descendant.
+///
+/// Rules stay in their style element if they can not be inlined, e.g. `a:visited`,
+/// or if they apply to `<html>` or an element without attributes.
+/// Style elements without any rules left are removed.
+pub fn inline_styles(document: &mut HtmlDocument) {
+    let (styles, kept) = {
+        let rules = stylesheet_rules(document);
+        let mut kept: Vec<bool> = rules
+            .iter()
+            .map(|rule| !is_inlinable(&rule.selector))
+            .collect();
+        let mut styles = vec![];
+        let mut root = true;
+        walk_document_paths(document, |node, path| {
+            let inlinable = node.accepts_attributes();
+            for (keep, rule) in kept.iter_mut().zip(rules.iter()) {
+                if (root && matches(&rule.selector, &path[..1]))
+                    || (!inlinable && matches(&rule.selector, path))
+                {
+                    *keep = true;
+                }
+            }
+            root = false;
+            if inlinable {
+                styles.push(cascade(path, &rules));
+            }
+        });
+        let kept: Vec<Selector> = rules
+            .iter()
+            .zip(kept)
+            .filter(|(_, keep)| *keep)
+            .map(|(rule, _)| rule.selector.clone())
+            .collect();
+        (styles, kept)
+    };
+    walk_document_mut(
+        &mut Inliner {
+            styles: styles.into_iter(),
+            kept,
+        },
+        document,
+    )
+}
+
+#[cfg(test)]
+mod inline_tests {
+    use super::inline_styles;
+    use crate::{
+        cascade::compute_styles, css::declaration::Declaration, html_document::HtmlDocument,
+        parse::parse_document, render::Render,
+    };
+
+    fn document(css: &str, body: &str) -> HtmlDocument {
+        parse_document(&format!(
+            "<html><head><title>t</title><style>{css}</style></head><body>{body}</body></html>"
+        ))
+        .unwrap()
+    }
+
+    fn computed(doc: &HtmlDocument) -> Vec<Vec<Declaration>> {
+        compute_styles(doc)
+            .into_iter()
+            .map(|style| style.declarations)
+            .collect()
+    }
+
+    #[test]
+    fn inline_rule() {
+        let mut doc = document(
+            ".btn { color: red; } a { color: blue; }",
+            "<a class=\"btn\" href=\"/\">x</a>",
+        );
+        inline_styles(&mut doc);
+        let result = doc.body.content.render();
+        let expected = "<a class=\"btn\" href=\"/\" style=\"color:red;\">x</a>";
+        assert_eq!(result, expected);
+        assert!(!doc.head.render().contains("<style"))
+    }
+
+    #[test]
+    fn inline_important() {
+        let mut doc = document(
+            "div { color: red !important; } #x { color: blue; }",
+            "<div id=\"x\" style=\"width: 1px\"></div>",
+        );
+        inline_styles(&mut doc);
+        let result = doc.body.content.render();
+        let expected = "<div id=\"x\" style=\"width:1px; color:red!important;\">\n\t\n</div>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn inline_keeps_visited() {
+        let mut doc = document(
+            "a:visited { color: purple; } a, :hover { color: blue; }",
+            "<a href=\"/\">x</a>",
+        );
+        inline_styles(&mut doc);
+        let result = doc.head.content.render();
+        let expected = "<style>\n\ta:visited {\n\t\tcolor:purple;\n\t}\n\t:hover {\n\t\tcolor:blue;\n\t}\n</style>";
+        assert_eq!(result, expected);
+        let result = doc.body.content.render();
+        let expected = "<a href=\"/\" style=\"color:blue;\">x</a>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn inline_keeps_root() {
+        let mut doc = document("html { color: red; }", "<div></div>");
+        inline_styles(&mut doc);
+        let result = doc.head.content.render();
+        let expected = "<style>\n\thtml {\n\t\tcolor:red;\n\t}\n</style>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn inline_matches_cascade() {
+        let mut doc = document(
+            "body { font-size: 12px; color: #333; } \
+             ul > li { margin: 0px; } \
+             li:nth-child(odd) { background: #eee; } \
+             .active, #main { color: red; } \
+             #main { width: 100px !important; } \
+             div { width: 50px; } \
+             a:visited { color: purple; }",
+            "<div id=\"main\" style=\"width: 10px; display: block\">\
+                <ul><li class=\"active\">a</li><li>b</li><li><a href=\"/\">c</a></li></ul>\
+             </div>",
+        );
+        let expected = computed(&doc);
+        inline_styles(&mut doc);
+        let result = computed(&doc);
+        assert_eq!(result, expected)
+    }
+}
@@ -1,5 +1,103 @@
+// lets the macros refer to `::html` from inside this crate as well
+extern crate self as html;
+
 pub mod attribute;
+pub mod cascade;
 pub mod css;
 pub mod elements;
+pub mod escape;
 pub mod html_document;
+pub mod inline;
+#[doc(hidden)]
+pub mod macro_support;
+pub mod parse;
+pub mod query;
 pub mod render;
+pub mod tabular;
+pub mod visit;
+
+/// Builds a [`elements::HtmlElement`] from markup.
+///
+/// Tags and attributes have to be known to the crate, except for custom elements such as
+/// `<my-widget>` which become an [`elements::Element`] and `data-*` attributes. Text is written as string literals and
+/// Rust values are interpolated in braces:
+/// - `{expr}` adds anything that converts into a [`elements::HtmlElement`]
+/// - `{for iter}` adds every item of an iterator
+/// - `if` and `match` choose between groups of nodes
+///
+/// Attribute values are literals or `{expr}`.
+/// `<option>` only takes a `value`, which defaults to its text, and only appears in `<select>`.
+/// `<head>` and `<style>` are left out on purpose, documents and stylesheets are built with
+/// [`html_document::HtmlDocument`] and [`css!`].
+/// Several nodes at the top level are wrapped in a [`elements::HtmlElement::ComponentList`].
+///
+/// ```
+/// use html::{html, render::Render};
+///
+/// let title = "Fruit";
+/// let items = ["apple", "pear"];
+/// let elem = html! {
+///     <div id="main" class="a b">
+///         <h1>{title}</h1>
+///         <ul>{for items.iter().map(|item| item.to_string())}</ul>
+///         if items.is_empty() { "nothing" }
+///     </div>
+/// };
+/// assert_eq!(elem.render().lines().next(), Some("<div id=\"main\" class=\"a b\">"));
+/// ```
+///
+/// Unknown tags and attributes do not compile:
+/// ```compile_fail
+/// html::html! { <blink>"hello"</blink> };
+/// ```
+/// ```compile_fail
+/// html::html! { <div colour="red"></div> };
+/// ```
+/// ```compile_fail
+/// html::html! { <div data-userId="1"></div> };
+/// ```
+/// ```compile_fail
+/// html::html! { <div onhover="show()"></div> };
+/// ```
+/// ```compile_fail
+/// html::html! { <option value="a">"A"</option> };
+/// ```
+pub use html_macros::html;
+
+/// Builds a [`css::CssDocument`] from a stylesheet written in css syntax.
+///
+/// Properties, keywords and units have to be known to the crate, selectors may use `>`,
+/// `:visited`, `:nth-child(..)` and lists of simple selectors.
+/// Rust values are interpolated as `{expr}`, anything that converts into a [`css::value::Value`].
+///
+/// Some css does not survive the Rust lexer and is written differently:
+/// - units starting with `e` follow after a space, as in `1.5 em`
+/// - hex colors the lexer rejects are quoted, as in `#"1e1e1e"`
+///
+/// Values of several parts, such as `1px solid #000`, become a [`css::value::Value::Other`].
+///
+/// ```
+/// use html::{css, css::value::{Color, Value}, render::Render};
+///
+/// let accent = Color::Rgb(255, 0, 0);
+/// let style = css! {
+///     .card > img {
+///         border-radius: 4px;
+///         display: none !important;
+///     }
+///     a:visited { color: {accent}; }
+/// };
+/// assert_eq!(
+///     style.render(),
+///     ".card>img {\n\tborder-radius:4px;\n\tdisplay:none!important;\n}\na:visited {\n\tcolor:#ff0000;\n}"
+/// );
+/// ```
+///
+/// Unknown properties and keywords do not compile:
+/// ```compile_fail
+/// html::css! { div { colour: auto; } };
+/// ```
+/// ```compile_fail
+/// html::css! { div { display: blocky; } };
+/// ```
+pub use html_macros::css;
@@ -0,0 +1,1140 @@
+//! Names the `html!` and `css!` macros expand to.
+//! Every element, attribute, property, keyword and unit the macros accept has a function here,
+//! so unknown names fail to compile.
+
+/// Builders for the elements usable as tags
+pub mod tags {
+    use crate::elements::{
+        Abbr, Article, Aside, Audio, Blockquote, Body, Button, Canvas, Caption, Code, Col,
+        Colgroup, Datalist, Div, Em, Fieldset, Figure, Footer, Form, Header, HeaderSize, Headline,
+        Hr, HtmlElement, Img, Input, Label, Legend, Li, Link, Main, Mark, Meta, Nav, Ol, Output,
+        Picture, Pre, Script, Section, Select, SelectOption, Small, Source, Span, Strong, Sub, Sup,
+        Table, TableSection, Td, Textarea, Th, Tr, Track, Ul, Video, A, P,
+    };
+
+    /// `<br>` has no struct of its own
+    pub struct Br;
+
+    impl From<Br> for HtmlElement {
+        fn from(_: Br) -> HtmlElement {
+            HtmlElement::Br
+        }
+    }
+
+    pub fn a() -> A {
+        A::new()
+    }
+    pub fn abbr() -> Abbr {
+        Abbr::new()
+    }
+    pub fn article() -> Article {
+        Article::new()
+    }
+    pub fn aside() -> Aside {
+        Aside::new()
+    }
+    pub fn audio() -> Audio {
+        Audio::new()
+    }
+    pub fn blockquote() -> Blockquote {
+        Blockquote::new()
+    }
+    pub fn body() -> Body {
+        Body::new()
+    }
+    pub fn br() -> Br {
+        Br
+    }
+    pub fn button() -> Button {
+        Button::new()
+    }
+    pub fn canvas() -> Canvas {
+        Canvas::new()
+    }
+    pub fn caption() -> Caption {
+        Caption::new()
+    }
+    pub fn code() -> Code {
+        Code::new()
+    }
+    pub fn col() -> Col {
+        Col::new()
+    }
+    pub fn colgroup() -> Colgroup {
+        Colgroup::new()
+    }
+    pub fn datalist() -> Datalist {
+        Datalist::new()
+    }
+    pub fn div() -> Div {
+        Div::new()
+    }
+    pub fn em() -> Em {
+        Em::new()
+    }
+    pub fn fieldset() -> Fieldset {
+        Fieldset::new()
+    }
+    pub fn figure() -> Figure {
+        Figure::new()
+    }
+    pub fn footer() -> Footer {
+        Footer::new()
+    }
+    pub fn form() -> Form {
+        Form::new()
+    }
+    pub fn h1() -> Headline {
+        Headline::new(HeaderSize::H1)
+    }
+    pub fn h2() -> Headline {
+        Headline::new(HeaderSize::H2)
+    }
+    pub fn h3() -> Headline {
+        Headline::new(HeaderSize::H3)
+    }
+    pub fn h4() -> Headline {
+        Headline::new(HeaderSize::H4)
+    }
+    pub fn h5() -> Headline {
+        Headline::new(HeaderSize::H5)
+    }
+    pub fn h6() -> Headline {
+        Headline::new(HeaderSize::H6)
+    }
+    pub fn header() -> Header {
+        Header::new()
+    }
+    pub fn hr() -> Hr {
+        Hr::new()
+    }
+    pub fn img() -> Img {
+        Img::new()
+    }
+    pub fn input() -> Input {
+        Input::new()
+    }
+    pub fn label() -> Label {
+        Label::new()
+    }
+    pub fn legend() -> Legend {
+        Legend::new()
+    }
+    pub fn li() -> Li {
+        Li::new()
+    }
+    pub fn link() -> Link {
+        Link::new()
+    }
+    pub fn main() -> Main {
+        Main::new()
+    }
+    pub fn mark() -> Mark {
+        Mark::new()
+    }
+    pub fn meta() -> Meta {
+        Meta::new()
+    }
+    pub fn nav() -> Nav {
+        Nav::new()
+    }
+    pub fn ol() -> Ol {
+        Ol::new()
+    }
+    /// Only used inside `<select>`, the value is the only attribute
+    pub fn option<T: Into<String>>(value: T) -> SelectOption {
+        SelectOption::new(&value.into())
+    }
+    pub fn output() -> Output {
+        Output::new()
+    }
+    pub fn p() -> P {
+        P::new()
+    }
+    pub fn picture() -> Picture {
+        Picture::new()
+    }
+    pub fn pre() -> Pre {
+        Pre::new()
+    }
+    pub fn script() -> Script {
+        Script::new()
+    }
+    pub fn section() -> Section {
+        Section::new()
+    }
+    pub fn select() -> Select {
+        Select::new()
+    }
+    pub fn small() -> Small {
+        Small::new()
+    }
+    pub fn source() -> Source {
+        Source::new()
+    }
+    pub fn span() -> Span {
+        Span::new()
+    }
+    pub fn strong() -> Strong {
+        Strong::new()
+    }
+    pub fn sub() -> Sub {
+        Sub::new()
+    }
+    pub fn sup() -> Sup {
+        Sup::new()
+    }
+    pub fn table() -> Table {
+        Table::new()
+    }
+    pub fn tbody() -> TableSection {
+        TableSection::body()
+    }
+    pub fn td() -> Td {
+        Td::new()
+    }
+    pub fn textarea() -> Textarea {
+        Textarea::new()
+    }
+    pub fn tfoot() -> TableSection {
+        TableSection::foot()
+    }
+    pub fn th() -> Th {
+        Th::new()
+    }
+    pub fn thead() -> TableSection {
+        TableSection::head()
+    }
+    pub fn tr() -> Tr {
+        Tr::new()
+    }
+    pub fn track() -> Track {
+        Track::new()
+    }
+    pub fn ul() -> Ul {
+        Ul::new()
+    }
+    pub fn video() -> Video {
+        Video::new()
+    }
+}
+
+/// Constructors for the attributes usable in tags
+pub mod attributes {
+    use crate::attribute::{
+        AriaAttr, Attribute, Dir, Event, Live, Loading, Method, Preload, Scope, TrackKind,
+    };
+
+    pub fn id<T: Into<String>>(id: T) -> Attribute {
+        Attribute::Id(id.into())
+    }
+    /// Whitespace separated list of classes
+    pub fn class<T: AsRef<str>>(classes: T) -> Attribute {
+        Attribute::Class(
+            classes
+                .as_ref()
+                .split_whitespace()
+                .map(str::to_owned)
+                .collect(),
+        )
+    }
+    pub fn src<T: Into<String>>(src: T) -> Attribute {
+        Attribute::Src(src.into())
+    }
+    pub fn style<T: Into<String>>(style: T) -> Attribute {
+        Attribute::Style(style.into())
+    }
+    pub fn href<T: Into<String>>(href: T) -> Attribute {
+        Attribute::Href(href.into())
+    }
+    pub fn rel<T: Into<String>>(rel: T) -> Attribute {
+        Attribute::Rel(rel.into())
+    }
+    pub fn r#type<T: Into<String>>(ty: T) -> Attribute {
+        Attribute::Type(ty.into())
+    }
+    /// `on*` attributes, the macro looks the event up in [`super::events`]
+    pub fn on<T: Into<String>>(event: Event, handler: T) -> Attribute {
+        Attribute::On(event, handler.into())
+    }
+    pub fn value<T: Into<String>>(value: T) -> Attribute {
+        Attribute::Value(value.into())
+    }
+    pub fn title<T: Into<String>>(title: T) -> Attribute {
+        Attribute::Title(title.into())
+    }
+    pub fn name<T: Into<String>>(name: T) -> Attribute {
+        Attribute::Name(name.into())
+    }
+    pub fn action<T: Into<String>>(action: T) -> Attribute {
+        Attribute::Action(action.into())
+    }
+    /// Written as `method={Method::Post}`
+    pub fn method(method: Method) -> Attribute {
+        Attribute::Method(method)
+    }
+    pub fn r#for<T: Into<String>>(id: T) -> Attribute {
+        Attribute::For(id.into())
+    }
+    pub fn placeholder<T: Into<String>>(placeholder: T) -> Attribute {
+        Attribute::Placeholder(placeholder.into())
+    }
+    pub fn list<T: Into<String>>(id: T) -> Attribute {
+        Attribute::List(id.into())
+    }
+    pub fn disabled() -> Attribute {
+        Attribute::Disabled
+    }
+    pub fn required() -> Attribute {
+        Attribute::Required
+    }
+    pub fn min<T: ToString>(min: T) -> Attribute {
+        Attribute::Min(min.to_string())
+    }
+    pub fn max<T: ToString>(max: T) -> Attribute {
+        Attribute::Max(max.to_string())
+    }
+    pub fn step<T: ToString>(step: T) -> Attribute {
+        Attribute::Step(step.to_string())
+    }
+    pub fn accept<T: Into<String>>(accept: T) -> Attribute {
+        Attribute::Accept(accept.into())
+    }
+    pub fn multiple() -> Attribute {
+        Attribute::Multiple
+    }
+    pub fn checked() -> Attribute {
+        Attribute::Checked
+    }
+    pub fn colspan(columns: u32) -> Attribute {
+        Attribute::Colspan(columns)
+    }
+    pub fn rowspan(rows: u32) -> Attribute {
+        Attribute::Rowspan(rows)
+    }
+    /// Written as `scope={Scope::Col}`
+    pub fn scope(scope: Scope) -> Attribute {
+        Attribute::Scope(scope)
+    }
+    pub fn span(columns: u32) -> Attribute {
+        Attribute::Span(columns)
+    }
+    pub fn alt<T: Into<String>>(alt: T) -> Attribute {
+        Attribute::Alt(alt.into())
+    }
+    pub fn width(width: u32) -> Attribute {
+        Attribute::Width(width)
+    }
+    pub fn height(height: u32) -> Attribute {
+        Attribute::Height(height)
+    }
+    pub fn srcset<T: Into<String>>(srcset: T) -> Attribute {
+        Attribute::Srcset(srcset.into())
+    }
+    pub fn sizes<T: Into<String>>(sizes: T) -> Attribute {
+        Attribute::Sizes(sizes.into())
+    }
+    /// Written as `loading={Loading::Lazy}`
+    pub fn loading(loading: Loading) -> Attribute {
+        Attribute::Loading(loading)
+    }
+    pub fn controls() -> Attribute {
+        Attribute::Controls
+    }
+    pub fn autoplay() -> Attribute {
+        Attribute::Autoplay
+    }
+    pub fn muted() -> Attribute {
+        Attribute::Muted
+    }
+    pub fn r#loop() -> Attribute {
+        Attribute::Loop
+    }
+    pub fn poster<T: Into<String>>(poster: T) -> Attribute {
+        Attribute::Poster(poster.into())
+    }
+    /// Written as `preload={Preload::Metadata}`
+    pub fn preload(preload: Preload) -> Attribute {
+        Attribute::Preload(preload)
+    }
+    pub fn media<T: Into<String>>(query: T) -> Attribute {
+        Attribute::Media(query.into())
+    }
+    /// Written as `kind={TrackKind::Subtitles}`
+    pub fn kind(kind: TrackKind) -> Attribute {
+        Attribute::Kind(kind)
+    }
+    pub fn srclang<T: Into<String>>(lang: T) -> Attribute {
+        Attribute::Srclang(lang.into())
+    }
+    pub fn label<T: Into<String>>(label: T) -> Attribute {
+        Attribute::Label(label.into())
+    }
+    pub fn default() -> Attribute {
+        Attribute::Default
+    }
+    pub fn charset<T: Into<String>>(charset: T) -> Attribute {
+        Attribute::Charset(charset.into())
+    }
+    pub fn content<T: Into<String>>(content: T) -> Attribute {
+        Attribute::Content(content.into())
+    }
+    pub fn lang<T: Into<String>>(lang: T) -> Attribute {
+        Attribute::Lang(lang.into())
+    }
+    /// Written as `dir={Dir::Rtl}`
+    pub fn dir(dir: Dir) -> Attribute {
+        Attribute::Dir(dir)
+    }
+    pub fn role<T: Into<String>>(role: T) -> Attribute {
+        Attribute::Role(role.into())
+    }
+    pub fn target<T: Into<String>>(target: T) -> Attribute {
+        Attribute::Target(target.into())
+    }
+    /// `data-*` attributes, the macro checks the key
+    pub fn data<T: Into<String>>(key: &str, value: T) -> Attribute {
+        Attribute::Data(key.to_owned(), value.into())
+    }
+    pub fn aria_label<T: Into<String>>(label: T) -> Attribute {
+        Attribute::Aria(AriaAttr::Label(label.into()))
+    }
+    pub fn aria_labelledby<T: Into<String>>(ids: T) -> Attribute {
+        Attribute::Aria(AriaAttr::LabelledBy(ids.into()))
+    }
+    pub fn aria_describedby<T: Into<String>>(ids: T) -> Attribute {
+        Attribute::Aria(AriaAttr::DescribedBy(ids.into()))
+    }
+    pub fn aria_controls<T: Into<String>>(ids: T) -> Attribute {
+        Attribute::Aria(AriaAttr::Controls(ids.into()))
+    }
+    pub fn aria_current<T: Into<String>>(current: T) -> Attribute {
+        Attribute::Aria(AriaAttr::Current(current.into()))
+    }
+    pub fn aria_hidden(hidden: bool) -> Attribute {
+        Attribute::Aria(AriaAttr::Hidden(hidden))
+    }
+    pub fn aria_expanded(expanded: bool) -> Attribute {
+        Attribute::Aria(AriaAttr::Expanded(expanded))
+    }
+    pub fn aria_pressed(pressed: bool) -> Attribute {
+        Attribute::Aria(AriaAttr::Pressed(pressed))
+    }
+    pub fn aria_selected(selected: bool) -> Attribute {
+        Attribute::Aria(AriaAttr::Selected(selected))
+    }
+    pub fn aria_checked(checked: bool) -> Attribute {
+        Attribute::Aria(AriaAttr::Checked(checked))
+    }
+    pub fn aria_disabled(disabled: bool) -> Attribute {
+        Attribute::Aria(AriaAttr::Disabled(disabled))
+    }
+    /// Written as `aria-live={Live::Polite}`
+    pub fn aria_live(live: Live) -> Attribute {
+        Attribute::Aria(AriaAttr::Live(live))
+    }
+}
+
+/// The events of `on*` attributes by their lowercase names
+#[allow(non_upper_case_globals)]
+pub mod events {
+    use crate::attribute::Event;
+
+    pub const abort: Event = Event::Abort;
+    pub const auxclick: Event = Event::AuxClick;
+    pub const beforeinput: Event = Event::BeforeInput;
+    pub const blur: Event = Event::Blur;
+    pub const cancel: Event = Event::Cancel;
+    pub const canplay: Event = Event::CanPlay;
+    pub const change: Event = Event::Change;
+    pub const click: Event = Event::Click;
+    pub const close: Event = Event::Close;
+    pub const contextmenu: Event = Event::ContextMenu;
+    pub const copy: Event = Event::Copy;
+    pub const cut: Event = Event::Cut;
+    pub const dblclick: Event = Event::DblClick;
+    pub const drag: Event = Event::Drag;
+    pub const dragend: Event = Event::DragEnd;
+    pub const dragenter: Event = Event::DragEnter;
+    pub const dragleave: Event = Event::DragLeave;
+    pub const dragover: Event = Event::DragOver;
+    pub const dragstart: Event = Event::DragStart;
+    pub const drop: Event = Event::Drop;
+    pub const ended: Event = Event::Ended;
+    pub const error: Event = Event::Error;
+    pub const focus: Event = Event::Focus;
+    pub const input: Event = Event::Input;
+    pub const invalid: Event = Event::Invalid;
+    pub const keydown: Event = Event::KeyDown;
+    pub const keypress: Event = Event::KeyPress;
+    pub const keyup: Event = Event::KeyUp;
+    pub const load: Event = Event::Load;
+    pub const loadeddata: Event = Event::LoadedData;
+    pub const loadedmetadata: Event = Event::LoadedMetadata;
+    pub const mousedown: Event = Event::MouseDown;
+    pub const mouseenter: Event = Event::MouseEnter;
+    pub const mouseleave: Event = Event::MouseLeave;
+    pub const mousemove: Event = Event::MouseMove;
+    pub const mouseout: Event = Event::MouseOut;
+    pub const mouseover: Event = Event::MouseOver;
+    pub const mouseup: Event = Event::MouseUp;
+    pub const paste: Event = Event::Paste;
+    pub const pause: Event = Event::Pause;
+    pub const play: Event = Event::Play;
+    pub const playing: Event = Event::Playing;
+    pub const pointercancel: Event = Event::PointerCancel;
+    pub const pointerdown: Event = Event::PointerDown;
+    pub const pointerenter: Event = Event::PointerEnter;
+    pub const pointerleave: Event = Event::PointerLeave;
+    pub const pointermove: Event = Event::PointerMove;
+    pub const pointerout: Event = Event::PointerOut;
+    pub const pointerover: Event = Event::PointerOver;
+    pub const pointerup: Event = Event::PointerUp;
+    pub const reset: Event = Event::Reset;
+    pub const resize: Event = Event::Resize;
+    pub const scroll: Event = Event::Scroll;
+    pub const scrollend: Event = Event::ScrollEnd;
+    pub const select: Event = Event::Select;
+    pub const submit: Event = Event::Submit;
+    pub const timeupdate: Event = Event::TimeUpdate;
+    pub const toggle: Event = Event::Toggle;
+    pub const touchcancel: Event = Event::TouchCancel;
+    pub const touchend: Event = Event::TouchEnd;
+    pub const touchmove: Event = Event::TouchMove;
+    pub const touchstart: Event = Event::TouchStart;
+    pub const volumechange: Event = Event::VolumeChange;
+    pub const wheel: Event = Event::Wheel;
+}
+
+/// Properties usable in `css!` declarations
+pub mod properties {
+    use crate::css::property::{
+        Border, Color, Direction, Flex, Font, Margin, Padding, Position, Property, Size,
+    };
+
+    pub fn text_align() -> Property {
+        Property::TextAlign
+    }
+    pub fn cursor() -> Property {
+        Property::Cursor
+    }
+    pub fn display() -> Property {
+        Property::Display
+    }
+    pub fn overflow() -> Property {
+        Property::Overflow
+    }
+    pub fn float() -> Property {
+        Property::Float
+    }
+    pub fn line_height() -> Property {
+        Property::LineHeight
+    }
+    pub fn position() -> Property {
+        Position::Position.into()
+    }
+    pub fn top() -> Property {
+        Position::Top.into()
+    }
+    pub fn bottom() -> Property {
+        Position::Bottom.into()
+    }
+    pub fn left() -> Property {
+        Position::Left.into()
+    }
+    pub fn right() -> Property {
+        Position::Right.into()
+    }
+    pub fn background() -> Property {
+        Color::Background.into()
+    }
+    pub fn color() -> Property {
+        Color::Color.into()
+    }
+    pub fn width() -> Property {
+        Size::Width.into()
+    }
+    pub fn max_width() -> Property {
+        Size::MaxWidth.into()
+    }
+    pub fn height() -> Property {
+        Size::Height.into()
+    }
+    pub fn max_height() -> Property {
+        Size::MaxHeight.into()
+    }
+    pub fn align_content() -> Property {
+        Flex::AlignContent.into()
+    }
+    pub fn align_items() -> Property {
+        Flex::AlignItems.into()
+    }
+    pub fn align_self() -> Property {
+        Flex::AlignSelf.into()
+    }
+    pub fn flex_wrap() -> Property {
+        Flex::FlexWrap.into()
+    }
+    pub fn flex_direction() -> Property {
+        Flex::FlexDirection.into()
+    }
+    pub fn gap() -> Property {
+        Flex::Gap.into()
+    }
+    pub fn justify_content() -> Property {
+        Flex::JustifyContent.into()
+    }
+    pub fn font_family() -> Property {
+        Font::Family.into()
+    }
+    pub fn font_weight() -> Property {
+        Font::Weight.into()
+    }
+    pub fn font_size() -> Property {
+        Font::Size.into()
+    }
+    pub fn border_top() -> Property {
+        Border::Side(Direction::Top).into()
+    }
+    pub fn border_bottom() -> Property {
+        Border::Side(Direction::Bottom).into()
+    }
+    pub fn border_left() -> Property {
+        Border::Side(Direction::Left).into()
+    }
+    pub fn border_right() -> Property {
+        Border::Side(Direction::Right).into()
+    }
+    pub fn border() -> Property {
+        Border::Side(Direction::All).into()
+    }
+    pub fn border_color() -> Property {
+        Border::Color.into()
+    }
+    pub fn border_style() -> Property {
+        Border::Style.into()
+    }
+    pub fn border_radius() -> Property {
+        Border::Radius.into()
+    }
+    pub fn border_collapse() -> Property {
+        Border::Collapse.into()
+    }
+    pub fn margin_top() -> Property {
+        Margin {
+            dir: Direction::Top,
+        }
+        .into()
+    }
+    pub fn margin_bottom() -> Property {
+        Margin {
+            dir: Direction::Bottom,
+        }
+        .into()
+    }
+    pub fn margin_left() -> Property {
+        Margin {
+            dir: Direction::Left,
+        }
+        .into()
+    }
+    pub fn margin_right() -> Property {
+        Margin {
+            dir: Direction::Right,
+        }
+        .into()
+    }
+    pub fn margin() -> Property {
+        Margin {
+            dir: Direction::All,
+        }
+        .into()
+    }
+    pub fn padding_top() -> Property {
+        Padding {
+            dir: Direction::Top,
+        }
+        .into()
+    }
+    pub fn padding_bottom() -> Property {
+        Padding {
+            dir: Direction::Bottom,
+        }
+        .into()
+    }
+    pub fn padding_left() -> Property {
+        Padding {
+            dir: Direction::Left,
+        }
+        .into()
+    }
+    pub fn padding_right() -> Property {
+        Padding {
+            dir: Direction::Right,
+        }
+        .into()
+    }
+    pub fn padding() -> Property {
+        Padding {
+            dir: Direction::All,
+        }
+        .into()
+    }
+}
+
+/// Keywords usable as `css!` values
+pub mod keywords {
+    use crate::css::value::Keyword;
+
+    pub fn center() -> Keyword {
+        Keyword::Center
+    }
+    pub fn flex_start() -> Keyword {
+        Keyword::FlexStart
+    }
+    pub fn pointer() -> Keyword {
+        Keyword::Pointer
+    }
+    pub fn collapse() -> Keyword {
+        Keyword::Collapse
+    }
+    pub fn auto() -> Keyword {
+        Keyword::Auto
+    }
+    pub fn right() -> Keyword {
+        Keyword::Right
+    }
+    pub fn left() -> Keyword {
+        Keyword::Left
+    }
+    pub fn flex() -> Keyword {
+        Keyword::Flex
+    }
+    pub fn space_around() -> Keyword {
+        Keyword::SpaceAround
+    }
+    pub fn wrap() -> Keyword {
+        Keyword::Wrap
+    }
+    pub fn stretch() -> Keyword {
+        Keyword::Stretch
+    }
+    pub fn none() -> Keyword {
+        Keyword::Non
+    }
+    pub fn fixed() -> Keyword {
+        Keyword::Fixed
+    }
+    pub fn relative() -> Keyword {
+        Keyword::Relative
+    }
+    pub fn block() -> Keyword {
+        Keyword::Block
+    }
+    pub fn solid() -> Keyword {
+        Keyword::Solid
+    }
+    pub fn column() -> Keyword {
+        Keyword::Column
+    }
+    pub fn flex_end() -> Keyword {
+        Keyword::FlexEnd
+    }
+    pub fn bold() -> Keyword {
+        Keyword::Bold
+    }
+    pub fn transparent() -> Keyword {
+        Keyword::Transparent
+    }
+    pub fn hidden() -> Keyword {
+        Keyword::Hidden
+    }
+    pub fn flow_root() -> Keyword {
+        Keyword::FlowRoot
+    }
+    pub fn nowrap() -> Keyword {
+        Keyword::NoWrap
+    }
+}
+
+/// Units of `css!` measurements, `%` is `percent`
+pub mod units {
+    use crate::css::value::Unit;
+
+    pub fn pt() -> Unit {
+        Unit::Pt
+    }
+    pub fn percent() -> Unit {
+        Unit::Percent
+    }
+    pub fn em() -> Unit {
+        Unit::Em
+    }
+    pub fn vh() -> Unit {
+        Unit::Vh
+    }
+    pub fn px() -> Unit {
+        Unit::Px
+    }
+}
+
+#[cfg(test)]
+mod html_macro_tests {
+    use crate::{
+        attribute::{
+            AriaAttr, Attribute, Dir, Event, Live, Loading, Method, Preload, Scope, TrackKind,
+        },
+        elements::{
+            Abbr, Build, Button, Caption, Div, Element, Form, FormControl, HeaderSize, Headline,
+            Hr, HtmlElement, Img, Input, Label, Li, MediaElement, Nav, Picture, Select,
+            SelectOption, Source, Strong, Table, TableCell, TableSection, Td, Textarea, Th, Tr,
+            Track, Ul, Video, WithAttributes, WithChildren, A, P,
+        },
+        html,
+    };
+
+    #[test]
+    fn macro_element() {
+        let title = "title";
+        let result = html! {
+            <div id="main" class="a b">
+                <h1>{title}</h1>
+                "text"
+            </div>
+        };
+        let expected = Div::new()
+            .id("main")
+            .attr(Attribute::Class(vec!["a".to_owned(), "b".to_owned()]))
+            .child(Headline::new(HeaderSize::H1).text("title"))
+            .text("text")
+            .build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn macro_void_and_attributes() {
+        let src = "a.png".to_owned();
+        let result = html! { <img src={src} style="width: 1px" /> };
+        let expected = Img::new().src("a.png").style("width: 1px").build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn macro_iterator() {
+        let items = ["a", "b"];
+        let result = html! {
+            <ul>
+                <li>"first"</li>
+                {for items.iter().map(|item| A::new().href(item).text(item))}
+            </ul>
+        };
+        let expected = Ul::new()
+            .item(Li::new().text("first"))
+            .child(A::new().href("a").text("a"))
+            .child(A::new().href("b").text("b"))
+            .build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn macro_select() {
+        let selected = "b";
+        let result = html! {
+            <select name="letter">
+                <option value="a">"A"</option>
+                <option value={selected}><strong>"B"</strong></option>
+                <option>"c"</option>
+            </select>
+        };
+        let expected = Select::new()
+            .attr(Attribute::Name("letter".to_owned()))
+            .option(SelectOption::new("a").text("A"))
+            .option(SelectOption::new("b").child(Strong::new().text("B")))
+            .option(SelectOption::new("c").text("c"))
+            .build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn macro_if() {
+        let render = |logged_in: bool| {
+            html! {
+                <div>
+                    if logged_in { <a href="/logout">"logout"</a> } else { "guest" }
+                    if !logged_in { <br/> }
+                </div>
+            }
+        };
+        let result = render(true);
+        let expected = Div::new()
+            .child(A::new().href("/logout").text("logout"))
+            .child(HtmlElement::default())
+            .build();
+        assert_eq!(result, expected);
+        let result = render(false);
+        let expected = Div::new().text("guest").child(HtmlElement::Br).build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn macro_match() {
+        let render = |count: usize| {
+            html! {
+                match count {
+                    0 => "none",
+                    1 => { "one" }
+                    n if n > 10 => { "many" "!" }
+                    _ => <br/>,
+                }
+            }
+        };
+        assert_eq!(render(0), HtmlElement::from("none"));
+        assert_eq!(render(1), HtmlElement::from("one"));
+        assert_eq!(
+            render(11),
+            HtmlElement::ComponentList(vec!["many".into(), "!".into()])
+        );
+    }
+
+    #[test]
+    fn macro_text_level() {
+        let result =
+            html! { <p>"a " <abbr title="HyperText Markup Language">"HTML"</abbr><hr/></p> };
+        let expected = P::new()
+            .text("a ")
+            .child(Abbr::new().title("HyperText Markup Language").text("HTML"))
+            .child(Hr::new())
+            .build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn macro_form() {
+        let result = html! {
+            <form action="/search" method={Method::Get}>
+                <label for="q">"Search"</label>
+                <input id="q" name="q" list="recent" required/>
+                <textarea name="note" disabled></textarea>
+                <button type="submit">"Go"</button>
+            </form>
+        };
+        let expected = Form::new()
+            .action("/search")
+            .method(Method::Get)
+            .child(Label::new().for_id("q").text("Search"))
+            .child(Input::new().id("q").name("q").list("recent").required())
+            .child(Textarea::new().name("note").disabled())
+            .child(Button::new().button_type("submit").text("Go"))
+            .build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn macro_lang_dir() {
+        let result = html! { <p lang="ar" dir={Dir::Rtl}>"مرحبا"</p> };
+        let expected = P::new()
+            .attr(Attribute::Lang("ar".to_owned()))
+            .attr(Attribute::Dir(Dir::Rtl))
+            .text("مرحبا")
+            .build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn macro_open_attributes() {
+        let result = html! {
+            <nav role="navigation" aria-label="Main" aria-live={Live::Polite} data-section-id={7.to_string()} data-active>
+                <a href="/" target="_blank" aria-current="page" aria-hidden=false>"Home"</a>
+            </nav>
+        };
+        let expected = Nav::new()
+            .attr(Attribute::Role("navigation".to_owned()))
+            .attr(Attribute::Aria(AriaAttr::Label("Main".to_owned())))
+            .attr(Attribute::Aria(AriaAttr::Live(Live::Polite)))
+            .attr(Attribute::Data("section-id".to_owned(), "7".to_owned()))
+            .attr(Attribute::Data("active".to_owned(), "".to_owned()))
+            .child(
+                A::new()
+                    .href("/")
+                    .attr(Attribute::Target("_blank".to_owned()))
+                    .attr(Attribute::Aria(AriaAttr::Current("page".to_owned())))
+                    .attr(Attribute::Aria(AriaAttr::Hidden(false)))
+                    .text("Home"),
+            )
+            .build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn macro_event_handlers() {
+        let result = html! {
+            <form onsubmit="return check()" onkeydown={format!("key({})", 1)}>
+                <button ondblclick="zoom('in')">"Zoom"</button>
+            </form>
+        };
+        let expected = Form::new()
+            .attr(Attribute::On(Event::Submit, "return check()".to_owned()))
+            .attr(Attribute::On(Event::KeyDown, "key(1)".to_owned()))
+            .child(
+                Button::new()
+                    .attr(Attribute::On(Event::DblClick, "zoom('in')".to_owned()))
+                    .text("Zoom"),
+            )
+            .build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn macro_media() {
+        let result = html! {
+            <video controls muted loop preload={Preload::Metadata} poster="p.jpg">
+                <source src="a.webm" type="video/webm"/>
+                <track kind={TrackKind::Subtitles} srclang="en" label="English" default/>
+                <picture>
+                    <source media="(min-width: 600px)" srcset="big.jpg" sizes="50vw"/>
+                    <img src="small.jpg" alt="Poster" width=320 height=180 loading={Loading::Lazy}/>
+                </picture>
+            </video>
+        };
+        let expected = Video::new()
+            .controls()
+            .muted()
+            .looped()
+            .preload(Preload::Metadata)
+            .poster("p.jpg")
+            .source(Source::new().src("a.webm").source_type("video/webm"))
+            .track(
+                Track::new()
+                    .kind(TrackKind::Subtitles)
+                    .srclang("en")
+                    .label("English")
+                    .default_track(),
+            )
+            .child(
+                Picture::new()
+                    .source(
+                        Source::new()
+                            .media("(min-width: 600px)")
+                            .srcset("big.jpg")
+                            .sizes("50vw"),
+                    )
+                    .child(
+                        Img::new()
+                            .src("small.jpg")
+                            .alt("Poster")
+                            .width(320)
+                            .height(180)
+                            .loading(Loading::Lazy),
+                    ),
+            )
+            .build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn macro_table() {
+        let result = html! {
+            <table>
+                <caption>"Scores"</caption>
+                <thead><tr><th scope={Scope::Col}>"Name"</th><th>"Score"</th></tr></thead>
+                <tbody><tr><td colspan=2>"none"</td></tr></tbody>
+            </table>
+        };
+        let expected = Table::new()
+            .caption(Caption::new().text("Scores"))
+            .section(
+                TableSection::head().row(
+                    Tr::new()
+                        .child(Th::new().scope(Scope::Col).text("Name"))
+                        .child(Th::new().text("Score")),
+                ),
+            )
+            .section(TableSection::body().row(Tr::new().child(Td::new().colspan(2).text("none"))))
+            .build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn macro_custom_element() {
+        let result = html! { <my-widget id="w">"custom"</my-widget> };
+        let expected = Element::new("my-widget").id("w").text("custom").build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn macro_top_level_list() {
+        let result = html! { <br/> "text" };
+        let expected = HtmlElement::ComponentList(vec![HtmlElement::Br, "text".into()]);
+        assert_eq!(result, expected)
+    }
+}
+
+#[cfg(test)]
+mod css_macro_tests {
+    use crate::{
+        css,
+        css::{
+            property::Property,
+            value::{Color, Keyword, Unit, Value},
+        },
+        parse::parse_css,
+    };
+
+    #[test]
+    fn macro_matches_parser() {
+        let result = css! {
+            .card > img {
+                border-radius: 4px;
+                display: none !important;
+            }
+            #main, h1, * { margin-top: -2 em; width: 50%; }
+            li:nth-child(2n+1) { background: #eee; }
+            a:visited { color: rgba(1, 2, 3, 0.5); }
+            :root { --main-bg: #1a2b3c; }
+            div { background: var(--main-bg); font-family: "Helvetica"; }
+        };
+        let expected = parse_css(
+            ".card > img { border-radius: 4px; display: none !important; }
+            #main, h1, * { margin-top: -2em; width: 50%; }
+            li:nth-child(2n+1) { background: #eee; }
+            a:visited { color: rgba(1, 2, 3, 0.5); }
+            :root { --main-bg: #1a2b3c; }
+            div { background: var(--main-bg); font-family: \"Helvetica\"; }",
+        )
+        .unwrap();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn macro_interpolation() {
+        let width = (80.0, Unit::Vh);
+        let result = css! { div { width: {width}; color: {Color::Rgb(1, 2, 3)}; } };
+        let decls = &result.decls[0].decls;
+        assert_eq!(decls[0].value, Value::Measurement(80.0, Unit::Vh));
+        assert_eq!(decls[1].value, Value::Color(Color::Rgb(1, 2, 3)))
+    }
+
+    #[test]
+    fn macro_compound_value() {
+        let result = css! {
+            p { border: 1px solid #"1e1e1e"; line-height: 1.5; font-family: "Helvetica", {Keyword::Auto}; }
+        };
+        let values: Vec<(Property, Value)> = result.decls[0]
+            .decls
+            .iter()
+            .map(|decl| (decl.property.clone(), decl.value.clone()))
+            .collect();
+        let expected = vec![
+            (
+                "border".parse().unwrap(),
+                Value::Other("1px solid #1e1e1e".to_owned()),
+            ),
+            (Property::LineHeight, Value::Other("1.5".to_owned())),
+            (
+                "font-family".parse().unwrap(),
+                Value::Other("\"Helvetica\", auto".to_owned()),
+            ),
+        ];
+        assert_eq!(values, expected)
+    }
+}
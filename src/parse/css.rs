@@ -0,0 +1,546 @@
+use super::ParseError;
+use crate::css::{
+    block::CssBlock,
+    declaration::Declaration,
+    property::Property,
+    selector::{ChildSelector, Selector, SubSelector, TopSelector},
+    value::Value,
+    CssDocument,
+};
+use std::{rc::Rc, str::FromStr};
+
+/// Replaces comments by spaces, so byte offsets into the source stay valid
+fn strip_comments(src: &str) -> Result<String, ParseError> {
+    let mut stripped = String::with_capacity(src.len());
+    let mut rest = src;
+    while let Some(start) = rest.find("/*") {
+        stripped.push_str(&rest[..start]);
+        let end = match rest[start + 2..].find("*/") {
+            Some(end) => start + 2 + end + 2,
+            None => {
+                let offset = src.len() - rest.len() + start;
+                return Err(ParseError::at(src, offset, "unterminated comment"));
+            }
+        };
+        stripped.push_str(&" ".repeat(rest[start..end].len()));
+        rest = &rest[end..];
+    }
+    stripped.push_str(rest);
+    Ok(stripped)
+}
+
+/// Splits `s` at every top level `sep`, ignoring separators in strings and parentheses.
+/// Returns each part together with its byte offset in `s`.
+fn split_top_level(s: &str, sep: char) -> Vec<(usize, &str)> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut quote = None;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => (),
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, c) if c == sep && depth == 0 => {
+                parts.push((start, &s[start..i]));
+                start = i + c.len_utf8();
+            }
+            _ => (),
+        }
+    }
+    parts.push((start, &s[start..]));
+    parts
+}
+
+fn parse_child_selector(s: &str) -> Option<ChildSelector> {
+    let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+    match s.as_str() {
+        "odd" => return Some(ChildSelector::Odd),
+        "even" => return Some(ChildSelector::Even),
+        _ => (),
+    }
+    let (a, b) = match s.split_once('n') {
+        Some((a, b)) => {
+            let a = match a {
+                "" | "+" => 1,
+                "-" => -1,
+                a => a.parse().ok()?,
+            };
+            (a, b)
+        }
+        None => (0, s.as_str()),
+    };
+    let b = b.strip_prefix('+').unwrap_or(b);
+    let b = if b.is_empty() { 0 } else { b.parse().ok()? };
+    Some(ChildSelector::AnPlusB(a, b))
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
+
+/// Parses a single simple selector, returning it and the remaining input
+fn parse_top(s: &str) -> Option<(TopSelector, &str)> {
+    let ident = |s: &str| -> Option<(String, usize)> {
+        let len = s.find(|c| !is_ident_char(c)).unwrap_or(s.len());
+        (len > 0).then(|| (s[..len].to_owned(), len))
+    };
+    if let Some(rest) = s.strip_prefix('*') {
+        return Some((TopSelector::All, rest));
+    }
+    let (prefix, rest) = s.split_at(s.chars().next()?.len_utf8());
+    let (top, len) = match prefix {
+        "." => ident(rest).map(|(class, len)| (TopSelector::Class(class), len))?,
+        "#" => ident(rest).map(|(id, len)| (TopSelector::Id(id), len))?,
+        ":" => ident(rest).map(|(pseudo, len)| (TopSelector::Pseudo(pseudo), len))?,
+        _ => return ident(s).map(|(tag, len)| (TopSelector::Tag(tag), &s[len..])),
+    };
+    Some((top, &rest[len..]))
+}
+
+fn parse_sub(s: &str) -> Option<SubSelector> {
+    if let Some(child) = s.strip_prefix('>') {
+        return Some(SubSelector::ChildCombinator(Rc::new(parse_single(child)?)));
+    }
+    if s == ":visited" {
+        return Some(SubSelector::Visited);
+    }
+    let child = s.strip_prefix(":nth-child(")?.strip_suffix(')')?;
+    parse_child_selector(child).map(SubSelector::NthChild)
+}
+
+fn parse_single(s: &str) -> Option<Selector> {
+    let (top, rest) = parse_top(s.trim())?;
+    let rest = rest.trim();
+    let sub = if rest.is_empty() {
+        None
+    } else {
+        Some(parse_sub(rest)?)
+    };
+    Some(Selector { top, sub })
+}
+
+impl FromStr for Selector {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Selector, ParseError> {
+        let parts = split_top_level(s, ',');
+        let selector = if parts.len() == 1 {
+            parse_single(s)
+        } else {
+            parts
+                .iter()
+                .map(|(_, part)| match parse_single(part) {
+                    Some(Selector { top, sub: None }) => Some(top),
+                    _ => None,
+                })
+                .collect::<Option<Vec<TopSelector>>>()
+                .map(|tops| TopSelector::Multiple(tops).into())
+        };
+        selector.ok_or_else(|| {
+            let offset = s.len() - s.trim_start().len();
+            ParseError::at(s, offset, &format!("unsupported selector `{}`", s.trim()))
+        })
+    }
+}
+
+impl FromStr for Declaration {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Declaration, ParseError> {
+        let (property, value) = s
+            .split_once(':')
+            .ok_or_else(|| ParseError::at(s, 0, "expected `:` in declaration"))?;
+        let property = property.trim();
+        let property =
+            Property::from_str(property).unwrap_or_else(|_| Property::Other(property.to_owned()));
+        let value = value.trim();
+        let (value, important) = match value.rfind('!') {
+            Some(bang) if value[bang + 1..].trim().eq_ignore_ascii_case("important") => {
+                (value[..bang].trim(), true)
+            }
+            _ => (value, false),
+        };
+        let value = match Value::from_str(value) {
+            Ok(value) => value,
+            Err(never) => match never {},
+        };
+        Ok(Declaration {
+            property,
+            value,
+            important,
+        })
+    }
+}
+
+/// Parses the `;` separated declarations in `stripped[start..end]`, reporting errors in `src`.
+/// With `skipped`, malformed declarations are collected there instead of failing.
+fn declarations(
+    src: &str,
+    stripped: &str,
+    start: usize,
+    end: usize,
+    mut skipped: Option<&mut Vec<String>>,
+) -> Result<Vec<Declaration>, ParseError> {
+    let mut decls = vec![];
+    for (offset, decl) in split_top_level(&stripped[start..end], ';') {
+        if decl.trim().is_empty() {
+            continue;
+        }
+        let decl_start = start + offset + decl.len() - decl.trim_start().len();
+        match (Declaration::from_str(decl), skipped.as_deref_mut()) {
+            (Ok(decl), _) => decls.push(decl),
+            (Err(_), Some(skipped)) => {
+                let decl_end = start + offset + decl.trim_end().len();
+                skipped.push(src[decl_start..decl_end].to_owned());
+            }
+            (Err(err), None) => return Err(ParseError::at(src, decl_start, &err.message)),
+        }
+    }
+    Ok(decls)
+}
+
+/// Parses a list of declarations without selector and braces, e.g. the value of a `style` attribute
+pub fn parse_declarations(src: &str) -> Result<Vec<Declaration>, ParseError> {
+    let stripped = strip_comments(src)?;
+    declarations(src, &stripped, 0, stripped.len(), None)
+}
+
+/// The end of the at-rule starting at `start`, after its `;` or its block including nested ones
+fn at_rule_end(src: &str, stripped: &str, start: usize) -> Result<usize, ParseError> {
+    let mut depth = 0;
+    let mut quote = None;
+    for (i, c) in stripped[start..].char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => (),
+            (None, '"' | '\'') => quote = Some(c),
+            (None, ';') if depth == 0 => return Ok(start + i + 1),
+            (None, '{') => depth += 1,
+            (None, '}') if depth == 0 => {
+                return Err(ParseError::at(src, start + i, "unexpected `}`"))
+            }
+            (None, '}') => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(start + i + 1);
+                }
+            }
+            _ => (),
+        }
+    }
+    Err(ParseError::at(src, start, "unterminated at-rule"))
+}
+
+/// Parses a stylesheet.
+/// Unknown properties and values are kept as [`Property::Other`] and [`Value::Other`].
+/// At-rules, rules whose selector can not be represented, such as `nav a`, and malformed
+/// declarations are skipped, see [`parse_css_partial`] to get them back.
+pub fn parse_css(src: &str) -> Result<CssDocument, ParseError> {
+    parse_css_partial(src).map(|(document, _)| document)
+}
+
+/// Parses a stylesheet like [`parse_css`], also returning the source of every skipped rule and
+/// declaration
+pub fn parse_css_partial(src: &str) -> Result<(CssDocument, Vec<String>), ParseError> {
+    let stripped = strip_comments(src)?;
+    let mut blocks = vec![];
+    let mut skipped = vec![];
+    let mut pos = 0;
+    loop {
+        let rest = &stripped[pos..];
+        let start = pos + rest.len() - rest.trim_start().len();
+        if start == stripped.len() {
+            break;
+        }
+        if stripped[start..].starts_with('@') {
+            pos = at_rule_end(src, &stripped, start)?;
+            skipped.push(src[start..pos].to_owned());
+            continue;
+        }
+        let open = match stripped[start..].find(['{', '}']) {
+            Some(i) if stripped[start + i..].starts_with('{') => start + i,
+            Some(i) => return Err(ParseError::at(src, start + i, "unexpected `}`")),
+            None => return Err(ParseError::at(src, start, "expected `{`")),
+        };
+        let close = match stripped[open + 1..].find(['{', '}']).map(|i| open + 1 + i) {
+            Some(close) if stripped[close..].starts_with('}') => close,
+            Some(nested) => return Err(ParseError::at(src, nested, "unexpected `{`")),
+            None => return Err(ParseError::at(src, open, "unterminated block")),
+        };
+
+        pos = close + 1;
+        match Selector::from_str(&stripped[start..open]) {
+            Ok(selector) => {
+                let decls = declarations(src, &stripped, open + 1, close, Some(&mut skipped))?;
+                blocks.push(CssBlock { selector, decls });
+            }
+            Err(_) => skipped.push(src[start..pos].to_owned()),
+        }
+    }
+    Ok((CssDocument { decls: blocks }, skipped))
+}
+
+impl FromStr for CssDocument {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<CssDocument, ParseError> {
+        parse_css(s)
+    }
+}
+
+#[cfg(test)]
+mod parse_css_tests {
+    use super::{parse_css, parse_css_partial, parse_declarations};
+    use crate::{
+        css::{
+            block::CssBlock,
+            declaration::Declaration,
+            property::{Border, Color as ColorProp, Direction, Margin, Property, Size},
+            selector::{ChildSelector, Selector, SubSelector, TopSelector},
+            value::{Color, Keyword, Unit, Value},
+            CssDocument,
+        },
+        render::{Render, RenderOptions},
+    };
+    use std::{rc::Rc, str::FromStr};
+
+    fn example_document() -> CssDocument {
+        CssDocument {
+            decls: vec![
+                CssBlock {
+                    selector: Selector {
+                        top: TopSelector::Class("card".to_owned()),
+                        sub: Some(SubSelector::ChildCombinator(Rc::new(
+                            TopSelector::Tag("img".to_owned()).into(),
+                        ))),
+                    },
+                    decls: vec![
+                        (Border::Radius.into(), (4.0, Unit::Px).into()).into(),
+                        Declaration {
+                            property: Property::Display,
+                            value: Keyword::Non.into(),
+                            important: true,
+                        },
+                    ],
+                },
+                CssBlock {
+                    selector: TopSelector::Multiple(vec![
+                        TopSelector::Id("main".to_owned()),
+                        TopSelector::All,
+                    ])
+                    .into(),
+                    decls: vec![
+                        (ColorProp::Color.into(), Color::Rgb(255, 0, 16).into()).into(),
+                        (Property::Var("gap".to_owned()), Value::Str("x".to_owned())).into(),
+                    ],
+                },
+                CssBlock {
+                    selector: Selector {
+                        top: TopSelector::Tag("tr".to_owned()),
+                        sub: Some(ChildSelector::AnPlusB(2, 1).into()),
+                    },
+                    decls: vec![(Size::Width.into(), Value::Var("w".to_owned())).into()],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn parse_block() {
+        let result = parse_css("a:visited { color: #fff; }").unwrap();
+        let expected = CssDocument {
+            decls: vec![CssBlock {
+                selector: Selector {
+                    top: TopSelector::Tag("a".to_owned()),
+                    sub: Some(SubSelector::Visited),
+                },
+                decls: vec![(ColorProp::Color.into(), Color::Rgb(255, 255, 255).into()).into()],
+            }],
+        };
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_important_and_comments() {
+        let result =
+            parse_css("/* a comment */ .x { display : none ! important /* why */ }").unwrap();
+        let expected = CssDocument {
+            decls: vec![CssBlock {
+                selector: TopSelector::Class("x".to_owned()).into(),
+                decls: vec![Declaration {
+                    property: Property::Display,
+                    value: Keyword::Non.into(),
+                    important: true,
+                }],
+            }],
+        };
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_fallbacks() {
+        let result = parse_css("div { grid-area: main; border: 1px solid red; }").unwrap();
+        let expected = CssDocument {
+            decls: vec![CssBlock {
+                selector: TopSelector::Tag("div".to_owned()).into(),
+                decls: vec![
+                    (
+                        Property::Other("grid-area".to_owned()),
+                        Value::Other("main".to_owned()),
+                    )
+                        .into(),
+                    (
+                        Border::Side(crate::css::property::Direction::All).into(),
+                        Value::Other("1px solid red".to_owned()),
+                    )
+                        .into(),
+                ],
+            }],
+        };
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_string_with_semicolon() {
+        let result = parse_css("p { --content: \"a;b\"; }").unwrap();
+        let expected = CssDocument {
+            decls: vec![CssBlock {
+                selector: TopSelector::Tag("p".to_owned()).into(),
+                decls: vec![(
+                    Property::Var("content".to_owned()),
+                    Value::Str("a;b".to_owned()),
+                )
+                    .into()],
+            }],
+        };
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_nth_child_forms() {
+        let cases = [
+            ("odd", ChildSelector::Odd),
+            ("even", ChildSelector::Even),
+            ("3", ChildSelector::AnPlusB(0, 3)),
+            ("n", ChildSelector::AnPlusB(1, 0)),
+            ("-n+3", ChildSelector::AnPlusB(-1, 3)),
+            ("2n - 1", ChildSelector::AnPlusB(2, -1)),
+            ("2n+-1", ChildSelector::AnPlusB(2, -1)),
+        ];
+        for (src, expected) in cases {
+            let result = Selector::from_str(&format!("li:nth-child({src})")).unwrap();
+            assert_eq!(result.sub, Some(SubSelector::NthChild(expected)))
+        }
+    }
+
+    #[test]
+    fn roundtrip_pretty() {
+        let rendered = example_document().render();
+        let result = parse_css(&rendered).unwrap();
+        assert_eq!(result, example_document())
+    }
+
+    #[test]
+    fn roundtrip_minified() {
+        let rendered = example_document().render_with(&RenderOptions::minified());
+        let result = parse_css(&rendered).unwrap();
+        assert_eq!(result, example_document())
+    }
+
+    #[test]
+    fn parse_mixed_stylesheet() {
+        let src = "@import url(\"a;b.css\");\na { color: #fff; }\n@media print { a { color: #000; } }\nnav a { color: #000; }\n@font-face { font-family: x; }\np { color: #000; }";
+        let result = parse_css_partial(src).unwrap();
+        let expected = (
+            CssDocument {
+                decls: vec![
+                    CssBlock {
+                        selector: TopSelector::Tag("a".to_owned()).into(),
+                        decls: vec![
+                            (ColorProp::Color.into(), Color::Rgb(255, 255, 255).into()).into()
+                        ],
+                    },
+                    CssBlock {
+                        selector: TopSelector::Tag("p".to_owned()).into(),
+                        decls: vec![(ColorProp::Color.into(), Color::Rgb(0, 0, 0).into()).into()],
+                    },
+                ],
+            },
+            vec![
+                "@import url(\"a;b.css\");".to_owned(),
+                "@media print { a { color: #000; } }".to_owned(),
+                "nav a { color: #000; }".to_owned(),
+                "@font-face { font-family: x; }".to_owned(),
+            ],
+        );
+        assert_eq!(result, expected);
+        assert_eq!(parse_css(src).unwrap(), expected.0)
+    }
+
+    #[test]
+    fn parse_skips_malformed_declaration() {
+        let result = parse_css_partial("a {\n  color #000;\n  margin-top: 1px;\n}").unwrap();
+        let expected = (
+            CssDocument {
+                decls: vec![CssBlock {
+                    selector: TopSelector::Tag("a".to_owned()).into(),
+                    decls: vec![(
+                        Margin {
+                            dir: Direction::Top,
+                        }
+                        .into(),
+                        (1.0, Unit::Px).into(),
+                    )
+                        .into()],
+                }],
+            },
+            vec!["color #000".to_owned()],
+        );
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn error_unterminated_at_rule() {
+        let result =
+            parse_css("a { color: #fff; }\n@media print { a { color: #000; }").unwrap_err();
+        assert_eq!((result.line, result.column), (2, 1));
+        assert_eq!(result.message, "unterminated at-rule")
+    }
+
+    #[test]
+    fn error_unterminated_block() {
+        let result = parse_css("a {\n color: #000;").unwrap_err();
+        assert_eq!((result.line, result.column), (1, 3));
+        assert_eq!(result.message, "unterminated block")
+    }
+
+    #[test]
+    fn error_missing_colon() {
+        let result = parse_declarations("color: #fff;\n  color #000;").unwrap_err();
+        assert_eq!((result.line, result.column), (2, 3));
+        assert_eq!(result.message, "expected `:` in declaration")
+    }
+
+    #[test]
+    fn parse_inline_declarations() {
+        let result = parse_declarations("color: #000; margin-top: 1px !important;").unwrap();
+        let expected = vec![
+            (ColorProp::Color.into(), Color::Rgb(0, 0, 0).into()).into(),
+            Declaration {
+                property: Margin {
+                    dir: Direction::Top,
+                }
+                .into(),
+                value: (1.0, Unit::Px).into(),
+                important: true,
+            },
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn error_inline_declarations() {
+        let result = parse_declarations("color: red; width").unwrap_err();
+        assert_eq!((result.line, result.column), (1, 13))
+    }
+}
@@ -0,0 +1,55 @@
+use std::{error::Error, fmt};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error for the byte `offset` of `src`, lines and columns start at 1
+    pub fn at(src: &str, offset: usize, message: &str) -> ParseError {
+        let before = &src[..offset.min(src.len())];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let column = before[line_start..].chars().count() + 1;
+        ParseError {
+            line,
+            column,
+            message: message.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod parse_error_tests {
+    use super::ParseError;
+
+    #[test]
+    fn position_first_line() {
+        let result = ParseError::at("<div>", 1, "error");
+        assert_eq!((result.line, result.column), (1, 2))
+    }
+
+    #[test]
+    fn position_later_line() {
+        let result = ParseError::at("<div>\n\t<a>\n  <b", 13, "error");
+        assert_eq!((result.line, result.column), (3, 3))
+    }
+
+    #[test]
+    fn display() {
+        let result = ParseError::at("a\nb", 2, "unexpected end of input").to_string();
+        let expected = "2:1: unexpected end of input";
+        assert_eq!(result, expected)
+    }
+}
@@ -0,0 +1,1688 @@
+use super::{parse_css_partial, ParseError};
+use crate::{
+    attribute::{AriaAttr, Attribute},
+    elements::{
+        Abbr, Article, Aside, Audio, Blockquote, Body, Button, Canvas, Caption, Cell, Code, Col,
+        Colgroup, Datalist, Div, Element, ElementKind, Em, Fieldset, Figure, Footer, Form, Head,
+        Header, HeaderSize, Headline, Hr, HtmlElement, Img, Input, Label, Legend, Li, Link,
+        Literal, Main, Mark, Meta, Metadata, Nav, Ol, Output, Picture, Pre, Script, Section,
+        Select, SelectOption, Small, Source, Span, Strong, Style, Sub, Sup, Table, TableSection,
+        TableSectionKind, Td, Textarea, Th, Tr, Track, Ul, Video, A, P,
+    },
+    escape::unescape,
+    html_document::{Doctype, HtmlDocument},
+};
+use std::rc::Rc;
+
+#[derive(Debug)]
+enum Node {
+    Element(ElementNode),
+    Text(String),
+}
+
+#[derive(Debug)]
+struct ElementNode {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Node>,
+    start: usize,
+    end: usize,
+}
+
+impl ElementNode {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attr, _)| attr == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn raw_text(&self) -> String {
+        self.children
+            .iter()
+            .map(|child| match child {
+                Node::Text(text) => text.clone(),
+                Node::Element(_) => "".to_owned(),
+            })
+            .collect()
+    }
+}
+
+/// Tolerant tokenizer and tree builder.
+/// Unclosed elements are closed at the end of the input or when a parent closes,
+/// stray closing tags are ignored.
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+    open: Vec<ElementNode>,
+    roots: Vec<Node>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(src: &'a str) -> Parser<'a> {
+        Parser {
+            src,
+            pos: 0,
+            open: vec![],
+            roots: vec![],
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn error(&self, offset: usize, message: &str) -> ParseError {
+        ParseError::at(self.src, offset, message)
+    }
+
+    fn parse(mut self) -> Result<Vec<Node>, ParseError> {
+        while self.pos < self.src.len() {
+            let rest = self.rest();
+            if rest.starts_with("<!--") {
+                self.skip_past(self.pos, "-->", "unterminated comment")?;
+            } else if rest.starts_with("<!") || rest.starts_with("<?") {
+                self.skip_past(self.pos, ">", "unterminated declaration")?;
+            } else if rest.starts_with("</") {
+                self.end_tag()?;
+            } else if rest.starts_with('<')
+                && rest.len() > 1
+                && rest.as_bytes()[1].is_ascii_alphabetic()
+            {
+                self.start_tag()?;
+            } else {
+                self.text();
+            }
+        }
+        while let Some(elem) = self.open.pop() {
+            self.close(elem, self.src.len());
+        }
+        Ok(self.roots)
+    }
+
+    fn skip_past(&mut self, start: usize, pat: &str, message: &str) -> Result<(), ParseError> {
+        match self.src[start..].find(pat) {
+            Some(i) => {
+                self.pos = start + i + pat.len();
+                Ok(())
+            }
+            None => Err(self.error(start, message)),
+        }
+    }
+
+    fn attach(&mut self, node: Node) {
+        match self.open.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => self.roots.push(node),
+        }
+    }
+
+    fn close(&mut self, mut elem: ElementNode, end: usize) {
+        elem.end = end;
+        self.attach(Node::Element(elem));
+    }
+
+    /// Closes open elements up to and including `name`, if it is open at all
+    fn close_until(&mut self, name: &str, end: usize) {
+        if !self.open.iter().any(|elem| elem.name == name) {
+            return;
+        }
+        while let Some(elem) = self.open.pop() {
+            let done = elem.name == name;
+            self.close(elem, end);
+            if done {
+                break;
+            }
+        }
+    }
+
+    /// Closes elements that end implicitly when `name` starts
+    fn close_implicit(&mut self, name: &str, end: usize) {
+        let implied: &[&str] = match name {
+            "li" => &["li"],
+            "option" => &["option"],
+            "p" => &["p"],
+            "td" | "th" => &["td", "th"],
+            "tr" => &["td", "th", "tr"],
+            "thead" | "tbody" | "tfoot" => &["td", "th", "tr", "thead", "tbody"],
+            _ => &[],
+        };
+        while let Some(top) = self.open.last() {
+            if !implied.contains(&top.name.as_str()) {
+                break;
+            }
+            let elem = self.open.pop().expect("Checked above");
+            self.close(elem, end);
+        }
+    }
+
+    fn text(&mut self) {
+        let rest = self.rest();
+        let len = rest
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| *c == '<')
+            .map(|(i, _)| i)
+            .unwrap_or(rest.len());
+        self.attach(Node::Text(unescape(&rest[..len])));
+        self.pos += len;
+    }
+
+    fn name(&mut self) -> String {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == ':' || c == '_'))
+            .unwrap_or(rest.len());
+        self.pos += len;
+        rest[..len].to_ascii_lowercase()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn end_tag(&mut self) -> Result<(), ParseError> {
+        let start = self.pos;
+        self.pos += 2;
+        let name = self.name();
+        self.skip_past(start, ">", "unterminated closing tag")?;
+        self.close_until(&name, self.pos);
+        Ok(())
+    }
+
+    fn start_tag(&mut self) -> Result<(), ParseError> {
+        let start = self.pos;
+        self.pos += 1;
+        let name = self.name();
+        let mut attributes = vec![];
+        let self_closing = loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.is_empty() {
+                return Err(self.error(start, &format!("unterminated tag <{name}>")));
+            } else if rest.starts_with("/>") {
+                self.pos += 2;
+                break true;
+            } else if rest.starts_with('>') {
+                self.pos += 1;
+                break false;
+            } else if rest.starts_with('/') {
+                self.pos += 1;
+            } else {
+                attributes.push(self.attribute()?);
+            }
+        };
+        self.close_implicit(&name, start);
+        let elem = ElementNode {
+            name,
+            attributes,
+            children: vec![],
+            start,
+            end: self.pos,
+        };
+        match ElementKind::of(&elem.name) {
+            _ if self_closing => self.close(elem, self.pos),
+            ElementKind::Void => self.close(elem, self.pos),
+            ElementKind::RawText => self.raw_text(elem),
+            ElementKind::Normal => self.open.push(elem),
+        }
+        Ok(())
+    }
+
+    fn attribute(&mut self) -> Result<(String, String), ParseError> {
+        let start = self.pos;
+        let rest = self.rest();
+        let len = match rest.find(|c: char| c.is_whitespace() || c == '=' || c == '>' || c == '/') {
+            Some(0) => rest.chars().next().map(|c| c.len_utf8()).unwrap_or(0),
+            None => rest.len(),
+            Some(len) => len,
+        };
+        let name = rest[..len].to_ascii_lowercase();
+        self.pos += len;
+        self.skip_whitespace();
+        if !self.rest().starts_with('=') {
+            return Ok((name, "".to_owned()));
+        }
+        self.pos += 1;
+        self.skip_whitespace();
+        let rest = self.rest();
+        let value = match rest.chars().next() {
+            Some(quote @ ('"' | '\'')) => match rest[1..].find(quote) {
+                Some(end) => {
+                    self.pos += end + 2;
+                    &rest[1..end + 1]
+                }
+                None => return Err(self.error(start, "unterminated attribute value")),
+            },
+            _ => {
+                let len = rest
+                    .find(|c: char| c.is_whitespace() || c == '>')
+                    .unwrap_or(rest.len());
+                self.pos += len;
+                &rest[..len]
+            }
+        };
+        Ok((name, unescape(value)))
+    }
+
+    /// Takes everything up to the closing tag as text, or the remaining input if it is missing
+    fn raw_text(&mut self, mut elem: ElementNode) {
+        let rest = self.rest();
+        let closing = format!("</{}", elem.name);
+        let len = rest
+            .to_ascii_lowercase()
+            .find(&closing)
+            .unwrap_or(rest.len());
+        let text = &rest[..len];
+        if !text.is_empty() {
+            let text = if elem.name == "title" || elem.name == "textarea" {
+                unescape(text)
+            } else {
+                text.to_owned()
+            };
+            elem.children.push(Node::Text(text));
+        }
+        self.pos += len;
+        let end = match self.rest().find('>') {
+            Some(i) if len < rest.len() => self.pos + i + 1,
+            _ => self.src.len(),
+        };
+        self.pos = end;
+        self.close(elem, end);
+    }
+}
+
+/// Collapses whitespace the way html displays it while keeping what the renderer can not put
+/// back. A run of spaces and tabs becomes a single space. A run with line breaks keeps a space
+/// for any whitespace before the first break and, inside the text, the breaks themselves, the
+/// indentation after them is dropped. Line breaks at either end are dropped, the renderer
+/// writes them between siblings. `None` if nothing is left.
+fn normalize_text(text: &str) -> Option<String> {
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+    let mut normalized = String::new();
+    let mut rest = text.as_str();
+    while !rest.is_empty() {
+        let word_len = rest
+            .find(|c: char| c.is_ascii_whitespace())
+            .unwrap_or(rest.len());
+        normalized.push_str(&rest[..word_len]);
+        rest = &rest[word_len..];
+        let run_len = rest
+            .find(|c: char| !c.is_ascii_whitespace())
+            .unwrap_or(rest.len());
+        let run = &rest[..run_len];
+        rest = &rest[run_len..];
+        if run.is_empty() {
+            continue;
+        }
+        if !run.starts_with('\n') {
+            normalized.push(' ');
+        }
+        let inside = !normalized.trim_start().is_empty() && !rest.is_empty();
+        if inside {
+            normalized.push_str(&"\n".repeat(run.matches('\n').count()));
+        }
+    }
+    (!normalized.is_empty()).then_some(normalized)
+}
+
+fn convert_attributes(elem: &ElementNode) -> Vec<Attribute> {
+    elem.attributes
+        .iter()
+        .filter_map(|(name, raw)| {
+            let value = raw.clone();
+            let typed = match name.as_str() {
+                "id" => Some(Attribute::Id(value)),
+                "class" => Some(Attribute::Class(
+                    value.split_whitespace().map(|cl| cl.to_owned()).collect(),
+                )),
+                "src" => Some(Attribute::Src(value)),
+                "style" => Some(Attribute::Style(value)),
+                "href" => Some(Attribute::Href(value)),
+                "rel" => Some(Attribute::Rel(value)),
+                "type" => Some(Attribute::Type(value)),
+                "value" => Some(Attribute::Value(value)),
+                "title" => Some(Attribute::Title(value)),
+                "name" => Some(Attribute::Name(value)),
+                "action" => Some(Attribute::Action(value)),
+                "method" => value.parse().ok().map(Attribute::Method),
+                "for" => Some(Attribute::For(value)),
+                "placeholder" => Some(Attribute::Placeholder(value)),
+                "list" => Some(Attribute::List(value)),
+                "disabled" => Some(Attribute::Disabled),
+                "required" => Some(Attribute::Required),
+                "min" => Some(Attribute::Min(value)),
+                "max" => Some(Attribute::Max(value)),
+                "step" => Some(Attribute::Step(value)),
+                "accept" => Some(Attribute::Accept(value)),
+                "multiple" => Some(Attribute::Multiple),
+                "checked" => Some(Attribute::Checked),
+                "colspan" => value.trim().parse().ok().map(Attribute::Colspan),
+                "rowspan" => value.trim().parse().ok().map(Attribute::Rowspan),
+                "scope" => value.parse().ok().map(Attribute::Scope),
+                "span" => value.trim().parse().ok().map(Attribute::Span),
+                "alt" => Some(Attribute::Alt(value)),
+                "width" => value.trim().parse().ok().map(Attribute::Width),
+                "height" => value.trim().parse().ok().map(Attribute::Height),
+                "srcset" => Some(Attribute::Srcset(value)),
+                "sizes" => Some(Attribute::Sizes(value)),
+                "loading" => value.parse().ok().map(Attribute::Loading),
+                "controls" => Some(Attribute::Controls),
+                "autoplay" => Some(Attribute::Autoplay),
+                "muted" => Some(Attribute::Muted),
+                "loop" => Some(Attribute::Loop),
+                "poster" => Some(Attribute::Poster(value)),
+                "preload" => value.parse().ok().map(Attribute::Preload),
+                "media" => Some(Attribute::Media(value)),
+                "kind" => value.parse().ok().map(Attribute::Kind),
+                "srclang" => Some(Attribute::Srclang(value)),
+                "label" => Some(Attribute::Label(value)),
+                "default" => Some(Attribute::Default),
+                "charset" => Some(Attribute::Charset(value)),
+                "content" => Some(Attribute::Content(value)),
+                "lang" => Some(Attribute::Lang(value)),
+                "dir" => value.parse().ok().map(Attribute::Dir),
+                "role" => Some(Attribute::Role(value)),
+                "target" => Some(Attribute::Target(value)),
+                name => name
+                    .strip_prefix("on")
+                    .and_then(|event| event.parse().ok())
+                    .map(|event| Attribute::On(event, value.clone()))
+                    .or_else(|| {
+                        let aria = name.strip_prefix("aria-")?;
+                        AriaAttr::parse(aria, &value).map(Attribute::Aria)
+                    })
+                    .or_else(|| {
+                        let key = name.strip_prefix("data-")?;
+                        Attribute::data(key, &value).ok()
+                    }),
+            };
+            // unknown attributes and values that do not parse are kept as they are
+            typed.or_else(|| {
+                // an empty value is the same as none at all
+                let value = (!raw.is_empty()).then_some(raw.as_str());
+                Attribute::custom(name, value).ok()
+            })
+        })
+        .collect()
+}
+
+fn convert_children<'a>(
+    src: &str,
+    children: impl IntoIterator<Item = &'a Node>,
+) -> Vec<HtmlElement> {
+    children
+        .into_iter()
+        .filter_map(|child| convert(src, child))
+        .collect()
+}
+
+/// Wraps converted children into the single element that content fields expect
+fn into_content(mut elems: Vec<HtmlElement>) -> Rc<HtmlElement> {
+    if elems.len() == 1 {
+        Rc::new(elems.remove(0))
+    } else {
+        Rc::new(elems.into())
+    }
+}
+
+fn content(src: &str, elem: &ElementNode) -> Rc<HtmlElement> {
+    into_content(convert_children(src, &elem.children))
+}
+
+fn child_elements(elem: &ElementNode) -> impl Iterator<Item = &ElementNode> {
+    elem.children.iter().filter_map(|child| match child {
+        Node::Element(elem) => Some(elem),
+        Node::Text(_) => None,
+    })
+}
+
+/// Children that are no part of a table are dropped
+fn convert_table(src: &str, elem: &ElementNode) -> Table {
+    let mut table = Table {
+        attributes: convert_attributes(elem),
+        ..Table::default()
+    };
+    for child in child_elements(elem) {
+        match child.name.as_str() {
+            "caption" => {
+                table.caption = Some(Caption {
+                    attributes: convert_attributes(child),
+                    content: content(src, child),
+                })
+            }
+            "colgroup" => table.colgroups.push(convert_colgroup(child)),
+            "col" => table.colgroups.push(Colgroup {
+                attributes: vec![],
+                cols: vec![Col {
+                    attributes: convert_attributes(child),
+                }],
+            }),
+            "thead" | "tbody" | "tfoot" => {
+                table = table.section(convert_section(src, child));
+            }
+            "tr" => table.rows.push(convert_tr(src, child)),
+            _ => (),
+        }
+    }
+    table
+}
+
+fn convert_section(src: &str, elem: &ElementNode) -> TableSection {
+    let kind = match elem.name.as_str() {
+        "thead" => TableSectionKind::Head,
+        "tfoot" => TableSectionKind::Foot,
+        _ => TableSectionKind::Body,
+    };
+    TableSection {
+        kind,
+        attributes: convert_attributes(elem),
+        rows: child_elements(elem)
+            .filter(|row| row.name == "tr")
+            .map(|row| convert_tr(src, row))
+            .collect(),
+    }
+}
+
+fn convert_tr(src: &str, elem: &ElementNode) -> Tr {
+    Tr {
+        attributes: convert_attributes(elem),
+        cols: child_elements(elem)
+            .map(|cell| convert_cell(src, cell))
+            .collect(),
+    }
+}
+
+/// Anything but a header cell becomes a data cell
+fn convert_cell(src: &str, elem: &ElementNode) -> Cell {
+    let attributes = convert_attributes(elem);
+    let content = content(src, elem);
+    if elem.name == "th" {
+        Th {
+            attributes,
+            content,
+        }
+        .into()
+    } else {
+        Td {
+            attributes,
+            content,
+        }
+        .into()
+    }
+}
+
+fn convert_colgroup(elem: &ElementNode) -> Colgroup {
+    Colgroup {
+        attributes: convert_attributes(elem),
+        cols: child_elements(elem)
+            .filter(|col| col.name == "col")
+            .map(|col| Col {
+                attributes: convert_attributes(col),
+            })
+            .collect(),
+    }
+}
+
+fn convert_options(src: &str, elem: &ElementNode) -> Vec<SelectOption> {
+    child_elements(elem)
+        .filter(|opt| opt.name == "option")
+        .map(|opt| SelectOption {
+            value: opt
+                .attribute("value")
+                .map(|value| value.to_owned())
+                .unwrap_or_else(|| opt.raw_text().trim().to_owned()),
+            content: content(src, opt),
+        })
+        .collect()
+}
+
+fn convert_legend(src: &str, elem: &ElementNode) -> Legend {
+    Legend {
+        attributes: convert_attributes(elem),
+        content: content(src, elem),
+    }
+}
+
+fn convert_sources(elem: &ElementNode) -> Vec<Source> {
+    child_elements(elem)
+        .filter(|child| child.name == "source")
+        .map(|source| Source {
+            attributes: convert_attributes(source),
+        })
+        .collect()
+}
+
+fn convert_tracks(elem: &ElementNode) -> Vec<Track> {
+    child_elements(elem)
+        .filter(|child| child.name == "track")
+        .map(|track| Track {
+            attributes: convert_attributes(track),
+        })
+        .collect()
+}
+
+/// The content of a media element, i.e. everything but its sources and tracks
+fn media_content(src: &str, elem: &ElementNode) -> Rc<HtmlElement> {
+    into_content(convert_children(
+        src,
+        elem.children.iter().filter(|child| {
+            !matches!(child, Node::Element(child) if child.name == "source" || child.name == "track")
+        }),
+    ))
+}
+
+fn convert_li(src: &str, elem: &ElementNode) -> Li {
+    if elem.name == "li" {
+        Li {
+            attributes: convert_attributes(elem),
+            content: content(src, elem),
+        }
+    } else {
+        Li {
+            attributes: vec![],
+            content: Rc::new(convert_element(src, elem)),
+        }
+    }
+}
+
+fn convert_element(src: &str, elem: &ElementNode) -> HtmlElement {
+    let attributes = convert_attributes(elem);
+    match elem.name.as_str() {
+        "div" => Div {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "a" => A {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "body" => Body {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "head" => convert_head(src, elem).into(),
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => Headline {
+            size: match elem.name.as_str() {
+                "h1" => HeaderSize::H1,
+                "h2" => HeaderSize::H2,
+                "h3" => HeaderSize::H3,
+                "h4" => HeaderSize::H4,
+                "h5" => HeaderSize::H5,
+                _ => HeaderSize::H6,
+            },
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "br" => HtmlElement::Br,
+        "img" => Img { attributes }.into(),
+        "input" => Input {
+            kind: None,
+            attributes,
+        }
+        .typed()
+        .into(),
+        "link" => Link { attributes }.into(),
+        "meta" => Meta { attributes }.into(),
+        "canvas" => Canvas { attributes }.into(),
+        "script" => Script {
+            attributes,
+            content: elem.raw_text(),
+        }
+        .into(),
+        "figure" => {
+            let caption = child_elements(elem).find(|child| child.name == "figcaption");
+            let rest = elem.children.iter().filter(
+                |child| !matches!(child, Node::Element(child) if child.name == "figcaption"),
+            );
+            Figure {
+                attributes,
+                content: into_content(convert_children(src, rest)),
+                caption: caption
+                    .map(|caption| content(src, caption))
+                    .unwrap_or_else(|| into_content(vec![])),
+            }
+            .into()
+        }
+        "table" => convert_table(src, elem).into(),
+        "thead" | "tbody" | "tfoot" => convert_section(src, elem).into(),
+        "tr" => convert_tr(src, elem).into(),
+        "td" | "th" => convert_cell(src, elem).into(),
+        "caption" => Caption {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "colgroup" => convert_colgroup(elem).into(),
+        "col" => Col { attributes }.into(),
+        "ul" => Ul {
+            attributes,
+            items: child_elements(elem).map(|li| convert_li(src, li)).collect(),
+        }
+        .into(),
+        "ol" => Ol {
+            attributes,
+            items: child_elements(elem).map(|li| convert_li(src, li)).collect(),
+        }
+        .into(),
+        "select" => Select {
+            attributes,
+            options: convert_options(src, elem),
+        }
+        .into(),
+        // skipped rules would be lost, so such a stylesheet is kept as it is
+        "style" => match parse_css_partial(&elem.raw_text()) {
+            Ok((style, skipped)) if skipped.is_empty() && elem.attributes.is_empty() => {
+                Style { style }.into()
+            }
+            _ => HtmlElement::Raw(src[elem.start..elem.end].to_owned()),
+        },
+        "section" => Section {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "article" => Article {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "nav" => Nav {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "header" => Header {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "footer" => Footer {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "main" => Main {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "aside" => Aside {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "p" => P {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "blockquote" => Blockquote {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "span" => Span {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "code" => Code {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "strong" => Strong {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "em" => Em {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "small" => Small {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "mark" => Mark {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "sub" => Sub {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "sup" => Sup {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "abbr" => Abbr {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "pre" => Pre {
+            attributes,
+            content: into_content(
+                elem.children
+                    .iter()
+                    .map(|child| match child {
+                        Node::Text(text) => Literal::preformatted(text.clone()).into(),
+                        Node::Element(elem) => convert_element(src, elem),
+                    })
+                    .collect(),
+            ),
+        }
+        .into(),
+        "hr" => Hr { attributes }.into(),
+        "form" => Form {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "label" => Label {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "button" => Button {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "textarea" => {
+            let content = elem.raw_text();
+            Textarea {
+                attributes,
+                // browsers drop a line break right after the opening tag
+                content: content.strip_prefix('\n').unwrap_or(&content).to_owned(),
+            }
+        }
+        .into(),
+        "fieldset" => Fieldset {
+            attributes,
+            legend: child_elements(elem)
+                .find(|child| child.name == "legend")
+                .map(|legend| convert_legend(src, legend)),
+            content: into_content(convert_children(
+                src,
+                elem.children.iter().filter(
+                    |child| !matches!(child, Node::Element(child) if child.name == "legend"),
+                ),
+            )),
+        }
+        .into(),
+        "legend" => convert_legend(src, elem).into(),
+        "datalist" => Datalist {
+            attributes,
+            options: convert_options(src, elem),
+        }
+        .into(),
+        "output" => Output {
+            attributes,
+            content: content(src, elem),
+        }
+        .into(),
+        "video" => Video {
+            attributes,
+            sources: convert_sources(elem),
+            tracks: convert_tracks(elem),
+            content: media_content(src, elem),
+        }
+        .into(),
+        "audio" => Audio {
+            attributes,
+            sources: convert_sources(elem),
+            tracks: convert_tracks(elem),
+            content: media_content(src, elem),
+        }
+        .into(),
+        "picture" => Picture {
+            attributes,
+            sources: convert_sources(elem),
+            content: media_content(src, elem),
+        }
+        .into(),
+        "source" => Source { attributes }.into(),
+        "track" => Track { attributes }.into(),
+        "html" => convert_children(src, &elem.children).into(),
+        _ if attributes.len() == elem.attributes.len() => convert_generic(src, elem, attributes),
+        _ => HtmlElement::Raw(src[elem.start..elem.end].to_owned()),
+    }
+}
+
+/// Converts an element without a typed struct, raw text is kept as a single text child
+fn convert_generic(src: &str, elem: &ElementNode, attributes: Vec<Attribute>) -> HtmlElement {
+    let kind = ElementKind::of(&elem.name);
+    let children = match kind {
+        ElementKind::RawText => match elem.raw_text() {
+            text if text.is_empty() => vec![],
+            text => vec![text.into()],
+        },
+        _ => convert_children(src, &elem.children),
+    };
+    Element {
+        tag: elem.name.clone(),
+        attributes,
+        children,
+        kind,
+    }
+    .into()
+}
+
+fn convert(src: &str, node: &Node) -> Option<HtmlElement> {
+    match node {
+        Node::Text(text) => normalize_text(text).map(|text| text.into()),
+        Node::Element(elem) => Some(convert_element(src, elem)),
+    }
+}
+
+/// Metadata with a typed field is moved out of the content, see [`Metadata`]
+fn convert_head(src: &str, elem: &ElementNode) -> Head {
+    let title = child_elements(elem)
+        .find(|child| child.name == "title")
+        .map(|title| title.raw_text().trim().to_owned())
+        .unwrap_or_default();
+    let rest = elem
+        .children
+        .iter()
+        .filter(|child| !matches!(child, Node::Element(child) if child.name == "title"));
+    let mut metadata = Metadata::default();
+    let content = convert_children(src, rest)
+        .into_iter()
+        .filter(|child| !metadata.absorb(child))
+        .collect();
+    Head {
+        title,
+        metadata,
+        content: into_content(content),
+    }
+}
+
+/// Parses a html fragment.
+/// Known tags are mapped onto the typed elements, all other elements onto the generic
+/// [`Element`]. Elements with attributes that can not be represented are kept as
+/// [`HtmlElement::Raw`] markup. A fragment with several top level nodes becomes a
+/// [`HtmlElement::ComponentList`].
+pub fn parse_fragment(src: &str) -> Result<HtmlElement, ParseError> {
+    let nodes = Parser::new(src).parse()?;
+    let mut elems = convert_children(src, &nodes);
+    if elems.len() == 1 {
+        Ok(elems.remove(0))
+    } else {
+        Ok(elems.into())
+    }
+}
+
+/// The doctype declared before any markup, ignoring case
+fn doctype(src: &str) -> Doctype {
+    let src = src.trim_start();
+    let Some(decl) = src
+        .get(..9)
+        .filter(|start| start.eq_ignore_ascii_case("<!doctype"))
+        .and_then(|_| src[9..].split_once('>'))
+        .map(|(decl, _)| decl.trim())
+    else {
+        return Doctype::None;
+    };
+    if decl.eq_ignore_ascii_case("html") {
+        Doctype::Html5
+    } else {
+        Doctype::Custom(decl.to_owned())
+    }
+}
+
+/// Parses a complete html document.
+/// Missing `<head>` or `<body>` elements are tolerated, content outside of them ends up in the body.
+pub fn parse_document(src: &str) -> Result<HtmlDocument, ParseError> {
+    let nodes = Parser::new(src).parse()?;
+    let html = nodes.iter().find_map(|node| match node {
+        Node::Element(elem) if elem.name == "html" => Some(elem),
+        _ => None,
+    });
+    let top: Vec<&Node> = match html {
+        Some(html) => html.children.iter().collect(),
+        None => nodes.iter().collect(),
+    };
+    let attributes = html.map(convert_attributes).unwrap_or_default();
+
+    let mut head = None;
+    let mut body_attributes = vec![];
+    let mut body_nodes = vec![];
+    for node in top {
+        match node {
+            Node::Element(elem) if elem.name == "head" && head.is_none() => {
+                head = Some(convert_head(src, elem))
+            }
+            Node::Element(elem) if elem.name == "body" => {
+                body_attributes.extend(convert_attributes(elem));
+                body_nodes.extend(elem.children.iter());
+            }
+            node => body_nodes.push(node),
+        }
+    }
+
+    Ok(HtmlDocument {
+        doctype: doctype(src),
+        attributes,
+        head: head.unwrap_or(Head {
+            title: "".to_owned(),
+            metadata: Metadata::default(),
+            content: into_content(vec![]),
+        }),
+        body: Body {
+            attributes: body_attributes,
+            content: into_content(convert_children(src, body_nodes)),
+        },
+    })
+}
+
+#[cfg(test)]
+mod parse_html_tests {
+    use super::{parse_document, parse_fragment};
+    use crate::{
+        attribute::{AriaAttr, Attribute, Dir, Event, Loading, Method, Scope, TrackKind},
+        css::{
+            block::CssBlock, property::Property, selector::TopSelector, value::Keyword, CssDocument,
+        },
+        elements::{
+            Article, Body, Bounded, Build, Button, Caption, Code, Col, Colgroup, Div, Element, Em,
+            Fieldset, Figure, Form, FormControl, Head, Header, HeaderSize, Headline, Hr,
+            HtmlElement, Icon, Img, Input, InputKind, Label, Legend, Li, Link, Main, MediaElement,
+            Meta, Metadata, Picture, Pre, Script, Select, SelectOption, Source, Strong, Style,
+            Table, TableCell, TableSection, Td, Textarea, Th, Tr, Track, Ul, Video, WithAttributes,
+            WithChildren, A, P,
+        },
+        html_document::{Doctype, HtmlDocument},
+        render::Render,
+    };
+    use chrono::NaiveDate;
+    use std::rc::Rc;
+
+    fn text(s: &str) -> Rc<HtmlElement> {
+        Rc::new(s.to_owned().into())
+    }
+
+    fn roundtrip(elem: HtmlElement) {
+        let rendered = elem.render();
+        let parsed = parse_fragment(&rendered).unwrap();
+        assert_eq!(parsed.render(), rendered);
+        assert_eq!(parsed, elem)
+    }
+
+    #[test]
+    fn parse_div() {
+        let result = parse_fragment("<div id=\"main\" class=\"a  b\">hello</div>").unwrap();
+        let expected = Div {
+            attributes: vec![
+                Attribute::Id("main".to_owned()),
+                Attribute::Class(vec!["a".to_owned(), "b".to_owned()]),
+            ],
+            content: text("hello"),
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_attribute_forms() {
+        let result = parse_fragment("<A HREF=index.html onClick='go(\"x\")'>x</A>").unwrap();
+        let expected = A {
+            attributes: vec![
+                Attribute::Href("index.html".to_owned()),
+                Attribute::On(Event::Click, "go(\"x\")".to_owned()),
+            ],
+            content: text("x"),
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_event_handlers() {
+        let result = parse_fragment(
+            "<div onMouseEnter=\"show()\" ontouchstart=\"drag(event)\" onfoo=\"x\">y</div>",
+        )
+        .unwrap();
+        let expected = Div::new()
+            .attr(Attribute::On(Event::MouseEnter, "show()".to_owned()))
+            .attr(Attribute::On(Event::TouchStart, "drag(event)".to_owned()))
+            .attr(Attribute::Custom("onfoo".to_owned(), Some("x".to_owned())))
+            .text("y")
+            .into();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_entities() {
+        let result = parse_fragment("<div tabindex=\"1\">a &lt;b&gt; &amp; c</div>").unwrap();
+        let expected = Div {
+            attributes: vec![Attribute::Custom(
+                "tabindex".to_owned(),
+                Some("1".to_owned()),
+            )],
+            content: text("a <b> & c"),
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_multiple_roots() {
+        let result = parse_fragment("<br><img src=\"a.png\">text").unwrap();
+        let expected = vec![
+            HtmlElement::Br,
+            Img {
+                attributes: vec![Attribute::Src("a.png".to_owned())],
+            }
+            .into(),
+            "text".to_owned().into(),
+        ]
+        .into();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_unknown_as_element() {
+        let result =
+            parse_fragment("<div><details class=\"x\">para <b>bold</b></details><wbr></div>")
+                .unwrap();
+        let expected = Div::new()
+            .child(
+                Element::new("details")
+                    .class("x")
+                    .text("para ")
+                    .child(Element::new("b").text("bold")),
+            )
+            .child(Element::new("wbr"))
+            .build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_invalid_values_as_custom() {
+        let result =
+            parse_fragment("<div><details colspan=\"x\">para <b>bold</b></details></div>").unwrap();
+        let expected = Div::new()
+            .child(
+                Element::new("details")
+                    .attr(Attribute::Custom(
+                        "colspan".to_owned(),
+                        Some("x".to_owned()),
+                    ))
+                    .text("para ")
+                    .child(Element::new("b").text("bold")),
+            )
+            .build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_open_attributes() {
+        let result = parse_fragment(
+            "<details tabindex=\"1\" open data-id=\"7\" aria-expanded=\"true\" aria-rowindex=\"2\" role=\"group\">x</details>",
+        )
+        .unwrap();
+        let expected = Element::new("details")
+            .attr(Attribute::Custom(
+                "tabindex".to_owned(),
+                Some("1".to_owned()),
+            ))
+            .attr(Attribute::Custom("open".to_owned(), None))
+            .attr(Attribute::Data("id".to_owned(), "7".to_owned()))
+            .attr(Attribute::Aria(AriaAttr::Expanded(true)))
+            .attr(Attribute::Custom(
+                "aria-rowindex".to_owned(),
+                Some("2".to_owned()),
+            ))
+            .attr(Attribute::Role("group".to_owned()))
+            .text("x")
+            .into();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_semantic() {
+        let result = parse_fragment(
+            "<main><article><header><h1>T</h1></header><p>Some <strong>bold</strong> text<hr></article></main>",
+        )
+        .unwrap();
+        let expected = Main::new()
+            .child(
+                Article::new()
+                    .child(Header::new().child(Headline::new(HeaderSize::H1).text("T")))
+                    .child(
+                        P::new()
+                            .text("Some ")
+                            .child(Strong::new().text("bold"))
+                            .text(" text")
+                            .child(Hr::new()),
+                    ),
+            )
+            .build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_form() {
+        let src = "<form action=\"/send\" method=\"POST\"><fieldset disabled><label for=\"msg\">Message</label><legend>Contact</legend><textarea id=\"msg\" name=\"msg\" required>a &lt; b</textarea></fieldset><button>Send</button></form>";
+        let result = parse_fragment(src).unwrap();
+        let expected = Form::new()
+            .action("/send")
+            .method(Method::Post)
+            .child(
+                Fieldset::new()
+                    .disabled()
+                    .legend(Legend::new().text("Contact"))
+                    .child(Label::new().for_id("msg").text("Message"))
+                    .child(
+                        Textarea::new()
+                            .id("msg")
+                            .name("msg")
+                            .required()
+                            .content("a < b"),
+                    ),
+            )
+            .child(Button::new().text("Send"))
+            .build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_input_kind() {
+        let result = parse_fragment(
+            "<div><input type=\"date\" name=\"d\" max=\"2024-12-31\"><input type=\"month\" min=\"2024-01\"></div>",
+        )
+        .unwrap();
+        let expected = Div::new()
+            .child(
+                Input::new()
+                    .kind(InputKind::Date(
+                        Bounded::new().max(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap()),
+                    ))
+                    .name("d"),
+            )
+            .child(
+                Input::new()
+                    .attr(Attribute::Type("month".to_owned()))
+                    .attr(Attribute::Min("2024-01".to_owned())),
+            )
+            .build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_media() {
+        let result = parse_fragment(
+            "<div><video controls width=\"320\"><source src=\"a.mp4\" type=\"video/mp4\"><track kind=\"captions\" src=\"a.vtt\" default>No video</video>\
+             <picture><source media=\"(min-width: 600px)\" srcset=\"big.jpg 2x\"><img src=\"small.jpg\" alt=\"Dog\" loading=\"lazy\"></picture></div>",
+        )
+        .unwrap();
+        let expected = Div::new()
+            .child(
+                Video::new()
+                    .controls()
+                    .width(320)
+                    .source(Source::new().src("a.mp4").source_type("video/mp4"))
+                    .track(
+                        Track::new()
+                            .kind(TrackKind::Captions)
+                            .src("a.vtt")
+                            .default_track(),
+                    )
+                    .text("No video"),
+            )
+            .child(
+                Picture::new()
+                    .source(
+                        Source::new()
+                            .media("(min-width: 600px)")
+                            .srcset("big.jpg 2x"),
+                    )
+                    .child(
+                        Img::new()
+                            .src("small.jpg")
+                            .alt("Dog")
+                            .loading(Loading::Lazy),
+                    ),
+            )
+            .build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_unknown_method() {
+        let result = parse_fragment("<form method=\"put\"><input name=\"a\"></form>").unwrap();
+        let expected = Form::new()
+            .attr(Attribute::Custom(
+                "method".to_owned(),
+                Some("put".to_owned()),
+            ))
+            .child(Input::new().name("a"))
+            .build();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn roundtrip_invalid_typed_values() {
+        let src = "<form method=\"PATCH\" dir=\"sideways\"><img loading=\"soon\"/><table><tr><td colspan=\"abc\">a</td></tr></table></form>";
+        let parsed = parse_fragment(src).unwrap();
+        let rendered = parsed.render();
+        assert_eq!(parse_fragment(&rendered).unwrap(), parsed);
+        let result = [
+            "method=\"PATCH\"",
+            "dir=\"sideways\"",
+            "loading=\"soon\"",
+            "colspan=\"abc\"",
+        ]
+        .map(|attr| rendered.contains(attr));
+        let expected = [true; 4];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_pre() {
+        let src = "<pre>fn main() {\n    <code>a &lt; b</code>\n}</pre>";
+        let result = parse_fragment(src).unwrap();
+        let expected = Pre::new()
+            .preformatted("fn main() {\n    ")
+            .child(Code::new().text("a < b"))
+            .preformatted("\n}")
+            .build();
+        assert_eq!(result, expected);
+        assert_eq!(
+            result.render(),
+            src.replace("<pre>", "<pre >").replace("<code>", "<code >")
+        )
+    }
+
+    #[test]
+    fn parse_unclosed() {
+        let result = parse_fragment("<div><div>inner</span>").unwrap();
+        let expected = Div {
+            attributes: vec![],
+            content: Rc::new(
+                Div {
+                    attributes: vec![],
+                    content: text("inner"),
+                }
+                .into(),
+            ),
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_implicit_close() {
+        let result = parse_fragment("<ul><li>one<li>two</ul>").unwrap();
+        let expected = Ul {
+            attributes: vec![],
+            items: vec![
+                Li {
+                    attributes: vec![],
+                    content: text("one"),
+                },
+                Li {
+                    attributes: vec![],
+                    content: text("two"),
+                },
+            ],
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_table() {
+        let result = parse_fragment("<table id=t><tr><td>a<td>b<tr><td>c</table>").unwrap();
+        let row = |cells: &[&str]| -> Tr {
+            Tr {
+                attributes: vec![],
+                cols: cells
+                    .iter()
+                    .map(|cell| {
+                        Td {
+                            attributes: vec![],
+                            content: text(cell),
+                        }
+                        .into()
+                    })
+                    .collect(),
+            }
+        };
+        let expected = Table {
+            attributes: vec![Attribute::Id("t".to_owned())],
+            rows: vec![row(&["a", "b"]), row(&["c"])],
+            ..Table::default()
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_table_sections() {
+        let result = parse_fragment(
+            "<table><caption>Sales</caption><colgroup><col span=2></colgroup><thead><tr><th scope=col>Item<th>Sum<tbody><tr><td colspan=\"2\">none<tfoot><tr><td>total<td>0</table>",
+        )
+        .unwrap();
+        let expected = Table::new()
+            .caption(Caption::new().text("Sales"))
+            .colgroup(Colgroup::new().col(Col::new().span(2)))
+            .section(
+                TableSection::head().row(
+                    Tr::new()
+                        .child(Th::new().scope(Scope::Col).text("Item"))
+                        .child(Th::new().text("Sum")),
+                ),
+            )
+            .section(TableSection::body().row(Tr::new().child(Td::new().colspan(2).text("none"))))
+            .section(TableSection::foot().row(Tr::new().text("total").text("0")))
+            .build();
+        assert_eq!(result, expected);
+        let HtmlElement::Table(table) = result else {
+            unreachable!()
+        };
+        assert_eq!(table.validate(), Ok(()))
+    }
+
+    #[test]
+    fn parse_select() {
+        let result = parse_fragment("<select><option value=\"1\">One<option>Two</select>").unwrap();
+        let expected = Select {
+            attributes: vec![],
+            options: vec![
+                SelectOption {
+                    value: "1".to_owned(),
+                    content: text("One"),
+                },
+                SelectOption {
+                    value: "Two".to_owned(),
+                    content: text("Two"),
+                },
+            ],
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_script_raw() {
+        let result = parse_fragment("<script>if (a < b && c) {\n  go();\n}</script>").unwrap();
+        let expected = Script {
+            attributes: vec![],
+            content: "if (a < b && c) {\n  go();\n}".to_owned(),
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_script_untrimmed() {
+        let result = parse_fragment("<script>\n  go();\n</script>").unwrap();
+        let expected = Script {
+            attributes: vec![],
+            content: "\n  go();\n".to_owned(),
+        }
+        .into();
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_collapses_whitespace() {
+        let result = [
+            "<p>Hello <a href=\"x\">link</a>\t  there</p>",
+            "<p><strong>a</strong> <em>b</em></p>",
+            "<p>\n  one\n\n    two  \n</p>",
+        ]
+        .map(|src| parse_fragment(src).unwrap());
+        let expected = [
+            P::new()
+                .text("Hello ")
+                .child(A::new().href("x").text("link"))
+                .text(" there")
+                .build(),
+            P::new()
+                .child(Strong::new().text("a"))
+                .text(" ")
+                .child(Em::new().text("b"))
+                .build(),
+            P::new().text("one\n\ntwo ").build(),
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_comments_ignored() {
+        let result = parse_fragment("<!-- a <div> comment --><br/>").unwrap();
+        assert_eq!(result, HtmlElement::Br)
+    }
+
+    #[test]
+    fn error_unterminated_tag() {
+        let result = parse_fragment("<div>\n  <a href=\"x\"").unwrap_err();
+        assert_eq!((result.line, result.column), (2, 3));
+        assert_eq!(result.message, "unterminated tag <a>")
+    }
+
+    #[test]
+    fn error_unterminated_attribute() {
+        let result = parse_fragment("<div>\n<img src=\"x.png>\n</div>").unwrap_err();
+        assert_eq!((result.line, result.column), (2, 6));
+        assert_eq!(result.message, "unterminated attribute value")
+    }
+
+    #[test]
+    fn error_unterminated_comment() {
+        let result = parse_fragment("text\n\n<!-- open").unwrap_err();
+        assert_eq!((result.line, result.column), (3, 1))
+    }
+
+    #[test]
+    fn roundtrip_elements() {
+        roundtrip(
+            Div {
+                attributes: vec![Attribute::Class(vec!["outer".to_owned()])],
+                content: Rc::new(
+                    vec![
+                        Headline {
+                            size: HeaderSize::H2,
+                            attributes: vec![],
+                            content: text("Title & more"),
+                        }
+                        .into(),
+                        A {
+                            attributes: vec![Attribute::Href("a.html?x=1&y=2".to_owned())],
+                            content: text("link"),
+                        }
+                        .into(),
+                        Figure {
+                            attributes: vec![],
+                            content: Rc::new(
+                                Img {
+                                    attributes: vec![Attribute::Src("a.png".to_owned())],
+                                }
+                                .into(),
+                            ),
+                            caption: text("caption"),
+                        }
+                        .into(),
+                    ]
+                    .into(),
+                ),
+            }
+            .into(),
+        );
+        roundtrip(
+            Table {
+                attributes: vec![],
+                rows: vec![Tr {
+                    attributes: vec![Attribute::Id("row".to_owned())],
+                    cols: vec![Td {
+                        attributes: vec![],
+                        content: text("multi\nline"),
+                    }
+                    .into()],
+                }],
+                ..Table::default()
+            }
+            .into(),
+        );
+        roundtrip(
+            Element::new("details")
+                .id("intro")
+                .child(Element::new("my-widget").text("custom"))
+                .child(Element::new("wbr"))
+                .child(Textarea::new().content("a < b\n  c"))
+                .child(Textarea::new().content("\nfirst"))
+                .build(),
+        );
+        roundtrip(
+            Script {
+                attributes: vec![Attribute::Type("module".to_owned())],
+                content: "let a = 1;\n  let b = a < 2;".to_owned(),
+            }
+            .into(),
+        );
+    }
+
+    #[test]
+    fn roundtrip_document() {
+        let document = HtmlDocument {
+            doctype: Doctype::Html5,
+            attributes: vec![
+                Attribute::Lang("he".to_owned()),
+                Attribute::Dir(Dir::Rtl),
+                Attribute::Class(vec!["dark".to_owned()]),
+            ],
+            head: Head {
+                title: "a <document>".to_owned(),
+                metadata: Metadata::default(),
+                content: Rc::new(vec![].into()),
+            },
+            body: Body {
+                attributes: vec![Attribute::Id("body".to_owned())],
+                content: Rc::new(
+                    Div {
+                        attributes: vec![],
+                        content: text("hello"),
+                    }
+                    .into(),
+                ),
+            },
+        };
+        let rendered = document.render();
+        let parsed = parse_document(&rendered).unwrap();
+        assert_eq!(parsed.render(), rendered);
+        assert_eq!(parsed, document)
+    }
+
+    #[test]
+    fn parse_head_metadata() {
+        let result = parse_document(
+            "<head><meta name=\"author\" content=\"me\"><title>t</title><meta charset=\"utf-8\">\
+             <link rel=\"icon\" href=\"/i.png\" sizes=\"32x32\"><meta name=\"viewport\" content=\"width=device-width\"></head>",
+        )
+        .unwrap()
+        .head;
+        let expected = Head::new()
+            .title("t")
+            .charset("utf-8")
+            .viewport("width=device-width")
+            .icon(Icon::new("/i.png").sizes("32x32"))
+            .child(Meta::named("author", "me"));
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn roundtrip_head_metadata() {
+        let head = Head::new()
+            .title("t")
+            .charset("utf-8")
+            .base("/")
+            .keywords(["a", "b"])
+            .canonical("/page")
+            .child(Link::new().rel("stylesheet").href("main.css"));
+        let document = HtmlDocument::new().head(head);
+        let rendered = document.render();
+        let parsed = parse_document(&rendered).unwrap();
+        assert_eq!(parsed, document)
+    }
+
+    #[test]
+    fn parse_doctype() {
+        let result = [
+            "<!DOCTYPE html><html></html>",
+            "\n  <!doctype HTML PUBLIC \"-//W3C//DTD HTML 4.01//EN\">",
+            "<html><!doctype html></html>",
+        ]
+        .map(|src| parse_document(src).unwrap().doctype);
+        let expected = [
+            Doctype::Html5,
+            Doctype::Custom("HTML PUBLIC \"-//W3C//DTD HTML 4.01//EN\"".to_owned()),
+            Doctype::None,
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_document_without_body() {
+        let result = parse_document("<title>t</title><div>content</div>").unwrap();
+        let expected = HtmlDocument {
+            doctype: Doctype::None,
+            attributes: vec![],
+            head: Head {
+                title: "".to_owned(),
+                metadata: Metadata::default(),
+                content: Rc::new(vec![].into()),
+            },
+            body: Body {
+                attributes: vec![],
+                content: Rc::new(
+                    vec![
+                        Element::new("title").text("t").into(),
+                        Div {
+                            attributes: vec![],
+                            content: text("content"),
+                        }
+                        .into(),
+                    ]
+                    .into(),
+                ),
+            },
+        };
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn parse_style() {
+        let style: HtmlElement = Style {
+            style: CssDocument {
+                decls: vec![CssBlock {
+                    selector: TopSelector::Class("x".to_owned()).into(),
+                    decls: vec![(Property::Display, Keyword::Flex.into()).into()],
+                }],
+            },
+        }
+        .into();
+        roundtrip(style)
+    }
+
+    #[test]
+    fn parse_unsupported_style_as_raw() {
+        let src = "<style>@media print { a { color: #000; } }</style>";
+        let result = parse_fragment(src).unwrap();
+        assert_eq!(result, HtmlElement::Raw(src.to_owned()))
+    }
+}
@@ -0,0 +1,7 @@
+mod css;
+mod error;
+mod html;
+
+pub use css::{parse_css, parse_css_partial, parse_declarations};
+pub use error::ParseError;
+pub use html::{parse_document, parse_fragment};
@@ -0,0 +1,335 @@
+//! Finding elements of a html tree with css selectors.
+//!
+//! Only nodes with a tag take part in matching: text is skipped and the elements of a
+//! [`HtmlElement::ComponentList`] count as children of the enclosing element.
+//! The `figcaption` of a [`crate::elements::Figure`] is not a node, so caption content is a child
+//! of the figure itself.
+use crate::{
+    attribute::Attribute,
+    css::selector::{Selector, SubSelector, TopSelector},
+    elements::HtmlElement,
+    html_document::HtmlDocument,
+    visit::{walk, Node, Visitor},
+};
+
+/// An element on the path from the root to the node being matched
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Frame<'a> {
+    pub tag: &'a str,
+    pub attributes: &'a [Attribute],
+    /// 1-based position among the element children of the parent
+    pub position: usize,
+    children: usize,
+}
+
+impl<'a> Frame<'a> {
+    fn new(tag: &'a str, attributes: &'a [Attribute], position: usize) -> Frame<'a> {
+        Frame {
+            tag,
+            attributes,
+            position,
+            children: 0,
+        }
+    }
+}
+
+/// Visitor calling `f` with every element and the path leading to it, the element being last
+struct PathWalker<'a, F> {
+    path: Vec<Frame<'a>>,
+    f: F,
+}
+
+impl<'a, F: FnMut(Node<'a>, &[Frame<'a>])> Visitor<'a> for PathWalker<'a, F> {
+    fn enter(&mut self, node: Node<'a>) {
+        let Some(tag) = node.tag_name() else {
+            return;
+        };
+        let position = match self.path.last_mut() {
+            Some(parent) => {
+                parent.children += 1;
+                parent.children
+            }
+            None => 1,
+        };
+        self.path.push(Frame::new(tag, node.attributes(), position));
+        (self.f)(node, &self.path)
+    }
+
+    fn leave(&mut self, node: Node<'a>) {
+        if node.tag_name().is_some() {
+            self.path.pop();
+        }
+    }
+}
+
+/// Calls `f` for every element below and including `node` in document order
+pub(crate) fn walk_paths<'a, F>(node: Node<'a>, f: F)
+where
+    F: FnMut(Node<'a>, &[Frame<'a>]),
+{
+    walk(&mut PathWalker { path: vec![], f }, node)
+}
+
+/// Same as [`walk_paths`] for the head and body of `document`, both children of `<html>`
+pub(crate) fn walk_document_paths<'a, F>(document: &'a HtmlDocument, f: F)
+where
+    F: FnMut(Node<'a>, &[Frame<'a>]),
+{
+    let mut walker = PathWalker {
+        path: vec![Frame::new("html", &[], 1)],
+        f,
+    };
+    walk(&mut walker, Node::Head(&document.head));
+    walk(&mut walker, Node::Body(&document.body));
+}
+
+fn matches_top(top: &TopSelector, path: &[Frame]) -> bool {
+    let Some(elem) = path.last() else {
+        return false;
+    };
+    match top {
+        TopSelector::Class(class) => elem.attributes.iter().any(|attr| match attr {
+            Attribute::Class(classes) => classes.contains(class),
+            _ => false,
+        }),
+        TopSelector::Id(id) => elem
+            .attributes
+            .iter()
+            .any(|attr| matches!(attr, Attribute::Id(elem_id) if elem_id == id)),
+        TopSelector::Tag(tag) => elem.tag.eq_ignore_ascii_case(tag),
+        TopSelector::Pseudo(pseudo) => pseudo == "root" && path.len() == 1,
+        TopSelector::All => true,
+        TopSelector::Multiple(tops) => tops.iter().any(|top| matches_top(top, path)),
+    }
+}
+
+/// Whether the last element of `path` is selected by `selector`
+pub(crate) fn matches(selector: &Selector, path: &[Frame]) -> bool {
+    // `a > b > c` is stored as `a` with the child combinator `b > c`, so the first compound
+    // has to match the outermost element of the chain
+    let mut compounds = vec![];
+    let mut current = selector;
+    loop {
+        match &current.sub {
+            Some(SubSelector::ChildCombinator(child)) => {
+                compounds.push((&current.top, None));
+                current = child;
+            }
+            sub => {
+                compounds.push((&current.top, sub.as_ref()));
+                break;
+            }
+        }
+    }
+    if compounds.len() > path.len() {
+        return false;
+    }
+    let offset = path.len() - compounds.len();
+    compounds.iter().enumerate().all(|(i, (top, sub))| {
+        let path = &path[..offset + i + 1];
+        matches_top(top, path)
+            && match sub {
+                None | Some(SubSelector::ChildCombinator(_)) => true,
+                Some(SubSelector::Visited) => false,
+                Some(SubSelector::NthChild(child)) => child.matches(path[path.len() - 1].position),
+            }
+    })
+}
+
+impl HtmlElement {
+    /// The first element in document order selected by `selector`, including `self`
+    pub fn query_selector(&self, selector: &Selector) -> Option<Node<'_>> {
+        self.query_selector_all(selector).into_iter().next()
+    }
+
+    /// All elements selected by `selector` in document order, including `self`
+    pub fn query_selector_all(&self, selector: &Selector) -> Vec<Node<'_>> {
+        let mut found = vec![];
+        walk_paths(self.into(), |node, path| {
+            if matches(selector, path) {
+                found.push(node)
+            }
+        });
+        found
+    }
+}
+
+impl HtmlDocument {
+    /// The first element in document order selected by `selector`
+    pub fn query_selector(&self, selector: &Selector) -> Option<Node<'_>> {
+        self.query_selector_all(selector).into_iter().next()
+    }
+
+    /// All elements selected by `selector` in document order
+    pub fn query_selector_all(&self, selector: &Selector) -> Vec<Node<'_>> {
+        let mut found = vec![];
+        walk_document_paths(self, |node, path| {
+            if matches(selector, path) {
+                found.push(node)
+            }
+        });
+        found
+    }
+}
+
+#[cfg(test)]
+mod query_tests {
+    use crate::{
+        attribute::Attribute,
+        css::selector::Selector,
+        elements::HtmlElement,
+        parse::{parse_document, parse_fragment},
+        visit::Node,
+    };
+
+    fn example() -> HtmlElement {
+        parse_fragment(
+            r#"<div id="main" class="page">
+                <h1>Title</h1>
+                <ul class="menu">
+                    <li><a href="/a">a</a></li>
+                    <li class="active"><a href="/b">b</a></li>
+                    <li><a href="/c">c</a></li>
+                    <li><div><a href="/d">d</a></div></li>
+                </ul>
+                <img src="logo.png">
+            </div>"#,
+        )
+        .unwrap()
+    }
+
+    fn query_all<'a>(elem: &'a HtmlElement, selector: &str) -> Vec<Node<'a>> {
+        elem.query_selector_all(&selector.parse::<Selector>().unwrap())
+    }
+
+    fn hrefs(nodes: Vec<Node>) -> Vec<String> {
+        nodes
+            .iter()
+            .flat_map(|node| node.attributes())
+            .filter_map(|attr| match attr {
+                Attribute::Href(href) => Some(href.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn query_tag() {
+        let elem = example();
+        let result = hrefs(query_all(&elem, "a"));
+        let expected = vec!["/a", "/b", "/c", "/d"];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn query_id_includes_self() {
+        let elem = example();
+        let result = query_all(&elem, "#main");
+        let expected = vec![Node::from(&elem)];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn query_class() {
+        let elem = example();
+        let result: Vec<&str> = query_all(&elem, ".active")
+            .iter()
+            .filter_map(|node| node.tag_name())
+            .collect();
+        let expected = vec!["li"];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn query_all_selector() {
+        let elem = example();
+        let result = query_all(&elem, "*").len();
+        let expected = 13;
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn query_multiple() {
+        let elem = example();
+        let result: Vec<&str> = query_all(&elem, "img, h1")
+            .iter()
+            .filter_map(|node| node.tag_name())
+            .collect();
+        let expected = vec!["h1", "img"];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn query_child_combinator() {
+        let elem = example();
+        let result = hrefs(query_all(&elem, "li > a"));
+        let expected = vec!["/a", "/b", "/c"];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn query_child_chain() {
+        let elem = example();
+        let result = hrefs(query_all(&elem, "ul > li > div > a"));
+        let expected = vec!["/d"];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn query_nth_child() {
+        let elem = example();
+        let result: Vec<Node> = query_all(&elem, "li:nth-child(even)")
+            .iter()
+            .flat_map(|li| li.children())
+            .collect();
+        let expected = vec![query_all(&elem, "a")[1], query_all(&elem, "li > div")[0]];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn query_nth_child_skips_text() {
+        let elem = example();
+        let result = query_all(&elem, "img:nth-child(3)").len();
+        let expected = 1;
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn query_visited_never_matches() {
+        let elem = example();
+        let result = query_all(&elem, "a:visited");
+        assert!(result.is_empty())
+    }
+
+    #[test]
+    fn query_selector_first() {
+        let elem = example();
+        let result = hrefs(
+            elem.query_selector(&"li > a".parse().unwrap())
+                .into_iter()
+                .collect(),
+        );
+        let expected = vec!["/a"];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn query_selector_none() {
+        let elem = example();
+        let result = elem.query_selector(&"table".parse().unwrap());
+        assert_eq!(result, None)
+    }
+
+    #[test]
+    fn query_document() {
+        let doc = parse_document(
+            "<html><head><title>t</title></head><body><p>x</p><div class=\"a\"></div></body></html>",
+        )
+        .unwrap();
+        let result = doc
+            .query_selector_all(&"html > body > .a".parse().unwrap())
+            .len();
+        let expected = 1;
+        assert_eq!(result, expected)
+    }
+}
@@ -1,13 +1,456 @@
+use crate::attribute::Attribute;
+use std::{fmt, io};
+
+/// Elements whose text content has to be emitted byte for byte
+pub const WHITESPACE_SENSITIVE: [&str; 4] = ["pre", "textarea", "script", "style"];
+
+/// Whether the content of `tag` must not be re-indented
+pub fn is_whitespace_sensitive(tag: &str) -> bool {
+    WHITESPACE_SENSITIVE.contains(&tag.to_lowercase().as_str())
+}
+
+/// Elements that flow with the surrounding text, so whitespace next to them is shown
+pub const INLINE_ELEMENTS: [&str; 32] = [
+    "a", "abbr", "audio", "b", "bdi", "bdo", "button", "canvas", "cite", "code", "data", "dfn",
+    "em", "i", "img", "input", "kbd", "label", "mark", "output", "picture", "q", "s", "samp",
+    "select", "small", "span", "strong", "sub", "sup", "textarea", "video",
+];
+
+/// Whether `tag` is laid out within a line of text
+pub fn is_inline_tag(tag: &str) -> bool {
+    INLINE_ELEMENTS.contains(&tag.to_lowercase().as_str())
+}
+
+/// Controls the whitespace emitted while rendering html and css
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// Inserted once per nesting level at the start of every line
+    pub indent: String,
+    /// Separates nodes that are rendered on their own line
+    pub newline: String,
+    /// Drops whitespace that does not change the meaning of the output, e.g. `<div >` becomes `<div>`
+    pub minify: bool,
+    /// Terminates the last declaration of a css block with `;`
+    pub trailing_semicolons: bool,
+    /// Puts a space between a css selector and its `{`
+    pub space_after_selector: bool,
+}
+
+impl RenderOptions {
+    /// Tab-indented output with one node per line, the default
+    pub fn pretty() -> RenderOptions {
+        RenderOptions {
+            indent: "\t".to_owned(),
+            newline: "\n".to_owned(),
+            minify: false,
+            trailing_semicolons: true,
+            space_after_selector: true,
+        }
+    }
+
+    /// One node per line without indentation
+    pub fn compact() -> RenderOptions {
+        RenderOptions {
+            indent: "".to_owned(),
+            ..RenderOptions::pretty()
+        }
+    }
+
+    /// Everything on one line with all insignificant whitespace removed
+    pub fn minified() -> RenderOptions {
+        RenderOptions {
+            indent: "".to_owned(),
+            newline: "".to_owned(),
+            minify: true,
+            trailing_semicolons: false,
+            space_after_selector: false,
+        }
+    }
+}
+
+impl Default for RenderOptions {
+    fn default() -> RenderOptions {
+        RenderOptions::pretty()
+    }
+}
+
+/// Output sink used while rendering.
+/// Every line break written through the renderer is followed by the indentation of the current
+/// nesting level, so each node is emitted exactly once at its final indentation.
+pub struct Renderer<W> {
+    out: W,
+    depth: usize,
+    options: RenderOptions,
+    /// A space separating two inline siblings, written before the next text unless that starts
+    /// with whitespace itself
+    pending_space: bool,
+    /// Whether the last text written ends with whitespace
+    trailing_space: bool,
+}
+
+impl<W: fmt::Write> Renderer<W> {
+    pub fn new(out: W) -> Renderer<W> {
+        Renderer::with_depth(out, 0)
+    }
+
+    pub fn with_depth(out: W, depth: usize) -> Renderer<W> {
+        Renderer {
+            out,
+            depth,
+            options: RenderOptions::default(),
+            pending_space: false,
+            trailing_space: false,
+        }
+    }
+
+    pub fn with_options(self, options: RenderOptions) -> Renderer<W> {
+        Renderer { options, ..self }
+    }
+
+    pub fn options(&self) -> &RenderOptions {
+        &self.options
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    /// Writes text content, indenting every line after the first to the current depth.
+    /// Line breaks in text are content, so they are kept even when rendering minified.
+    pub fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write_pending_space(s)?;
+        let mut lines = s.split('\n');
+        if let Some(first) = lines.next() {
+            self.out.write_str(first)?;
+        }
+        for line in lines {
+            self.out.write_char('\n')?;
+            self.write_indent()?;
+            self.out.write_str(line)?;
+        }
+        Ok(())
+    }
+
+    /// Writes `s` exactly as given, without indenting its lines
+    pub fn write_raw(&mut self, s: &str) -> fmt::Result {
+        self.write_pending_space(s)?;
+        self.out.write_str(s)
+    }
+
+    /// Renders `item` with the options of this renderer but without indentation, for content
+    /// whose whitespace is shown as it is
+    pub fn write_unindented<T: Render>(&mut self, item: &T) -> fmt::Result {
+        let options = RenderOptions {
+            indent: String::new(),
+            ..self.options.clone()
+        };
+        self.write_raw(&item.render_with(&options))
+    }
+
+    fn write_pending_space(&mut self, next: &str) -> fmt::Result {
+        if next.is_empty() {
+            return Ok(());
+        }
+        if std::mem::take(&mut self.pending_space) && !next.starts_with(char::is_whitespace) {
+            self.out.write_char(' ')?;
+        }
+        self.trailing_space = next.ends_with(char::is_whitespace);
+        Ok(())
+    }
+
+    fn write_indent(&mut self) -> fmt::Result {
+        for _ in 0..self.depth {
+            self.out.write_str(&self.options.indent)?;
+        }
+        Ok(())
+    }
+
+    /// Starts a new line at the current depth
+    pub fn newline(&mut self) -> fmt::Result {
+        if self.options.newline.is_empty() {
+            return Ok(());
+        }
+        self.out.write_str(&self.options.newline)?;
+        self.write_indent()
+    }
+
+    /// Writes a single level of indentation
+    pub fn indent(&mut self) -> fmt::Result {
+        self.out.write_str(&self.options.indent)
+    }
+
+    /// Writes a single space unless rendering minified
+    pub fn space(&mut self) -> fmt::Result {
+        if self.options.minify {
+            Ok(())
+        } else {
+            self.out.write_char(' ')
+        }
+    }
+
+    /// Runs `f` one nesting level deeper
+    pub fn nested<F>(&mut self, f: F) -> fmt::Result
+    where
+        F: FnOnce(&mut Renderer<W>) -> fmt::Result,
+    {
+        self.depth += 1;
+        let res = f(self);
+        self.depth -= 1;
+        res
+    }
+
+    /// Writes `f` on its own line one level deeper, then starts a new line at the current depth
+    pub fn block<F>(&mut self, f: F) -> fmt::Result
+    where
+        F: FnOnce(&mut Renderer<W>) -> fmt::Result,
+    {
+        self.nested(|out| {
+            out.newline()?;
+            f(out)
+        })?;
+        self.newline()
+    }
+
+    /// Writes `items` separated by `sep`
+    pub fn write_joined<T: Render>(&mut self, items: &[T], sep: &str) -> fmt::Result {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.write_str(sep)?;
+            }
+            item.render_into(self)?;
+        }
+        Ok(())
+    }
+
+    /// Writes `items` on separate lines.
+    /// Without line breaks, inline siblings are separated by a single space instead, so words
+    /// are not glued together.
+    pub fn write_lines<T: Render>(&mut self, items: &[T]) -> fmt::Result {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                let inline = items[i - 1].is_inline() && item.is_inline();
+                if inline && self.options.newline.is_empty() {
+                    self.pending_space = !self.trailing_space;
+                } else {
+                    self.newline()?;
+                }
+            }
+            item.render_into(self)?;
+        }
+        self.pending_space = false;
+        Ok(())
+    }
+
+    /// Writes the attributes of an opening tag including the leading space.
+    /// When minified, the space is left out for tags without attributes.
+    /// Attributes with invalid names are skipped.
+    pub fn write_attributes(&mut self, attributes: &[Attribute]) -> fmt::Result {
+        let mut attributes = attributes.iter().filter(|attr| attr.has_valid_name());
+        let first = attributes.next();
+        if first.is_some() || !self.options.minify {
+            self.out.write_char(' ')?;
+        }
+        for (i, attr) in first.into_iter().chain(attributes).enumerate() {
+            if i > 0 {
+                self.out.write_char(' ')?;
+            }
+            attr.render_into(self)?;
+        }
+        Ok(())
+    }
+}
+
+/// Adapter to stream into an [`io::Write`], keeping the underlying io error around
+struct IoWriter<W> {
+    inner: W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> fmt::Write for IoWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
+    }
+}
+
+/// Types that can be rendered to html or css.
+/// Implementations write into a [`Renderer`], so children are streamed instead of re-indented.
 pub trait Render {
-    fn render(&self) -> String;
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result;
+
+    /// Whether the output is laid out within a line of text, see [`Renderer::write_lines`]
+    fn is_inline(&self) -> bool {
+        false
+    }
+
+    fn render(&self) -> String {
+        self.render_with(&RenderOptions::default())
+    }
+
+    fn render_with(&self, options: &RenderOptions) -> String {
+        let mut rendered = String::new();
+        self.render_into(&mut Renderer::new(&mut rendered).with_options(options.clone()))
+            .expect("Writing to a String cannot fail");
+        rendered
+    }
+
+    /// Writes the rendered output to `out`, with line breaks indented to `depth`
+    fn render_to<W: fmt::Write>(&self, out: &mut W, depth: usize) -> fmt::Result {
+        self.render_into(&mut Renderer::with_depth(out, depth))
+    }
+
+    /// Same as [`Render::render_to`] for byte sinks such as files or sockets
+    fn write_to<W: io::Write>(&self, out: &mut W, depth: usize) -> io::Result<()> {
+        let mut writer = IoWriter {
+            inner: out,
+            error: None,
+        };
+        match self.render_to(&mut writer, depth) {
+            Ok(()) => Ok(()),
+            Err(err) => Err(writer.error.unwrap_or_else(|| io::Error::other(err))),
+        }
+    }
 }
 
 impl<T: Render> Render for Vec<T> {
-    fn render(&self) -> String {
-        let mut t_strs = vec![];
-        for t in self.iter() {
-            t_strs.push(t.render());
+    fn render_into<W: fmt::Write>(&self, out: &mut Renderer<W>) -> fmt::Result {
+        out.write_lines(self)
+    }
+}
+
+#[cfg(test)]
+mod render_tests {
+    use super::{is_whitespace_sensitive, Render, RenderOptions, Renderer};
+    use crate::elements::{Build, Div, HtmlElement, Span, WithChildren, A, P};
+    use std::rc::Rc;
+
+    fn nested_divs(depth: usize) -> HtmlElement {
+        let mut elem: HtmlElement = "leaf".to_owned().into();
+        for _ in 0..depth {
+            elem = Div {
+                attributes: vec![],
+                content: Rc::new(elem),
+            }
+            .into();
+        }
+        elem
+    }
+
+    #[test]
+    fn write_str_indents() {
+        let mut out = String::new();
+        let mut renderer = Renderer::with_depth(&mut out, 2);
+        renderer.write_str("a\nb").unwrap();
+        assert_eq!(out, "a\n\t\tb")
+    }
+
+    #[test]
+    fn render_to_depth() {
+        let mut result = String::new();
+        nested_divs(1).render_to(&mut result, 1).unwrap();
+        let expected = "<div >\n\t\tleaf\n\t</div>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn write_to_io() {
+        let mut result: Vec<u8> = vec![];
+        nested_divs(2).write_to(&mut result, 0).unwrap();
+        let expected = nested_divs(2).render();
+        assert_eq!(String::from_utf8(result).unwrap(), expected)
+    }
+
+    #[test]
+    fn render_deeply_nested() {
+        let depth = 1000;
+        let result = nested_divs(depth).render();
+        let mut expected = String::new();
+        for i in 0..depth {
+            expected.push_str(&"\t".repeat(i));
+            expected.push_str("<div >\n");
+        }
+        expected.push_str(&"\t".repeat(depth));
+        expected.push_str("leaf");
+        for i in (0..depth).rev() {
+            expected.push('\n');
+            expected.push_str(&"\t".repeat(i));
+            expected.push_str("</div>");
         }
-        t_strs.join("\n")
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_compact() {
+        let result = nested_divs(2).render_with(&RenderOptions::compact());
+        let expected = "<div >\n<div >\nleaf\n</div>\n</div>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_minified() {
+        let result = nested_divs(2).render_with(&RenderOptions::minified());
+        let expected = "<div><div>leaf</div></div>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn minified_separates_inline_siblings() {
+        let result = [
+            Div::new()
+                .text("Hello")
+                .child(A::new().text("link"))
+                .text("there")
+                .build(),
+            Div::new()
+                .text("Hello ")
+                .child(Span::new().text("x"))
+                .build(),
+            Div::new().child(P::new().text("a")).text("b").build(),
+        ]
+        .map(|elem| elem.render_with(&RenderOptions::minified()));
+        let expected = [
+            "<div>Hello <a>link</a> there</div>",
+            "<div>Hello <span>x</span></div>",
+            "<div><p>a</p>b</div>",
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn render_custom_indent() {
+        let options = RenderOptions {
+            indent: "  ".to_owned(),
+            ..RenderOptions::pretty()
+        };
+        let result = nested_divs(2).render_with(&options);
+        let expected = "<div >\n  <div >\n    leaf\n  </div>\n</div>";
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn minified_keeps_text_breaks() {
+        let mut out = String::new();
+        let mut renderer = Renderer::new(&mut out).with_options(RenderOptions::minified());
+        renderer.write_str("a\nb").unwrap();
+        assert_eq!(out, "a\nb")
+    }
+
+    #[test]
+    fn write_raw_keeps_lines() {
+        let mut out = String::new();
+        let mut renderer = Renderer::with_depth(&mut out, 2);
+        renderer.write_raw("a\n  b").unwrap();
+        assert_eq!(out, "a\n  b")
+    }
+
+    #[test]
+    fn whitespace_sensitive_tags() {
+        assert!(is_whitespace_sensitive("pre"));
+        assert!(is_whitespace_sensitive("TEXTAREA"));
+        assert!(is_whitespace_sensitive("script"));
+        assert!(is_whitespace_sensitive("style"));
+        assert!(!is_whitespace_sensitive("div"))
     }
 }
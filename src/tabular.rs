@@ -0,0 +1,533 @@
+//! Building [`Table`]s from rows of Rust values.
+use crate::{
+    attribute::Scope,
+    elements::{HtmlElement, Table, TableSection, Td, Th, Tr, WithAttributes, WithChildren},
+};
+use chrono::{NaiveDate, NaiveDateTime};
+use std::{
+    error::Error,
+    fmt::{self, Write},
+};
+
+/// Derives [`Tabular`](trait@Tabular) for a struct with named fields.
+///
+/// Every field becomes a column headed by its name, fields have to implement [`ToCell`].
+/// `#[tabular(rename = "..")]` changes the header and `#[tabular(skip)]` leaves out a field.
+///
+/// ```
+/// use html::{elements::Table, render::Render, tabular::Tabular};
+///
+/// #[derive(Tabular)]
+/// struct Fruit {
+///     #[tabular(rename = "Fruit")]
+///     name: &'static str,
+///     #[tabular(skip)]
+///     #[allow(dead_code)]
+///     color: &'static str,
+///     count: u32,
+/// }
+///
+/// let table = Table::from_rows(&[Fruit { name: "pear", color: "green", count: 3 }]);
+/// assert!(table.render().contains("<th scope=\"col\">\n\t\t\t\tFruit\n\t\t\t</th>"));
+/// ```
+pub use html_macros::Tabular;
+
+/// Style of cells holding numbers
+pub const NUMERIC_STYLE: &str = "text-align:right;";
+
+/// Values that can be shown as rows of a table
+pub trait Tabular {
+    /// Headers of the columns, in the same order as the cells
+    fn headers() -> Vec<&'static str>;
+    fn cells(&self) -> Vec<CellValue>;
+}
+
+impl<T: Tabular> Tabular for &T {
+    fn headers() -> Vec<&'static str> {
+        T::headers()
+    }
+
+    fn cells(&self) -> Vec<CellValue> {
+        (*self).cells()
+    }
+}
+
+/// The value of a single cell before formatting
+#[derive(Clone, Debug, PartialEq)]
+pub enum CellValue {
+    Empty,
+    Text(String),
+    Bool(bool),
+    Integer(i128),
+    Float(f64),
+    Date(NaiveDate),
+    DateTime(NaiveDateTime),
+}
+
+impl CellValue {
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, CellValue::Integer(_) | CellValue::Float(_))
+    }
+}
+
+/// Values that can be shown in a table cell
+pub trait ToCell {
+    fn to_cell(&self) -> CellValue;
+}
+
+impl<T: ToCell + ?Sized> ToCell for &T {
+    fn to_cell(&self) -> CellValue {
+        (*self).to_cell()
+    }
+}
+
+/// `None` leaves the cell empty
+impl<T: ToCell> ToCell for Option<T> {
+    fn to_cell(&self) -> CellValue {
+        self.as_ref().map(T::to_cell).unwrap_or(CellValue::Empty)
+    }
+}
+
+impl ToCell for str {
+    fn to_cell(&self) -> CellValue {
+        CellValue::Text(self.to_owned())
+    }
+}
+
+impl ToCell for String {
+    fn to_cell(&self) -> CellValue {
+        CellValue::Text(self.clone())
+    }
+}
+
+impl ToCell for bool {
+    fn to_cell(&self) -> CellValue {
+        CellValue::Bool(*self)
+    }
+}
+
+impl ToCell for NaiveDate {
+    fn to_cell(&self) -> CellValue {
+        CellValue::Date(*self)
+    }
+}
+
+impl ToCell for NaiveDateTime {
+    fn to_cell(&self) -> CellValue {
+        CellValue::DateTime(*self)
+    }
+}
+
+macro_rules! integer_cells {
+    ($($ty:ty),*) => {
+        $(impl ToCell for $ty {
+            fn to_cell(&self) -> CellValue {
+                CellValue::Integer(*self as i128)
+            }
+        })*
+    };
+}
+integer_cells!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl ToCell for f32 {
+    fn to_cell(&self) -> CellValue {
+        CellValue::Float(*self as f64)
+    }
+}
+
+impl ToCell for f64 {
+    fn to_cell(&self) -> CellValue {
+        CellValue::Float(*self)
+    }
+}
+
+type Formatter = Box<dyn Fn(&CellValue) -> HtmlElement>;
+
+/// Which columns [`Table::from_rows_with`] shows and how their cells are formatted
+pub struct Columns {
+    selected: Option<Vec<String>>,
+    formatters: Vec<(String, Formatter)>,
+    date_format: String,
+    datetime_format: String,
+}
+
+/// All columns, dates as `2024-01-31` and date times as `2024-01-31 12:30`
+impl Default for Columns {
+    fn default() -> Columns {
+        Columns {
+            selected: None,
+            formatters: vec![],
+            date_format: "%Y-%m-%d".to_owned(),
+            datetime_format: "%Y-%m-%d %H:%M".to_owned(),
+        }
+    }
+}
+
+/// A format string chrono can not write, such as `%Q` or a time of day for dates
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidFormat(pub String);
+
+impl fmt::Display for InvalidFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "`{}` is no valid format", self.0)
+    }
+}
+
+impl Error for InvalidFormat {}
+
+/// Whether `formatted` can be written, chrono reports invalid formats only while writing
+fn writes(formatted: impl fmt::Display) -> bool {
+    write!(String::new(), "{formatted}").is_ok()
+}
+
+impl Columns {
+    pub fn new() -> Columns {
+        Columns::default()
+    }
+
+    /// Shows only the given columns in the given order, unknown headers are ignored
+    pub fn select<S, I>(mut self, headers: I) -> Columns
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = S>,
+    {
+        self.selected = Some(headers.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Formats the cells of a column, replacing the default formatting
+    pub fn format<F, E>(mut self, header: &str, formatter: F) -> Columns
+    where
+        F: Fn(&CellValue) -> E + 'static,
+        E: Into<HtmlElement>,
+    {
+        self.formatters.retain(|(name, _)| name != header);
+        self.formatters.push((
+            header.to_owned(),
+            Box::new(move |value| formatter(value).into()),
+        ));
+        self
+    }
+
+    /// A [`chrono` format string](chrono::format::strftime) for dates
+    pub fn date_format(mut self, format: &str) -> Result<Columns, InvalidFormat> {
+        if !writes(NaiveDate::MIN.format(format)) {
+            return Err(InvalidFormat(format.to_owned()));
+        }
+        self.date_format = format.to_owned();
+        Ok(self)
+    }
+
+    /// A [`chrono` format string](chrono::format::strftime) for date times
+    pub fn datetime_format(mut self, format: &str) -> Result<Columns, InvalidFormat> {
+        if !writes(NaiveDateTime::MIN.format(format)) {
+            return Err(InvalidFormat(format.to_owned()));
+        }
+        self.datetime_format = format.to_owned();
+        Ok(self)
+    }
+
+    /// Positions of the shown columns among all headers
+    fn indices(&self, headers: &[&str]) -> Vec<usize> {
+        match &self.selected {
+            None => (0..headers.len()).collect(),
+            Some(selected) => selected
+                .iter()
+                .filter_map(|name| headers.iter().position(|header| header == name))
+                .collect(),
+        }
+    }
+
+    fn format_cell(&self, header: &str, value: &CellValue) -> HtmlElement {
+        if let Some((_, formatter)) = self.formatters.iter().find(|(name, _)| name == header) {
+            return formatter(value);
+        }
+        match value {
+            CellValue::Empty => HtmlElement::default(),
+            CellValue::Text(text) => text.as_str().into(),
+            CellValue::Bool(b) => b.to_string().into(),
+            CellValue::Integer(n) => n.to_string().into(),
+            CellValue::Float(x) => x.to_string().into(),
+            CellValue::Date(date) => date.format(&self.date_format).to_string().into(),
+            CellValue::DateTime(datetime) => {
+                datetime.format(&self.datetime_format).to_string().into()
+            }
+        }
+    }
+}
+
+impl Table {
+    /// A table with a header row and a body row for every item, showing all columns
+    pub fn from_rows<T, I>(rows: I) -> Table
+    where
+        T: Tabular,
+        I: IntoIterator<Item = T>,
+    {
+        Table::from_rows_with(rows, &Columns::default())
+    }
+
+    /// A table with a header row and a body row for every item
+    ///
+    /// Numeric cells are right aligned with [`NUMERIC_STYLE`], as is the header of a column
+    /// whose non-empty cells are all numeric. Rows with fewer cells than headers are filled up
+    /// with empty cells.
+    pub fn from_rows_with<T, I>(rows: I, columns: &Columns) -> Table
+    where
+        T: Tabular,
+        I: IntoIterator<Item = T>,
+    {
+        let headers = T::headers();
+        let indices = columns.indices(&headers);
+        let rows = rows.into_iter().map(|row| {
+            let cells = row.cells();
+            indices
+                .iter()
+                .map(|&i| {
+                    let value = cells.get(i).cloned().unwrap_or(CellValue::Empty);
+                    let content = columns.format_cell(headers[i], &value);
+                    (value, content)
+                })
+                .collect()
+        });
+        aligned_table(
+            Some(indices.iter().map(|&i| headers[i].to_owned()).collect()),
+            rows.collect(),
+        )
+    }
+}
+
+/// A table of formatted cells, right aligning numeric values and the headers of numeric columns
+pub(crate) fn aligned_table(
+    headers: Option<Vec<String>>,
+    rows: Vec<Vec<(CellValue, HtmlElement)>>,
+) -> Table {
+    let body = rows.iter().fold(TableSection::body(), |body, row| {
+        body.row(row.iter().fold(Tr::new(), |tr, (value, content)| {
+            let td = Td::new().child(content.clone());
+            tr.child(if value.is_numeric() {
+                td.style(NUMERIC_STYLE)
+            } else {
+                td
+            })
+        }))
+    });
+    let Some(headers) = headers else {
+        return Table::new().section(body);
+    };
+
+    let head = headers
+        .iter()
+        .enumerate()
+        .fold(Tr::new(), |tr, (i, header)| {
+            let column = || {
+                rows.iter()
+                    .filter_map(|row| row.get(i).map(|(value, _)| value))
+            };
+            let numeric = column().all(|value| value.is_numeric() || *value == CellValue::Empty)
+                && column().any(CellValue::is_numeric);
+            let th = Th::new().scope(Scope::Col).text(header);
+            tr.child(if numeric { th.style(NUMERIC_STYLE) } else { th })
+        });
+    Table::new()
+        .section(TableSection::head().row(head))
+        .section(body)
+}
+
+#[cfg(test)]
+mod tabular_tests {
+    use super::{CellValue, Columns, InvalidFormat, Tabular, ToCell, NUMERIC_STYLE};
+    use crate::{
+        attribute::{Attribute, Scope},
+        elements::{HtmlElement, Table, TableSection, Td, Th, Tr, WithAttributes, WithChildren, A},
+    };
+    use chrono::NaiveDate;
+
+    #[derive(Tabular)]
+    struct Fruit {
+        name: String,
+        #[tabular(rename = "Count")]
+        count: u32,
+        #[tabular(skip)]
+        #[allow(dead_code)]
+        secret: (),
+        price: Option<f64>,
+        harvested: NaiveDate,
+    }
+
+    fn fruits() -> Vec<Fruit> {
+        vec![
+            Fruit {
+                name: "pear".to_owned(),
+                count: 3,
+                secret: (),
+                price: Some(1.5),
+                harvested: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+            },
+            Fruit {
+                name: "apple".to_owned(),
+                count: 10,
+                secret: (),
+                price: None,
+                harvested: NaiveDate::from_ymd_opt(2024, 10, 15).unwrap(),
+            },
+        ]
+    }
+
+    fn head(headers: &[(&str, bool)]) -> TableSection {
+        TableSection::head().row(headers.iter().fold(Tr::new(), |tr, (header, numeric)| {
+            let th = Th::new().scope(Scope::Col).text(header);
+            tr.child(if *numeric {
+                th.style(NUMERIC_STYLE)
+            } else {
+                th
+            })
+        }))
+    }
+
+    fn text(s: &str) -> Td {
+        Td::new().text(s)
+    }
+
+    fn number(s: &str) -> Td {
+        Td::new().text(s).style(NUMERIC_STYLE)
+    }
+
+    #[test]
+    fn derive_headers() {
+        let result = Fruit::headers();
+        let expected = vec!["name", "Count", "price", "harvested"];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn derive_cells() {
+        let result = fruits()[1].cells();
+        let expected = vec![
+            CellValue::Text("apple".to_owned()),
+            CellValue::Integer(10),
+            CellValue::Empty,
+            CellValue::Date(NaiveDate::from_ymd_opt(2024, 10, 15).unwrap()),
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn option_cells() {
+        let result = (Some(-2i8).to_cell(), None::<&str>.to_cell(), true.to_cell());
+        let expected = (
+            CellValue::Integer(-2),
+            CellValue::Empty,
+            CellValue::Bool(true),
+        );
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn table_from_rows() {
+        let result = Table::from_rows(fruits());
+        let expected = Table::new()
+            .section(head(&[
+                ("name", false),
+                ("Count", true),
+                ("price", true),
+                ("harvested", false),
+            ]))
+            .section(
+                TableSection::body()
+                    .row(
+                        Tr::new()
+                            .child(text("pear"))
+                            .child(number("3"))
+                            .child(number("1.5"))
+                            .child(text("2024-09-01")),
+                    )
+                    .row(
+                        Tr::new()
+                            .child(text("apple"))
+                            .child(number("10"))
+                            .child(Td::new())
+                            .child(text("2024-10-15")),
+                    ),
+            );
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn table_from_no_rows() {
+        let result = Table::from_rows(Vec::<Fruit>::new());
+        let expected = Table::new()
+            .section(head(&[
+                ("name", false),
+                ("Count", false),
+                ("price", false),
+                ("harvested", false),
+            ]))
+            .section(TableSection::body());
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn table_select_and_format() {
+        let columns = Columns::new()
+            .select(["harvested", "unknown", "name"])
+            .date_format("%d.%m.%Y")
+            .unwrap()
+            .format("name", |value| match value {
+                CellValue::Text(name) => A::new().href(&format!("/{name}")).text(name).into(),
+                _ => HtmlElement::default(),
+            });
+        let result = Table::from_rows_with(fruits().iter().take(1), &columns);
+        let expected = Table::new()
+            .section(head(&[("harvested", false), ("name", false)]))
+            .section(
+                TableSection::body().row(
+                    Tr::new()
+                        .child(text("01.09.2024"))
+                        .child(Td::new().child(A::new().href("/pear").text("pear"))),
+                ),
+            );
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn invalid_formats() {
+        let result = [
+            Columns::new().date_format("%Y-%Q").err(),
+            Columns::new().date_format("%H:%M").err(),
+            Columns::new().datetime_format("%Y %Z").err(),
+            Columns::new().datetime_format("%d.%m.%Y %H:%M").err(),
+        ];
+        let expected = [
+            Some(InvalidFormat("%Y-%Q".to_owned())),
+            Some(InvalidFormat("%H:%M".to_owned())),
+            Some(InvalidFormat("%Y %Z".to_owned())),
+            None,
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn table_from_short_rows() {
+        struct Short;
+        impl Tabular for Short {
+            fn headers() -> Vec<&'static str> {
+                vec!["a", "b"]
+            }
+            fn cells(&self) -> Vec<CellValue> {
+                vec![CellValue::Integer(1)]
+            }
+        }
+        let result = Table::from_rows([Short]);
+        let expected = Table::new()
+            .section(head(&[("a", true), ("b", false)]))
+            .section(TableSection::body().row(Tr::new().child(number("1")).child(Td::new())));
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn numeric_style() {
+        let result = number("1").attributes;
+        let expected = vec![Attribute::Style("text-align:right;".to_owned())];
+        assert_eq!(result, expected)
+    }
+}
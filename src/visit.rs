@@ -0,0 +1,1537 @@
+//! Generic traversal of html trees.
+//!
+//! Both [`Visitor`] and [`VisitMut`] walk the tree depth first in document order, i.e. in the
+//! order the nodes are rendered:
+//! a node is entered before any of its children and left after all of them.
+//! Children are visited as they are stored, so a [`HtmlElement::ComponentList`] is a node of its own.
+//! [`Figure`] visits its content before its caption, [`Table`] its parts in the order they are
+//! rendered, [`TableSection`] its rows, [`Colgroup`] its columns, [`Tr`] its cells,
+//! [`Ul`] and [`Ol`] their items, [`Select`] and [`Datalist`] their options,
+//! [`Fieldset`] its legend before its content, [`Video`] and [`Audio`] their sources and tracks
+//! before their content, [`Picture`] its sources before its content and [`Element`] its children.
+//! The title and the typed metadata of a [`Head`] are no nodes.
+use crate::{
+    attribute::Attribute,
+    elements::{
+        Abbr, Article, Aside, Audio, Blockquote, Body, Button, Canvas, Caption, Cell, Code, Col,
+        Colgroup, Datalist, Div, Element, Em, Fieldset, Figure, Footer, Form, Head, Header,
+        HeaderSize, Headline, Hr, HtmlElement, Img, Input, Label, Legend, Li, Link, Literal, Main,
+        Mark, Meta, Nav, Ol, Output, Picture, Pre, Script, Section, Select, SelectOption, Small,
+        Source, Span, Strong, Style, Sub, Sup, Table, TableSection, Td, Textarea, Th, Tr, Track,
+        Ul, Video, A, P,
+    },
+    html_document::HtmlDocument,
+};
+use std::rc::Rc;
+
+/// Borrowed view of any node in a html tree.
+/// Unlike [`HtmlElement`] this also covers list items, the parts of tables and select options,
+/// which are stored directly in their parents.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Node<'a> {
+    Head(&'a Head),
+    Body(&'a Body),
+    Div(&'a Div),
+    Link(&'a Link),
+    A(&'a A),
+    Literal(&'a Literal),
+    Raw(&'a str),
+    Table(&'a Table),
+    Tr(&'a Tr),
+    Td(&'a Td),
+    Th(&'a Th),
+    TableSection(&'a TableSection),
+    Caption(&'a Caption),
+    Colgroup(&'a Colgroup),
+    Col(&'a Col),
+    Headline(&'a Headline),
+    Br,
+    Figure(&'a Figure),
+    Img(&'a Img),
+    Canvas(&'a Canvas),
+    Input(&'a Input),
+    Select(&'a Select),
+    SelectOption(&'a SelectOption),
+    Script(&'a Script),
+    ComponentList(&'a [HtmlElement]),
+    Style(&'a Style),
+    Ul(&'a Ul),
+    Ol(&'a Ol),
+    Li(&'a Li),
+    Section(&'a Section),
+    Article(&'a Article),
+    Nav(&'a Nav),
+    Header(&'a Header),
+    Footer(&'a Footer),
+    Main(&'a Main),
+    Aside(&'a Aside),
+    P(&'a P),
+    Blockquote(&'a Blockquote),
+    Pre(&'a Pre),
+    Span(&'a Span),
+    Code(&'a Code),
+    Strong(&'a Strong),
+    Em(&'a Em),
+    Small(&'a Small),
+    Mark(&'a Mark),
+    Sub(&'a Sub),
+    Sup(&'a Sup),
+    Abbr(&'a Abbr),
+    Hr(&'a Hr),
+    Form(&'a Form),
+    Label(&'a Label),
+    Button(&'a Button),
+    Textarea(&'a Textarea),
+    Fieldset(&'a Fieldset),
+    Legend(&'a Legend),
+    Datalist(&'a Datalist),
+    Output(&'a Output),
+    Video(&'a Video),
+    Audio(&'a Audio),
+    Source(&'a Source),
+    Track(&'a Track),
+    Picture(&'a Picture),
+    Meta(&'a Meta),
+    Element(&'a Element),
+}
+
+impl<'a> From<&'a HtmlElement> for Node<'a> {
+    fn from(elem: &'a HtmlElement) -> Node<'a> {
+        match elem {
+            HtmlElement::Head(head) => Node::Head(head),
+            HtmlElement::Body(body) => Node::Body(body),
+            HtmlElement::Div(div) => Node::Div(div),
+            HtmlElement::Link(link) => Node::Link(link),
+            HtmlElement::A(a) => Node::A(a),
+            HtmlElement::Literal(literal) => Node::Literal(literal),
+            HtmlElement::Raw(raw) => Node::Raw(raw),
+            HtmlElement::Table(table) => Node::Table(table),
+            HtmlElement::Tr(tr) => Node::Tr(tr),
+            HtmlElement::Td(td) => Node::Td(td),
+            HtmlElement::Th(th) => Node::Th(th),
+            HtmlElement::TableSection(section) => Node::TableSection(section),
+            HtmlElement::Caption(caption) => Node::Caption(caption),
+            HtmlElement::Colgroup(colgroup) => Node::Colgroup(colgroup),
+            HtmlElement::Col(col) => Node::Col(col),
+            HtmlElement::Headline(headline) => Node::Headline(headline),
+            HtmlElement::Br => Node::Br,
+            HtmlElement::Figure(figure) => Node::Figure(figure),
+            HtmlElement::Img(img) => Node::Img(img),
+            HtmlElement::Canvas(canvas) => Node::Canvas(canvas),
+            HtmlElement::Input(input) => Node::Input(input),
+            HtmlElement::Select(select) => Node::Select(select),
+            HtmlElement::Script(script) => Node::Script(script),
+            HtmlElement::ComponentList(elements) => Node::ComponentList(elements),
+            HtmlElement::Style(style) => Node::Style(style),
+            HtmlElement::Ul(ul) => Node::Ul(ul),
+            HtmlElement::Ol(ol) => Node::Ol(ol),
+            HtmlElement::Section(section) => Node::Section(section),
+            HtmlElement::Article(article) => Node::Article(article),
+            HtmlElement::Nav(nav) => Node::Nav(nav),
+            HtmlElement::Header(header) => Node::Header(header),
+            HtmlElement::Footer(footer) => Node::Footer(footer),
+            HtmlElement::Main(main) => Node::Main(main),
+            HtmlElement::Aside(aside) => Node::Aside(aside),
+            HtmlElement::P(p) => Node::P(p),
+            HtmlElement::Blockquote(blockquote) => Node::Blockquote(blockquote),
+            HtmlElement::Pre(pre) => Node::Pre(pre),
+            HtmlElement::Span(span) => Node::Span(span),
+            HtmlElement::Code(code) => Node::Code(code),
+            HtmlElement::Strong(strong) => Node::Strong(strong),
+            HtmlElement::Em(em) => Node::Em(em),
+            HtmlElement::Small(small) => Node::Small(small),
+            HtmlElement::Mark(mark) => Node::Mark(mark),
+            HtmlElement::Sub(sub) => Node::Sub(sub),
+            HtmlElement::Sup(sup) => Node::Sup(sup),
+            HtmlElement::Abbr(abbr) => Node::Abbr(abbr),
+            HtmlElement::Hr(hr) => Node::Hr(hr),
+            HtmlElement::Form(form) => Node::Form(form),
+            HtmlElement::Label(label) => Node::Label(label),
+            HtmlElement::Button(button) => Node::Button(button),
+            HtmlElement::Textarea(textarea) => Node::Textarea(textarea),
+            HtmlElement::Fieldset(fieldset) => Node::Fieldset(fieldset),
+            HtmlElement::Legend(legend) => Node::Legend(legend),
+            HtmlElement::Datalist(datalist) => Node::Datalist(datalist),
+            HtmlElement::Output(output) => Node::Output(output),
+            HtmlElement::Video(video) => Node::Video(video),
+            HtmlElement::Audio(audio) => Node::Audio(audio),
+            HtmlElement::Source(source) => Node::Source(source),
+            HtmlElement::Track(track) => Node::Track(track),
+            HtmlElement::Picture(picture) => Node::Picture(picture),
+            HtmlElement::Meta(meta) => Node::Meta(meta),
+            HtmlElement::Element(elem) => Node::Element(elem),
+        }
+    }
+}
+
+impl<'a> From<&'a Cell> for Node<'a> {
+    fn from(cell: &'a Cell) -> Node<'a> {
+        match cell {
+            Cell::Td(td) => Node::Td(td),
+            Cell::Th(th) => Node::Th(th),
+        }
+    }
+}
+
+impl<'a> Node<'a> {
+    /// The tag this node is rendered with, `None` for text and lists of components
+    pub fn tag_name(&self) -> Option<&'a str> {
+        let tag = match *self {
+            Node::Head(_) => "head",
+            Node::Body(_) => "body",
+            Node::Div(_) => "div",
+            Node::Link(_) => "link",
+            Node::A(_) => "a",
+            Node::Table(_) => "table",
+            Node::Tr(_) => "tr",
+            Node::Td(_) => "td",
+            Node::Th(_) => "th",
+            Node::TableSection(section) => section.kind.tag(),
+            Node::Caption(_) => "caption",
+            Node::Colgroup(_) => "colgroup",
+            Node::Col(_) => "col",
+            Node::Headline(headline) => match headline.size {
+                HeaderSize::H1 => "h1",
+                HeaderSize::H2 => "h2",
+                HeaderSize::H3 => "h3",
+                HeaderSize::H4 => "h4",
+                HeaderSize::H5 => "h5",
+                HeaderSize::H6 => "h6",
+            },
+            Node::Br => "br",
+            Node::Figure(_) => "figure",
+            Node::Img(_) => "img",
+            Node::Canvas(_) => "canvas",
+            Node::Input(_) => "input",
+            Node::Select(_) => "select",
+            Node::SelectOption(_) => "option",
+            Node::Script(_) => "script",
+            Node::Style(_) => "style",
+            Node::Ul(_) => "ul",
+            Node::Ol(_) => "ol",
+            Node::Li(_) => "li",
+            Node::Section(_) => "section",
+            Node::Article(_) => "article",
+            Node::Nav(_) => "nav",
+            Node::Header(_) => "header",
+            Node::Footer(_) => "footer",
+            Node::Main(_) => "main",
+            Node::Aside(_) => "aside",
+            Node::P(_) => "p",
+            Node::Blockquote(_) => "blockquote",
+            Node::Pre(_) => "pre",
+            Node::Span(_) => "span",
+            Node::Code(_) => "code",
+            Node::Strong(_) => "strong",
+            Node::Em(_) => "em",
+            Node::Small(_) => "small",
+            Node::Mark(_) => "mark",
+            Node::Sub(_) => "sub",
+            Node::Sup(_) => "sup",
+            Node::Abbr(_) => "abbr",
+            Node::Hr(_) => "hr",
+            Node::Form(_) => "form",
+            Node::Label(_) => "label",
+            Node::Button(_) => "button",
+            Node::Textarea(_) => "textarea",
+            Node::Fieldset(_) => "fieldset",
+            Node::Legend(_) => "legend",
+            Node::Datalist(_) => "datalist",
+            Node::Output(_) => "output",
+            Node::Video(_) => "video",
+            Node::Audio(_) => "audio",
+            Node::Source(_) => "source",
+            Node::Track(_) => "track",
+            Node::Picture(_) => "picture",
+            Node::Meta(_) => "meta",
+            Node::Element(elem) => &elem.tag,
+            Node::Literal(_) | Node::Raw(_) | Node::ComponentList(_) => return None,
+        };
+        Some(tag)
+    }
+
+    /// Whether this node stores attributes, i.e. whether [`VisitMut::visit_attributes_mut`] is
+    /// called for it
+    pub fn accepts_attributes(&self) -> bool {
+        !matches!(
+            self,
+            Node::Head(_)
+                | Node::Literal(_)
+                | Node::Raw(_)
+                | Node::Br
+                | Node::SelectOption(_)
+                | Node::Style(_)
+                | Node::ComponentList(_)
+        )
+    }
+
+    /// The attributes of this node, empty for nodes that cannot have any
+    pub fn attributes(&self) -> &'a [Attribute] {
+        match *self {
+            Node::Body(Body { attributes, .. })
+            | Node::Div(Div { attributes, .. })
+            | Node::Link(Link { attributes })
+            | Node::A(A { attributes, .. })
+            | Node::Table(Table { attributes, .. })
+            | Node::Tr(Tr { attributes, .. })
+            | Node::Td(Td { attributes, .. })
+            | Node::Th(Th { attributes, .. })
+            | Node::TableSection(TableSection { attributes, .. })
+            | Node::Caption(Caption { attributes, .. })
+            | Node::Colgroup(Colgroup { attributes, .. })
+            | Node::Col(Col { attributes })
+            | Node::Headline(Headline { attributes, .. })
+            | Node::Figure(Figure { attributes, .. })
+            | Node::Img(Img { attributes })
+            | Node::Canvas(Canvas { attributes })
+            | Node::Input(Input { attributes, .. })
+            | Node::Select(Select { attributes, .. })
+            | Node::Script(Script { attributes, .. })
+            | Node::Ul(Ul { attributes, .. })
+            | Node::Ol(Ol { attributes, .. })
+            | Node::Li(Li { attributes, .. })
+            | Node::Section(Section { attributes, .. })
+            | Node::Article(Article { attributes, .. })
+            | Node::Nav(Nav { attributes, .. })
+            | Node::Header(Header { attributes, .. })
+            | Node::Footer(Footer { attributes, .. })
+            | Node::Main(Main { attributes, .. })
+            | Node::Aside(Aside { attributes, .. })
+            | Node::P(P { attributes, .. })
+            | Node::Blockquote(Blockquote { attributes, .. })
+            | Node::Pre(Pre { attributes, .. })
+            | Node::Span(Span { attributes, .. })
+            | Node::Code(Code { attributes, .. })
+            | Node::Strong(Strong { attributes, .. })
+            | Node::Em(Em { attributes, .. })
+            | Node::Small(Small { attributes, .. })
+            | Node::Mark(Mark { attributes, .. })
+            | Node::Sub(Sub { attributes, .. })
+            | Node::Sup(Sup { attributes, .. })
+            | Node::Abbr(Abbr { attributes, .. })
+            | Node::Hr(Hr { attributes })
+            | Node::Form(Form { attributes, .. })
+            | Node::Label(Label { attributes, .. })
+            | Node::Button(Button { attributes, .. })
+            | Node::Textarea(Textarea { attributes, .. })
+            | Node::Fieldset(Fieldset { attributes, .. })
+            | Node::Legend(Legend { attributes, .. })
+            | Node::Datalist(Datalist { attributes, .. })
+            | Node::Output(Output { attributes, .. })
+            | Node::Video(Video { attributes, .. })
+            | Node::Audio(Audio { attributes, .. })
+            | Node::Source(Source { attributes })
+            | Node::Track(Track { attributes })
+            | Node::Picture(Picture { attributes, .. })
+            | Node::Meta(Meta { attributes })
+            | Node::Element(Element { attributes, .. }) => attributes,
+            Node::Head(_)
+            | Node::Literal(_)
+            | Node::Raw(_)
+            | Node::Br
+            | Node::SelectOption(_)
+            | Node::Style(_)
+            | Node::ComponentList(_) => &[],
+        }
+    }
+
+    /// The direct children of this node, in document order
+    pub fn children(&self) -> Vec<Node<'a>> {
+        match *self {
+            Node::Head(Head { content, .. })
+            | Node::Body(Body { content, .. })
+            | Node::Div(Div { content, .. })
+            | Node::A(A { content, .. })
+            | Node::Td(Td { content, .. })
+            | Node::Th(Th { content, .. })
+            | Node::Caption(Caption { content, .. })
+            | Node::Headline(Headline { content, .. })
+            | Node::SelectOption(SelectOption { content, .. })
+            | Node::Li(Li { content, .. })
+            | Node::Section(Section { content, .. })
+            | Node::Article(Article { content, .. })
+            | Node::Nav(Nav { content, .. })
+            | Node::Header(Header { content, .. })
+            | Node::Footer(Footer { content, .. })
+            | Node::Main(Main { content, .. })
+            | Node::Aside(Aside { content, .. })
+            | Node::P(P { content, .. })
+            | Node::Blockquote(Blockquote { content, .. })
+            | Node::Pre(Pre { content, .. })
+            | Node::Span(Span { content, .. })
+            | Node::Code(Code { content, .. })
+            | Node::Strong(Strong { content, .. })
+            | Node::Em(Em { content, .. })
+            | Node::Small(Small { content, .. })
+            | Node::Mark(Mark { content, .. })
+            | Node::Sub(Sub { content, .. })
+            | Node::Sup(Sup { content, .. })
+            | Node::Abbr(Abbr { content, .. })
+            | Node::Form(Form { content, .. })
+            | Node::Label(Label { content, .. })
+            | Node::Button(Button { content, .. })
+            | Node::Legend(Legend { content, .. })
+            | Node::Output(Output { content, .. }) => vec![content.as_ref().into()],
+            Node::Fieldset(fieldset) => {
+                let mut children: Vec<Node> = fieldset.legend.iter().map(Node::Legend).collect();
+                children.push(fieldset.content.as_ref().into());
+                children
+            }
+            Node::Datalist(datalist) => datalist.options.iter().map(Node::SelectOption).collect(),
+            Node::Video(Video {
+                sources,
+                tracks,
+                content,
+                ..
+            })
+            | Node::Audio(Audio {
+                sources,
+                tracks,
+                content,
+                ..
+            }) => {
+                let mut children: Vec<Node> = sources.iter().map(Node::Source).collect();
+                children.extend(tracks.iter().map(Node::Track));
+                children.push(content.as_ref().into());
+                children
+            }
+            Node::Picture(picture) => {
+                let mut children: Vec<Node> = picture.sources.iter().map(Node::Source).collect();
+                children.push(picture.content.as_ref().into());
+                children
+            }
+            Node::Figure(figure) => vec![
+                figure.content.as_ref().into(),
+                figure.caption.as_ref().into(),
+            ],
+            Node::Table(table) => {
+                let mut children: Vec<Node> = table.caption.iter().map(Node::Caption).collect();
+                children.extend(table.colgroups.iter().map(Node::Colgroup));
+                children.extend(table.head.iter().map(Node::TableSection));
+                children.extend(table.rows.iter().map(Node::Tr));
+                children.extend(table.bodies.iter().map(Node::TableSection));
+                children.extend(table.foot.iter().map(Node::TableSection));
+                children
+            }
+            Node::TableSection(section) => section.rows.iter().map(Node::Tr).collect(),
+            Node::Colgroup(colgroup) => colgroup.cols.iter().map(Node::Col).collect(),
+            Node::Tr(tr) => tr.cols.iter().map(Node::from).collect(),
+            Node::Select(select) => select.options.iter().map(Node::SelectOption).collect(),
+            Node::Ul(Ul { items, .. }) | Node::Ol(Ol { items, .. }) => {
+                items.iter().map(Node::Li).collect()
+            }
+            Node::ComponentList(elements) => elements.iter().map(Node::from).collect(),
+            Node::Element(elem) => elem.children.iter().map(Node::from).collect(),
+            Node::Link(_)
+            | Node::Literal(_)
+            | Node::Raw(_)
+            | Node::Br
+            | Node::Img(_)
+            | Node::Canvas(_)
+            | Node::Input(_)
+            | Node::Script(_)
+            | Node::Style(_)
+            | Node::Hr(_)
+            | Node::Textarea(_)
+            | Node::Col(_)
+            | Node::Source(_)
+            | Node::Track(_)
+            | Node::Meta(_) => vec![],
+        }
+    }
+}
+
+/// Read-only traversal with hooks for entering and leaving nodes.
+/// [`Visitor::enter`] is called before the hook for the specific kind of node,
+/// [`Visitor::leave`] after the specific one.
+#[allow(unused_variables)]
+pub trait Visitor<'a> {
+    fn enter(&mut self, node: Node<'a>) {}
+    fn leave(&mut self, node: Node<'a>) {}
+
+    fn enter_head(&mut self, _head: &'a Head) {}
+    fn leave_head(&mut self, _head: &'a Head) {}
+
+    fn enter_body(&mut self, _body: &'a Body) {}
+    fn leave_body(&mut self, _body: &'a Body) {}
+
+    fn enter_div(&mut self, _div: &'a Div) {}
+    fn leave_div(&mut self, _div: &'a Div) {}
+
+    fn enter_link(&mut self, _link: &'a Link) {}
+    fn leave_link(&mut self, _link: &'a Link) {}
+
+    fn enter_a(&mut self, _a: &'a A) {}
+    fn leave_a(&mut self, _a: &'a A) {}
+
+    fn enter_literal(&mut self, _literal: &'a Literal) {}
+    fn leave_literal(&mut self, _literal: &'a Literal) {}
+
+    fn enter_raw(&mut self, _raw: &'a str) {}
+    fn leave_raw(&mut self, _raw: &'a str) {}
+
+    fn enter_table(&mut self, _table: &'a Table) {}
+    fn leave_table(&mut self, _table: &'a Table) {}
+
+    fn enter_tr(&mut self, _tr: &'a Tr) {}
+    fn leave_tr(&mut self, _tr: &'a Tr) {}
+
+    fn enter_td(&mut self, _td: &'a Td) {}
+    fn leave_td(&mut self, _td: &'a Td) {}
+
+    fn enter_th(&mut self, _th: &'a Th) {}
+    fn leave_th(&mut self, _th: &'a Th) {}
+
+    fn enter_table_section(&mut self, _section: &'a TableSection) {}
+    fn leave_table_section(&mut self, _section: &'a TableSection) {}
+
+    fn enter_caption(&mut self, _caption: &'a Caption) {}
+    fn leave_caption(&mut self, _caption: &'a Caption) {}
+
+    fn enter_colgroup(&mut self, _colgroup: &'a Colgroup) {}
+    fn leave_colgroup(&mut self, _colgroup: &'a Colgroup) {}
+
+    fn enter_col(&mut self, _col: &'a Col) {}
+    fn leave_col(&mut self, _col: &'a Col) {}
+
+    fn enter_headline(&mut self, _headline: &'a Headline) {}
+    fn leave_headline(&mut self, _headline: &'a Headline) {}
+
+    fn enter_br(&mut self) {}
+    fn leave_br(&mut self) {}
+
+    fn enter_figure(&mut self, _figure: &'a Figure) {}
+    fn leave_figure(&mut self, _figure: &'a Figure) {}
+
+    fn enter_img(&mut self, _img: &'a Img) {}
+    fn leave_img(&mut self, _img: &'a Img) {}
+
+    fn enter_canvas(&mut self, _canvas: &'a Canvas) {}
+    fn leave_canvas(&mut self, _canvas: &'a Canvas) {}
+
+    fn enter_input(&mut self, _input: &'a Input) {}
+    fn leave_input(&mut self, _input: &'a Input) {}
+
+    fn enter_select(&mut self, _select: &'a Select) {}
+    fn leave_select(&mut self, _select: &'a Select) {}
+
+    fn enter_select_option(&mut self, _option: &'a SelectOption) {}
+    fn leave_select_option(&mut self, _option: &'a SelectOption) {}
+
+    fn enter_script(&mut self, _script: &'a Script) {}
+    fn leave_script(&mut self, _script: &'a Script) {}
+
+    fn enter_component_list(&mut self, _elements: &'a [HtmlElement]) {}
+    fn leave_component_list(&mut self, _elements: &'a [HtmlElement]) {}
+
+    fn enter_style(&mut self, _style: &'a Style) {}
+    fn leave_style(&mut self, _style: &'a Style) {}
+
+    fn enter_ul(&mut self, _ul: &'a Ul) {}
+    fn leave_ul(&mut self, _ul: &'a Ul) {}
+
+    fn enter_ol(&mut self, _ol: &'a Ol) {}
+    fn leave_ol(&mut self, _ol: &'a Ol) {}
+
+    fn enter_li(&mut self, _li: &'a Li) {}
+    fn leave_li(&mut self, _li: &'a Li) {}
+
+    fn enter_section(&mut self, _section: &'a Section) {}
+    fn leave_section(&mut self, _section: &'a Section) {}
+
+    fn enter_article(&mut self, _article: &'a Article) {}
+    fn leave_article(&mut self, _article: &'a Article) {}
+
+    fn enter_nav(&mut self, _nav: &'a Nav) {}
+    fn leave_nav(&mut self, _nav: &'a Nav) {}
+
+    fn enter_header(&mut self, _header: &'a Header) {}
+    fn leave_header(&mut self, _header: &'a Header) {}
+
+    fn enter_footer(&mut self, _footer: &'a Footer) {}
+    fn leave_footer(&mut self, _footer: &'a Footer) {}
+
+    fn enter_main(&mut self, _main: &'a Main) {}
+    fn leave_main(&mut self, _main: &'a Main) {}
+
+    fn enter_aside(&mut self, _aside: &'a Aside) {}
+    fn leave_aside(&mut self, _aside: &'a Aside) {}
+
+    fn enter_p(&mut self, _p: &'a P) {}
+    fn leave_p(&mut self, _p: &'a P) {}
+
+    fn enter_blockquote(&mut self, _blockquote: &'a Blockquote) {}
+    fn leave_blockquote(&mut self, _blockquote: &'a Blockquote) {}
+
+    fn enter_pre(&mut self, _pre: &'a Pre) {}
+    fn leave_pre(&mut self, _pre: &'a Pre) {}
+
+    fn enter_span(&mut self, _span: &'a Span) {}
+    fn leave_span(&mut self, _span: &'a Span) {}
+
+    fn enter_code(&mut self, _code: &'a Code) {}
+    fn leave_code(&mut self, _code: &'a Code) {}
+
+    fn enter_strong(&mut self, _strong: &'a Strong) {}
+    fn leave_strong(&mut self, _strong: &'a Strong) {}
+
+    fn enter_em(&mut self, _em: &'a Em) {}
+    fn leave_em(&mut self, _em: &'a Em) {}
+
+    fn enter_small(&mut self, _small: &'a Small) {}
+    fn leave_small(&mut self, _small: &'a Small) {}
+
+    fn enter_mark(&mut self, _mark: &'a Mark) {}
+    fn leave_mark(&mut self, _mark: &'a Mark) {}
+
+    fn enter_sub(&mut self, _sub: &'a Sub) {}
+    fn leave_sub(&mut self, _sub: &'a Sub) {}
+
+    fn enter_sup(&mut self, _sup: &'a Sup) {}
+    fn leave_sup(&mut self, _sup: &'a Sup) {}
+
+    fn enter_abbr(&mut self, _abbr: &'a Abbr) {}
+    fn leave_abbr(&mut self, _abbr: &'a Abbr) {}
+
+    fn enter_hr(&mut self, _hr: &'a Hr) {}
+    fn leave_hr(&mut self, _hr: &'a Hr) {}
+
+    fn enter_form(&mut self, _form: &'a Form) {}
+    fn leave_form(&mut self, _form: &'a Form) {}
+
+    fn enter_label(&mut self, _label: &'a Label) {}
+    fn leave_label(&mut self, _label: &'a Label) {}
+
+    fn enter_button(&mut self, _button: &'a Button) {}
+    fn leave_button(&mut self, _button: &'a Button) {}
+
+    fn enter_textarea(&mut self, _textarea: &'a Textarea) {}
+    fn leave_textarea(&mut self, _textarea: &'a Textarea) {}
+
+    fn enter_fieldset(&mut self, _fieldset: &'a Fieldset) {}
+    fn leave_fieldset(&mut self, _fieldset: &'a Fieldset) {}
+
+    fn enter_legend(&mut self, _legend: &'a Legend) {}
+    fn leave_legend(&mut self, _legend: &'a Legend) {}
+
+    fn enter_datalist(&mut self, _datalist: &'a Datalist) {}
+    fn leave_datalist(&mut self, _datalist: &'a Datalist) {}
+
+    fn enter_output(&mut self, _output: &'a Output) {}
+    fn leave_output(&mut self, _output: &'a Output) {}
+
+    fn enter_video(&mut self, _video: &'a Video) {}
+    fn leave_video(&mut self, _video: &'a Video) {}
+
+    fn enter_audio(&mut self, _audio: &'a Audio) {}
+    fn leave_audio(&mut self, _audio: &'a Audio) {}
+
+    fn enter_source(&mut self, _source: &'a Source) {}
+    fn leave_source(&mut self, _source: &'a Source) {}
+
+    fn enter_track(&mut self, _track: &'a Track) {}
+    fn leave_track(&mut self, _track: &'a Track) {}
+
+    fn enter_picture(&mut self, _picture: &'a Picture) {}
+    fn leave_picture(&mut self, _picture: &'a Picture) {}
+
+    fn enter_meta(&mut self, _meta: &'a Meta) {}
+    fn leave_meta(&mut self, _meta: &'a Meta) {}
+
+    fn enter_generic(&mut self, _elem: &'a Element) {}
+    fn leave_generic(&mut self, _elem: &'a Element) {}
+}
+
+fn enter_kind<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, node: Node<'a>) {
+    match node {
+        Node::Head(head) => visitor.enter_head(head),
+        Node::Body(body) => visitor.enter_body(body),
+        Node::Div(div) => visitor.enter_div(div),
+        Node::Link(link) => visitor.enter_link(link),
+        Node::A(a) => visitor.enter_a(a),
+        Node::Literal(literal) => visitor.enter_literal(literal),
+        Node::Raw(raw) => visitor.enter_raw(raw),
+        Node::Table(table) => visitor.enter_table(table),
+        Node::Tr(tr) => visitor.enter_tr(tr),
+        Node::Td(td) => visitor.enter_td(td),
+        Node::Th(th) => visitor.enter_th(th),
+        Node::TableSection(section) => visitor.enter_table_section(section),
+        Node::Caption(caption) => visitor.enter_caption(caption),
+        Node::Colgroup(colgroup) => visitor.enter_colgroup(colgroup),
+        Node::Col(col) => visitor.enter_col(col),
+        Node::Headline(headline) => visitor.enter_headline(headline),
+        Node::Br => visitor.enter_br(),
+        Node::Figure(figure) => visitor.enter_figure(figure),
+        Node::Img(img) => visitor.enter_img(img),
+        Node::Canvas(canvas) => visitor.enter_canvas(canvas),
+        Node::Input(input) => visitor.enter_input(input),
+        Node::Select(select) => visitor.enter_select(select),
+        Node::SelectOption(option) => visitor.enter_select_option(option),
+        Node::Script(script) => visitor.enter_script(script),
+        Node::ComponentList(elements) => visitor.enter_component_list(elements),
+        Node::Style(style) => visitor.enter_style(style),
+        Node::Ul(ul) => visitor.enter_ul(ul),
+        Node::Ol(ol) => visitor.enter_ol(ol),
+        Node::Li(li) => visitor.enter_li(li),
+        Node::Section(section) => visitor.enter_section(section),
+        Node::Article(article) => visitor.enter_article(article),
+        Node::Nav(nav) => visitor.enter_nav(nav),
+        Node::Header(header) => visitor.enter_header(header),
+        Node::Footer(footer) => visitor.enter_footer(footer),
+        Node::Main(main) => visitor.enter_main(main),
+        Node::Aside(aside) => visitor.enter_aside(aside),
+        Node::P(p) => visitor.enter_p(p),
+        Node::Blockquote(blockquote) => visitor.enter_blockquote(blockquote),
+        Node::Pre(pre) => visitor.enter_pre(pre),
+        Node::Span(span) => visitor.enter_span(span),
+        Node::Code(code) => visitor.enter_code(code),
+        Node::Strong(strong) => visitor.enter_strong(strong),
+        Node::Em(em) => visitor.enter_em(em),
+        Node::Small(small) => visitor.enter_small(small),
+        Node::Mark(mark) => visitor.enter_mark(mark),
+        Node::Sub(sub) => visitor.enter_sub(sub),
+        Node::Sup(sup) => visitor.enter_sup(sup),
+        Node::Abbr(abbr) => visitor.enter_abbr(abbr),
+        Node::Hr(hr) => visitor.enter_hr(hr),
+        Node::Form(form) => visitor.enter_form(form),
+        Node::Label(label) => visitor.enter_label(label),
+        Node::Button(button) => visitor.enter_button(button),
+        Node::Textarea(textarea) => visitor.enter_textarea(textarea),
+        Node::Fieldset(fieldset) => visitor.enter_fieldset(fieldset),
+        Node::Legend(legend) => visitor.enter_legend(legend),
+        Node::Datalist(datalist) => visitor.enter_datalist(datalist),
+        Node::Output(output) => visitor.enter_output(output),
+        Node::Video(video) => visitor.enter_video(video),
+        Node::Audio(audio) => visitor.enter_audio(audio),
+        Node::Source(source) => visitor.enter_source(source),
+        Node::Track(track) => visitor.enter_track(track),
+        Node::Picture(picture) => visitor.enter_picture(picture),
+        Node::Meta(meta) => visitor.enter_meta(meta),
+        Node::Element(elem) => visitor.enter_generic(elem),
+    }
+}
+
+fn leave_kind<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, node: Node<'a>) {
+    match node {
+        Node::Head(head) => visitor.leave_head(head),
+        Node::Body(body) => visitor.leave_body(body),
+        Node::Div(div) => visitor.leave_div(div),
+        Node::Link(link) => visitor.leave_link(link),
+        Node::A(a) => visitor.leave_a(a),
+        Node::Literal(literal) => visitor.leave_literal(literal),
+        Node::Raw(raw) => visitor.leave_raw(raw),
+        Node::Table(table) => visitor.leave_table(table),
+        Node::Tr(tr) => visitor.leave_tr(tr),
+        Node::Td(td) => visitor.leave_td(td),
+        Node::Th(th) => visitor.leave_th(th),
+        Node::TableSection(section) => visitor.leave_table_section(section),
+        Node::Caption(caption) => visitor.leave_caption(caption),
+        Node::Colgroup(colgroup) => visitor.leave_colgroup(colgroup),
+        Node::Col(col) => visitor.leave_col(col),
+        Node::Headline(headline) => visitor.leave_headline(headline),
+        Node::Br => visitor.leave_br(),
+        Node::Figure(figure) => visitor.leave_figure(figure),
+        Node::Img(img) => visitor.leave_img(img),
+        Node::Canvas(canvas) => visitor.leave_canvas(canvas),
+        Node::Input(input) => visitor.leave_input(input),
+        Node::Select(select) => visitor.leave_select(select),
+        Node::SelectOption(option) => visitor.leave_select_option(option),
+        Node::Script(script) => visitor.leave_script(script),
+        Node::ComponentList(elements) => visitor.leave_component_list(elements),
+        Node::Style(style) => visitor.leave_style(style),
+        Node::Ul(ul) => visitor.leave_ul(ul),
+        Node::Ol(ol) => visitor.leave_ol(ol),
+        Node::Li(li) => visitor.leave_li(li),
+        Node::Section(section) => visitor.leave_section(section),
+        Node::Article(article) => visitor.leave_article(article),
+        Node::Nav(nav) => visitor.leave_nav(nav),
+        Node::Header(header) => visitor.leave_header(header),
+        Node::Footer(footer) => visitor.leave_footer(footer),
+        Node::Main(main) => visitor.leave_main(main),
+        Node::Aside(aside) => visitor.leave_aside(aside),
+        Node::P(p) => visitor.leave_p(p),
+        Node::Blockquote(blockquote) => visitor.leave_blockquote(blockquote),
+        Node::Pre(pre) => visitor.leave_pre(pre),
+        Node::Span(span) => visitor.leave_span(span),
+        Node::Code(code) => visitor.leave_code(code),
+        Node::Strong(strong) => visitor.leave_strong(strong),
+        Node::Em(em) => visitor.leave_em(em),
+        Node::Small(small) => visitor.leave_small(small),
+        Node::Mark(mark) => visitor.leave_mark(mark),
+        Node::Sub(sub) => visitor.leave_sub(sub),
+        Node::Sup(sup) => visitor.leave_sup(sup),
+        Node::Abbr(abbr) => visitor.leave_abbr(abbr),
+        Node::Hr(hr) => visitor.leave_hr(hr),
+        Node::Form(form) => visitor.leave_form(form),
+        Node::Label(label) => visitor.leave_label(label),
+        Node::Button(button) => visitor.leave_button(button),
+        Node::Textarea(textarea) => visitor.leave_textarea(textarea),
+        Node::Fieldset(fieldset) => visitor.leave_fieldset(fieldset),
+        Node::Legend(legend) => visitor.leave_legend(legend),
+        Node::Datalist(datalist) => visitor.leave_datalist(datalist),
+        Node::Output(output) => visitor.leave_output(output),
+        Node::Video(video) => visitor.leave_video(video),
+        Node::Audio(audio) => visitor.leave_audio(audio),
+        Node::Source(source) => visitor.leave_source(source),
+        Node::Track(track) => visitor.leave_track(track),
+        Node::Picture(picture) => visitor.leave_picture(picture),
+        Node::Meta(meta) => visitor.leave_meta(meta),
+        Node::Element(elem) => visitor.leave_generic(elem),
+    }
+}
+
+/// Walks `node` and all of its descendants
+pub fn walk<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, node: Node<'a>) {
+    visitor.enter(node);
+    enter_kind(visitor, node);
+    for child in node.children() {
+        walk(visitor, child);
+    }
+    leave_kind(visitor, node);
+    visitor.leave(node);
+}
+
+/// Walks `elem` and all of its descendants
+pub fn walk_element<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, elem: &'a HtmlElement) {
+    walk(visitor, elem.into())
+}
+
+/// Walks the head and then the body of `document`
+pub fn walk_document<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, document: &'a HtmlDocument) {
+    walk(visitor, Node::Head(&document.head));
+    walk(visitor, Node::Body(&document.body));
+}
+
+/// Traversal that can rewrite the tree in place.
+/// Every node is passed to its hooks before its children are visited, so replacing an element in
+/// [`VisitMut::visit_element_mut`] means the replacement is walked.
+/// Children behind a shared [`Rc`] are cloned before they are changed.
+#[allow(unused_variables)]
+pub trait VisitMut {
+    fn visit_element_mut(&mut self, elem: &mut HtmlElement) {}
+    fn visit_attributes_mut(&mut self, attributes: &mut Vec<Attribute>) {}
+
+    fn visit_head_mut(&mut self, _head: &mut Head) {}
+    fn visit_body_mut(&mut self, _body: &mut Body) {}
+    fn visit_div_mut(&mut self, _div: &mut Div) {}
+    fn visit_link_mut(&mut self, _link: &mut Link) {}
+    fn visit_a_mut(&mut self, _a: &mut A) {}
+    fn visit_literal_mut(&mut self, _literal: &mut Literal) {}
+    fn visit_table_mut(&mut self, _table: &mut Table) {}
+    fn visit_tr_mut(&mut self, _tr: &mut Tr) {}
+    fn visit_td_mut(&mut self, _td: &mut Td) {}
+    fn visit_th_mut(&mut self, _th: &mut Th) {}
+    fn visit_table_section_mut(&mut self, _section: &mut TableSection) {}
+    fn visit_caption_mut(&mut self, _caption: &mut Caption) {}
+    fn visit_colgroup_mut(&mut self, _colgroup: &mut Colgroup) {}
+    fn visit_col_mut(&mut self, _col: &mut Col) {}
+    fn visit_headline_mut(&mut self, _headline: &mut Headline) {}
+    fn visit_figure_mut(&mut self, _figure: &mut Figure) {}
+    fn visit_img_mut(&mut self, _img: &mut Img) {}
+    fn visit_canvas_mut(&mut self, _canvas: &mut Canvas) {}
+    fn visit_input_mut(&mut self, _input: &mut Input) {}
+    fn visit_select_mut(&mut self, _select: &mut Select) {}
+    fn visit_select_option_mut(&mut self, _option: &mut SelectOption) {}
+    fn visit_script_mut(&mut self, _script: &mut Script) {}
+    fn visit_style_mut(&mut self, _style: &mut Style) {}
+    fn visit_ul_mut(&mut self, _ul: &mut Ul) {}
+    fn visit_ol_mut(&mut self, _ol: &mut Ol) {}
+    fn visit_li_mut(&mut self, _li: &mut Li) {}
+    fn visit_section_mut(&mut self, _section: &mut Section) {}
+    fn visit_article_mut(&mut self, _article: &mut Article) {}
+    fn visit_nav_mut(&mut self, _nav: &mut Nav) {}
+    fn visit_header_mut(&mut self, _header: &mut Header) {}
+    fn visit_footer_mut(&mut self, _footer: &mut Footer) {}
+    fn visit_main_mut(&mut self, _main: &mut Main) {}
+    fn visit_aside_mut(&mut self, _aside: &mut Aside) {}
+    fn visit_p_mut(&mut self, _p: &mut P) {}
+    fn visit_blockquote_mut(&mut self, _blockquote: &mut Blockquote) {}
+    fn visit_pre_mut(&mut self, _pre: &mut Pre) {}
+    fn visit_span_mut(&mut self, _span: &mut Span) {}
+    fn visit_code_mut(&mut self, _code: &mut Code) {}
+    fn visit_strong_mut(&mut self, _strong: &mut Strong) {}
+    fn visit_em_mut(&mut self, _em: &mut Em) {}
+    fn visit_small_mut(&mut self, _small: &mut Small) {}
+    fn visit_mark_mut(&mut self, _mark: &mut Mark) {}
+    fn visit_sub_mut(&mut self, _sub: &mut Sub) {}
+    fn visit_sup_mut(&mut self, _sup: &mut Sup) {}
+    fn visit_abbr_mut(&mut self, _abbr: &mut Abbr) {}
+    fn visit_hr_mut(&mut self, _hr: &mut Hr) {}
+    fn visit_form_mut(&mut self, _form: &mut Form) {}
+    fn visit_label_mut(&mut self, _label: &mut Label) {}
+    fn visit_button_mut(&mut self, _button: &mut Button) {}
+    fn visit_textarea_mut(&mut self, _textarea: &mut Textarea) {}
+    fn visit_fieldset_mut(&mut self, _fieldset: &mut Fieldset) {}
+    fn visit_legend_mut(&mut self, _legend: &mut Legend) {}
+    fn visit_datalist_mut(&mut self, _datalist: &mut Datalist) {}
+    fn visit_output_mut(&mut self, _output: &mut Output) {}
+    fn visit_video_mut(&mut self, _video: &mut Video) {}
+    fn visit_audio_mut(&mut self, _audio: &mut Audio) {}
+    fn visit_source_mut(&mut self, _source: &mut Source) {}
+    fn visit_track_mut(&mut self, _track: &mut Track) {}
+    fn visit_picture_mut(&mut self, _picture: &mut Picture) {}
+    fn visit_meta_mut(&mut self, _meta: &mut Meta) {}
+    fn visit_generic_mut(&mut self, _elem: &mut Element) {}
+}
+
+fn walk_content_mut<V: VisitMut + ?Sized>(visitor: &mut V, content: &mut Rc<HtmlElement>) {
+    walk_mut(visitor, Rc::make_mut(content))
+}
+
+fn walk_td_mut<V: VisitMut + ?Sized>(visitor: &mut V, td: &mut Td) {
+    visitor.visit_td_mut(td);
+    visitor.visit_attributes_mut(&mut td.attributes);
+    walk_content_mut(visitor, &mut td.content);
+}
+
+fn walk_th_mut<V: VisitMut + ?Sized>(visitor: &mut V, th: &mut Th) {
+    visitor.visit_th_mut(th);
+    visitor.visit_attributes_mut(&mut th.attributes);
+    walk_content_mut(visitor, &mut th.content);
+}
+
+fn walk_tr_mut<V: VisitMut + ?Sized>(visitor: &mut V, tr: &mut Tr) {
+    visitor.visit_tr_mut(tr);
+    visitor.visit_attributes_mut(&mut tr.attributes);
+    for cell in tr.cols.iter_mut() {
+        match cell {
+            Cell::Td(td) => walk_td_mut(visitor, td),
+            Cell::Th(th) => walk_th_mut(visitor, th),
+        }
+    }
+}
+
+fn walk_table_section_mut<V: VisitMut + ?Sized>(visitor: &mut V, section: &mut TableSection) {
+    visitor.visit_table_section_mut(section);
+    visitor.visit_attributes_mut(&mut section.attributes);
+    for tr in section.rows.iter_mut() {
+        walk_tr_mut(visitor, tr);
+    }
+}
+
+fn walk_caption_mut<V: VisitMut + ?Sized>(visitor: &mut V, caption: &mut Caption) {
+    visitor.visit_caption_mut(caption);
+    visitor.visit_attributes_mut(&mut caption.attributes);
+    walk_content_mut(visitor, &mut caption.content);
+}
+
+fn walk_col_mut<V: VisitMut + ?Sized>(visitor: &mut V, col: &mut Col) {
+    visitor.visit_col_mut(col);
+    visitor.visit_attributes_mut(&mut col.attributes);
+}
+
+fn walk_colgroup_mut<V: VisitMut + ?Sized>(visitor: &mut V, colgroup: &mut Colgroup) {
+    visitor.visit_colgroup_mut(colgroup);
+    visitor.visit_attributes_mut(&mut colgroup.attributes);
+    for col in colgroup.cols.iter_mut() {
+        walk_col_mut(visitor, col);
+    }
+}
+
+fn walk_li_mut<V: VisitMut + ?Sized>(visitor: &mut V, li: &mut Li) {
+    visitor.visit_li_mut(li);
+    visitor.visit_attributes_mut(&mut li.attributes);
+    walk_content_mut(visitor, &mut li.content);
+}
+
+fn walk_legend_mut<V: VisitMut + ?Sized>(visitor: &mut V, legend: &mut Legend) {
+    visitor.visit_legend_mut(legend);
+    visitor.visit_attributes_mut(&mut legend.attributes);
+    walk_content_mut(visitor, &mut legend.content);
+}
+
+fn walk_source_mut<V: VisitMut + ?Sized>(visitor: &mut V, source: &mut Source) {
+    visitor.visit_source_mut(source);
+    visitor.visit_attributes_mut(&mut source.attributes);
+}
+
+fn walk_track_mut<V: VisitMut + ?Sized>(visitor: &mut V, track: &mut Track) {
+    visitor.visit_track_mut(track);
+    visitor.visit_attributes_mut(&mut track.attributes);
+}
+
+fn walk_head_mut<V: VisitMut + ?Sized>(visitor: &mut V, head: &mut Head) {
+    visitor.visit_head_mut(head);
+    walk_content_mut(visitor, &mut head.content);
+}
+
+fn walk_body_mut<V: VisitMut + ?Sized>(visitor: &mut V, body: &mut Body) {
+    visitor.visit_body_mut(body);
+    visitor.visit_attributes_mut(&mut body.attributes);
+    walk_content_mut(visitor, &mut body.content);
+}
+
+/// Walks `elem` and all of its descendants, see [`VisitMut`]
+pub fn walk_mut<V: VisitMut + ?Sized>(visitor: &mut V, elem: &mut HtmlElement) {
+    visitor.visit_element_mut(elem);
+    match elem {
+        HtmlElement::Head(head) => walk_head_mut(visitor, head),
+        HtmlElement::Body(body) => walk_body_mut(visitor, body),
+        HtmlElement::Div(div) => {
+            visitor.visit_div_mut(div);
+            visitor.visit_attributes_mut(&mut div.attributes);
+            walk_content_mut(visitor, &mut div.content);
+        }
+        HtmlElement::Link(link) => {
+            visitor.visit_link_mut(link);
+            visitor.visit_attributes_mut(&mut link.attributes);
+        }
+        HtmlElement::A(a) => {
+            visitor.visit_a_mut(a);
+            visitor.visit_attributes_mut(&mut a.attributes);
+            walk_content_mut(visitor, &mut a.content);
+        }
+        HtmlElement::Literal(literal) => visitor.visit_literal_mut(literal),
+        HtmlElement::Raw(_) | HtmlElement::Br => (),
+        HtmlElement::Table(table) => {
+            visitor.visit_table_mut(table);
+            visitor.visit_attributes_mut(&mut table.attributes);
+            if let Some(caption) = table.caption.as_mut() {
+                walk_caption_mut(visitor, caption);
+            }
+            for colgroup in table.colgroups.iter_mut() {
+                walk_colgroup_mut(visitor, colgroup);
+            }
+            if let Some(head) = table.head.as_mut() {
+                walk_table_section_mut(visitor, head);
+            }
+            for tr in table.rows.iter_mut() {
+                walk_tr_mut(visitor, tr);
+            }
+            for body in table.bodies.iter_mut() {
+                walk_table_section_mut(visitor, body);
+            }
+            if let Some(foot) = table.foot.as_mut() {
+                walk_table_section_mut(visitor, foot);
+            }
+        }
+        HtmlElement::Tr(tr) => walk_tr_mut(visitor, tr),
+        HtmlElement::Td(td) => walk_td_mut(visitor, td),
+        HtmlElement::Th(th) => walk_th_mut(visitor, th),
+        HtmlElement::TableSection(section) => walk_table_section_mut(visitor, section),
+        HtmlElement::Caption(caption) => walk_caption_mut(visitor, caption),
+        HtmlElement::Colgroup(colgroup) => walk_colgroup_mut(visitor, colgroup),
+        HtmlElement::Col(col) => walk_col_mut(visitor, col),
+        HtmlElement::Headline(headline) => {
+            visitor.visit_headline_mut(headline);
+            visitor.visit_attributes_mut(&mut headline.attributes);
+            walk_content_mut(visitor, &mut headline.content);
+        }
+        HtmlElement::Figure(figure) => {
+            visitor.visit_figure_mut(figure);
+            visitor.visit_attributes_mut(&mut figure.attributes);
+            walk_content_mut(visitor, &mut figure.content);
+            walk_content_mut(visitor, &mut figure.caption);
+        }
+        HtmlElement::Img(img) => {
+            visitor.visit_img_mut(img);
+            visitor.visit_attributes_mut(&mut img.attributes);
+        }
+        HtmlElement::Canvas(canvas) => {
+            visitor.visit_canvas_mut(canvas);
+            visitor.visit_attributes_mut(&mut canvas.attributes);
+        }
+        HtmlElement::Input(input) => {
+            visitor.visit_input_mut(input);
+            visitor.visit_attributes_mut(&mut input.attributes);
+        }
+        HtmlElement::Select(select) => {
+            visitor.visit_select_mut(select);
+            visitor.visit_attributes_mut(&mut select.attributes);
+            for option in select.options.iter_mut() {
+                visitor.visit_select_option_mut(option);
+                walk_content_mut(visitor, &mut option.content);
+            }
+        }
+        HtmlElement::Script(script) => {
+            visitor.visit_script_mut(script);
+            visitor.visit_attributes_mut(&mut script.attributes);
+        }
+        HtmlElement::ComponentList(elements) => {
+            for elem in elements.iter_mut() {
+                walk_mut(visitor, elem);
+            }
+        }
+        HtmlElement::Style(style) => visitor.visit_style_mut(style),
+        HtmlElement::Ul(ul) => {
+            visitor.visit_ul_mut(ul);
+            visitor.visit_attributes_mut(&mut ul.attributes);
+            for li in ul.items.iter_mut() {
+                walk_li_mut(visitor, li);
+            }
+        }
+        HtmlElement::Ol(ol) => {
+            visitor.visit_ol_mut(ol);
+            visitor.visit_attributes_mut(&mut ol.attributes);
+            for li in ol.items.iter_mut() {
+                walk_li_mut(visitor, li);
+            }
+        }
+        HtmlElement::Section(section) => {
+            visitor.visit_section_mut(section);
+            visitor.visit_attributes_mut(&mut section.attributes);
+            walk_content_mut(visitor, &mut section.content);
+        }
+        HtmlElement::Article(article) => {
+            visitor.visit_article_mut(article);
+            visitor.visit_attributes_mut(&mut article.attributes);
+            walk_content_mut(visitor, &mut article.content);
+        }
+        HtmlElement::Nav(nav) => {
+            visitor.visit_nav_mut(nav);
+            visitor.visit_attributes_mut(&mut nav.attributes);
+            walk_content_mut(visitor, &mut nav.content);
+        }
+        HtmlElement::Header(header) => {
+            visitor.visit_header_mut(header);
+            visitor.visit_attributes_mut(&mut header.attributes);
+            walk_content_mut(visitor, &mut header.content);
+        }
+        HtmlElement::Footer(footer) => {
+            visitor.visit_footer_mut(footer);
+            visitor.visit_attributes_mut(&mut footer.attributes);
+            walk_content_mut(visitor, &mut footer.content);
+        }
+        HtmlElement::Main(main) => {
+            visitor.visit_main_mut(main);
+            visitor.visit_attributes_mut(&mut main.attributes);
+            walk_content_mut(visitor, &mut main.content);
+        }
+        HtmlElement::Aside(aside) => {
+            visitor.visit_aside_mut(aside);
+            visitor.visit_attributes_mut(&mut aside.attributes);
+            walk_content_mut(visitor, &mut aside.content);
+        }
+        HtmlElement::P(p) => {
+            visitor.visit_p_mut(p);
+            visitor.visit_attributes_mut(&mut p.attributes);
+            walk_content_mut(visitor, &mut p.content);
+        }
+        HtmlElement::Blockquote(blockquote) => {
+            visitor.visit_blockquote_mut(blockquote);
+            visitor.visit_attributes_mut(&mut blockquote.attributes);
+            walk_content_mut(visitor, &mut blockquote.content);
+        }
+        HtmlElement::Pre(pre) => {
+            visitor.visit_pre_mut(pre);
+            visitor.visit_attributes_mut(&mut pre.attributes);
+            walk_content_mut(visitor, &mut pre.content);
+        }
+        HtmlElement::Span(span) => {
+            visitor.visit_span_mut(span);
+            visitor.visit_attributes_mut(&mut span.attributes);
+            walk_content_mut(visitor, &mut span.content);
+        }
+        HtmlElement::Code(code) => {
+            visitor.visit_code_mut(code);
+            visitor.visit_attributes_mut(&mut code.attributes);
+            walk_content_mut(visitor, &mut code.content);
+        }
+        HtmlElement::Strong(strong) => {
+            visitor.visit_strong_mut(strong);
+            visitor.visit_attributes_mut(&mut strong.attributes);
+            walk_content_mut(visitor, &mut strong.content);
+        }
+        HtmlElement::Em(em) => {
+            visitor.visit_em_mut(em);
+            visitor.visit_attributes_mut(&mut em.attributes);
+            walk_content_mut(visitor, &mut em.content);
+        }
+        HtmlElement::Small(small) => {
+            visitor.visit_small_mut(small);
+            visitor.visit_attributes_mut(&mut small.attributes);
+            walk_content_mut(visitor, &mut small.content);
+        }
+        HtmlElement::Mark(mark) => {
+            visitor.visit_mark_mut(mark);
+            visitor.visit_attributes_mut(&mut mark.attributes);
+            walk_content_mut(visitor, &mut mark.content);
+        }
+        HtmlElement::Sub(sub) => {
+            visitor.visit_sub_mut(sub);
+            visitor.visit_attributes_mut(&mut sub.attributes);
+            walk_content_mut(visitor, &mut sub.content);
+        }
+        HtmlElement::Sup(sup) => {
+            visitor.visit_sup_mut(sup);
+            visitor.visit_attributes_mut(&mut sup.attributes);
+            walk_content_mut(visitor, &mut sup.content);
+        }
+        HtmlElement::Abbr(abbr) => {
+            visitor.visit_abbr_mut(abbr);
+            visitor.visit_attributes_mut(&mut abbr.attributes);
+            walk_content_mut(visitor, &mut abbr.content);
+        }
+        HtmlElement::Hr(hr) => {
+            visitor.visit_hr_mut(hr);
+            visitor.visit_attributes_mut(&mut hr.attributes);
+        }
+        HtmlElement::Form(form) => {
+            visitor.visit_form_mut(form);
+            visitor.visit_attributes_mut(&mut form.attributes);
+            walk_content_mut(visitor, &mut form.content);
+        }
+        HtmlElement::Label(label) => {
+            visitor.visit_label_mut(label);
+            visitor.visit_attributes_mut(&mut label.attributes);
+            walk_content_mut(visitor, &mut label.content);
+        }
+        HtmlElement::Button(button) => {
+            visitor.visit_button_mut(button);
+            visitor.visit_attributes_mut(&mut button.attributes);
+            walk_content_mut(visitor, &mut button.content);
+        }
+        HtmlElement::Textarea(textarea) => {
+            visitor.visit_textarea_mut(textarea);
+            visitor.visit_attributes_mut(&mut textarea.attributes);
+        }
+        HtmlElement::Fieldset(fieldset) => {
+            visitor.visit_fieldset_mut(fieldset);
+            visitor.visit_attributes_mut(&mut fieldset.attributes);
+            if let Some(legend) = fieldset.legend.as_mut() {
+                walk_legend_mut(visitor, legend);
+            }
+            walk_content_mut(visitor, &mut fieldset.content);
+        }
+        HtmlElement::Legend(legend) => walk_legend_mut(visitor, legend),
+        HtmlElement::Datalist(datalist) => {
+            visitor.visit_datalist_mut(datalist);
+            visitor.visit_attributes_mut(&mut datalist.attributes);
+            for option in datalist.options.iter_mut() {
+                visitor.visit_select_option_mut(option);
+                walk_content_mut(visitor, &mut option.content);
+            }
+        }
+        HtmlElement::Output(output) => {
+            visitor.visit_output_mut(output);
+            visitor.visit_attributes_mut(&mut output.attributes);
+            walk_content_mut(visitor, &mut output.content);
+        }
+        HtmlElement::Video(video) => {
+            visitor.visit_video_mut(video);
+            visitor.visit_attributes_mut(&mut video.attributes);
+            for source in video.sources.iter_mut() {
+                walk_source_mut(visitor, source);
+            }
+            for track in video.tracks.iter_mut() {
+                walk_track_mut(visitor, track);
+            }
+            walk_content_mut(visitor, &mut video.content);
+        }
+        HtmlElement::Audio(audio) => {
+            visitor.visit_audio_mut(audio);
+            visitor.visit_attributes_mut(&mut audio.attributes);
+            for source in audio.sources.iter_mut() {
+                walk_source_mut(visitor, source);
+            }
+            for track in audio.tracks.iter_mut() {
+                walk_track_mut(visitor, track);
+            }
+            walk_content_mut(visitor, &mut audio.content);
+        }
+        HtmlElement::Source(source) => walk_source_mut(visitor, source),
+        HtmlElement::Track(track) => walk_track_mut(visitor, track),
+        HtmlElement::Picture(picture) => {
+            visitor.visit_picture_mut(picture);
+            visitor.visit_attributes_mut(&mut picture.attributes);
+            for source in picture.sources.iter_mut() {
+                walk_source_mut(visitor, source);
+            }
+            walk_content_mut(visitor, &mut picture.content);
+        }
+        HtmlElement::Meta(meta) => {
+            visitor.visit_meta_mut(meta);
+            visitor.visit_attributes_mut(&mut meta.attributes);
+        }
+        HtmlElement::Element(elem) => {
+            visitor.visit_generic_mut(elem);
+            visitor.visit_attributes_mut(&mut elem.attributes);
+            for child in elem.children.iter_mut() {
+                walk_mut(visitor, child);
+            }
+        }
+    }
+}
+
+/// Walks the head and then the body of `document`.
+/// Since they are not stored as [`HtmlElement`], [`VisitMut::visit_element_mut`] is not called for them.
+pub fn walk_document_mut<V: VisitMut + ?Sized>(visitor: &mut V, document: &mut HtmlDocument) {
+    walk_head_mut(visitor, &mut document.head);
+    walk_body_mut(visitor, &mut document.body);
+}
+
+#[cfg(test)]
+mod visit_tests {
+    use super::{walk_document, walk_element, walk_mut, Node, VisitMut, Visitor};
+    use crate::{
+        attribute::Attribute,
+        elements::{
+            Body, Caption, Div, Element, Fieldset, Head, HtmlElement, Img, Input, Legend, Li,
+            Metadata, Source, Table, TableSection, Td, Th, Tr, Track, Ul, Video, WithChildren, A,
+        },
+        html_document::{Doctype, HtmlDocument},
+        render::Render,
+    };
+    use std::rc::Rc;
+
+    #[derive(Default)]
+    struct Events(Vec<String>);
+
+    impl<'a> Visitor<'a> for Events {
+        fn enter(&mut self, node: Node<'a>) {
+            self.0.push(format!("enter {}", kind(node)))
+        }
+        fn leave(&mut self, node: Node<'a>) {
+            self.0.push(format!("leave {}", kind(node)))
+        }
+    }
+
+    fn kind(node: Node) -> &'static str {
+        match node {
+            Node::Div(_) => "div",
+            Node::Img(_) => "img",
+            Node::A(_) => "a",
+            Node::Ul(_) => "ul",
+            Node::Li(_) => "li",
+            Node::Literal(_) => "text",
+            Node::ComponentList(_) => "list",
+            Node::Head(_) => "head",
+            Node::Body(_) => "body",
+            _ => "other",
+        }
+    }
+
+    fn example() -> HtmlElement {
+        Div {
+            attributes: vec![],
+            content: Rc::new(HtmlElement::ComponentList(vec![
+                Img {
+                    attributes: vec![Attribute::Src("a.png".to_owned())],
+                }
+                .into(),
+                Ul {
+                    attributes: vec![],
+                    items: vec![Li {
+                        attributes: vec![],
+                        content: Rc::new(
+                            A {
+                                attributes: vec![Attribute::Href("/old".to_owned())],
+                                content: Rc::new("link".to_owned().into()),
+                            }
+                            .into(),
+                        ),
+                    }],
+                }
+                .into(),
+            ])),
+        }
+        .into()
+    }
+
+    #[test]
+    fn visit_order() {
+        let elem = example();
+        let mut events = Events::default();
+        walk_element(&mut events, &elem);
+        let result = events.0;
+        let expected = vec![
+            "enter div",
+            "enter list",
+            "enter img",
+            "leave img",
+            "enter ul",
+            "enter li",
+            "enter a",
+            "enter text",
+            "leave text",
+            "leave a",
+            "leave li",
+            "leave ul",
+            "leave list",
+            "leave div",
+        ];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn visit_generic() {
+        #[derive(Default)]
+        struct Tags(Vec<String>);
+        impl<'a> Visitor<'a> for Tags {
+            fn enter(&mut self, node: Node<'a>) {
+                if let Some(tag) = node.tag_name() {
+                    self.0.push(format!("{tag} {}", node.attributes().len()))
+                }
+            }
+        }
+        let typed = example();
+        let generic: HtmlElement = Element::try_from(example()).unwrap().into();
+        let (mut result, mut expected) = (Tags::default(), Tags::default());
+        walk_element(&mut result, &generic);
+        walk_element(&mut expected, &typed);
+        assert_eq!(result.0, expected.0)
+    }
+
+    #[test]
+    fn visit_kind_hooks() {
+        struct Srcs<'a>(Vec<&'a Attribute>);
+        impl<'a> Visitor<'a> for Srcs<'a> {
+            fn enter_img(&mut self, img: &'a Img) {
+                self.0.extend(img.attributes.iter())
+            }
+        }
+        let elem = example();
+        let mut srcs = Srcs(vec![]);
+        walk_element(&mut srcs, &elem);
+        let result = srcs.0;
+        let expected = [Attribute::Src("a.png".to_owned())];
+        assert_eq!(result, expected.iter().collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn visit_document() {
+        let doc = HtmlDocument {
+            doctype: Doctype::Html5,
+            attributes: vec![],
+            head: Head {
+                title: "title".to_owned(),
+                metadata: Metadata::default(),
+                content: Rc::new(HtmlElement::ComponentList(vec![])),
+            },
+            body: Body {
+                attributes: vec![],
+                content: Rc::new(example()),
+            },
+        };
+        let mut events = Events::default();
+        walk_document(&mut events, &doc);
+        let result = &events.0[..3];
+        let expected = vec!["enter head", "enter list", "leave list"];
+        assert_eq!(result, expected);
+        assert_eq!(events.0[4], "enter body")
+    }
+
+    #[test]
+    fn rewrite_hrefs() {
+        struct Rewrite;
+        impl VisitMut for Rewrite {
+            fn visit_attributes_mut(&mut self, attributes: &mut Vec<Attribute>) {
+                for attr in attributes.iter_mut() {
+                    if let Attribute::Href(href) = attr {
+                        *href = format!("/new{href}");
+                    }
+                }
+            }
+        }
+        let mut elem = example();
+        walk_mut(&mut Rewrite, &mut elem);
+        let result = elem.render();
+        let expected = example().render().replace("/old", "/new/old");
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn add_class_to_images() {
+        struct Lazy;
+        impl VisitMut for Lazy {
+            fn visit_img_mut(&mut self, img: &mut Img) {
+                img.attributes
+                    .push(Attribute::Class(vec!["lazy".to_owned()]))
+            }
+        }
+        let shared = example();
+        let mut elem = shared.clone();
+        walk_mut(&mut Lazy, &mut elem);
+        let result = elem.render();
+        let expected = shared
+            .render()
+            .replace("src=\"a.png\"", "src=\"a.png\" class=\"lazy\"");
+        assert_eq!(result, expected);
+        assert!(!shared.render().contains("lazy"))
+    }
+
+    #[test]
+    fn replace_elements() {
+        struct DropImages;
+        impl VisitMut for DropImages {
+            fn visit_element_mut(&mut self, elem: &mut HtmlElement) {
+                if let HtmlElement::Img(_) = elem {
+                    *elem = HtmlElement::Br
+                }
+            }
+        }
+        let mut elem = example();
+        walk_mut(&mut DropImages, &mut elem);
+        let result = elem.render();
+        assert!(result.contains("<br"));
+        assert!(!result.contains("<img"))
+    }
+
+    #[test]
+    fn visit_fieldset_legend_first() {
+        #[derive(Default)]
+        struct Tags(Vec<String>);
+        impl<'a> Visitor<'a> for Tags {
+            fn enter(&mut self, node: Node<'a>) {
+                if let Some(tag) = node.tag_name() {
+                    self.0.push(tag.to_owned())
+                }
+            }
+        }
+        let elem: HtmlElement = Fieldset::new()
+            .legend(Legend::new().text("legend"))
+            .child(Input::new())
+            .into();
+        let mut tags = Tags::default();
+        walk_element(&mut tags, &elem);
+        let result = tags.0;
+        let expected = vec!["fieldset", "legend", "input"];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn visit_video_sources_first() {
+        #[derive(Default)]
+        struct Tags(Vec<String>);
+        impl<'a> Visitor<'a> for Tags {
+            fn enter(&mut self, node: Node<'a>) {
+                if let Some(tag) = node.tag_name() {
+                    self.0.push(tag.to_owned())
+                }
+            }
+        }
+        let elem: HtmlElement = Video::new()
+            .child(Img::new())
+            .child(Track::new())
+            .child(Source::new())
+            .into();
+        let mut tags = Tags::default();
+        walk_element(&mut tags, &elem);
+        let result = tags.0;
+        let expected = vec!["video", "source", "track", "img"];
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn visit_table_parts_in_order() {
+        #[derive(Default)]
+        struct Tags(Vec<String>);
+        impl<'a> Visitor<'a> for Tags {
+            fn enter(&mut self, node: Node<'a>) {
+                if let Some(tag) = node.tag_name() {
+                    self.0.push(tag.to_owned())
+                }
+            }
+        }
+        let table: HtmlElement = Table::new()
+            .section(TableSection::foot().row(Tr::new().child(Td::new())))
+            .row(Tr::new().child(Th::new()))
+            .caption(Caption::new())
+            .into();
+        let mut tags = Tags::default();
+        walk_element(&mut tags, &table);
+        let result = tags.0;
+        let expected = vec!["table", "caption", "tr", "th", "tfoot", "tr", "td"];
+        assert_eq!(result, expected)
+    }
+}